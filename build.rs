@@ -0,0 +1,22 @@
+//! The build script sets `DAILYBIBLE_GIT_COMMIT` to the git commit the bot is built from, which is shown by `/about`.
+//! Builds without the git repository (like in Docker) can pass the commit in the environment variable `GIT_COMMIT`.
+
+use std::{env, process::Command};
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=GIT_COMMIT");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs");
+
+    let commit = env::var("GIT_COMMIT").ok()
+        .filter(|commit| !commit.trim().is_empty())
+        .or_else(|| {
+            Command::new("git").args(["rev-parse", "--short", "HEAD"]).output().ok()
+                .filter(|output| output.status.success())
+                .and_then(|output| String::from_utf8(output.stdout).ok())
+        })
+        .map(|commit| commit.trim().to_string())
+        .unwrap_or_else(|| String::from("unknown"));
+
+    println!("cargo:rustc-env=DAILYBIBLE_GIT_COMMIT={}", commit);
+}
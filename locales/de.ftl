@@ -0,0 +1,138 @@
+# Die deutschen Nachrichten des DailyBible-Bots.
+# Nachrichten, die mit MarkdownV2 formatiert werden (die Bibellese), müssen die reservierten Zeichen escapen.
+
+biblereading = *📖 Dies ist eine Erinnerung, heute in der Bibel zu lesen*:
+
+    AT: { $old_testament }
+    NT: { $new_testament }
+verse-of-the-day = ✨ Vers des Tages
+next-biblereading = *📖 Die Bibellese für morgen*:
+
+    AT: { $old_testament }
+    NT: { $new_testament }
+next-biblereading-not-found = Für morgen ist keine Bibellese eingeplant.
+biblereading-not-found = Dies ist eine Erinnerung, heute in der Bibel zu lesen.
+button-mark-read = ✅ Als gelesen markieren
+button-snooze = ⏰ Später erinnern
+marked-as-read = Gut gemacht! Du hast { $streak } Tag(e) in Folge in der Bibel gelesen.
+poll-stale = Diese Umfrage gehört zu einer früheren Erinnerung, daher wird deine Antwort nicht gezählt. Bitte beantworte die Umfrage der heutigen Erinnerung.
+snoozed = Ich erinnere dich um { $time } noch einmal.
+language-set = Die Sprache wurde auf Deutsch umgestellt.
+poll-question = Hast du heute in der Bibel gelesen?
+poll-yes = Ja
+poll-no = Nein
+not-implemented-yet = Diese Funktion wurde noch nicht implementiert.
+select-language = Bitte wählen Sie die Sprache aus, die sie einstellen möchten.
+timer-updated = Die tägliche Erinnerung wurde auf { $time } Uhr gesetzt.
+timer-unset = Die tägliche Erinnerung wurde deaktiviert
+error-timer-update = Ungültiges Format. Bitte benutze die Funktion mit einer gültigen Zeitangabe, zum Beispiel /settimer 08:00.
+biblereading-not-found-for-date = Für den { $date } ist keine Bibellese eingeplant.
+error-invalid-date = Ungültiges Datum. Bitte benutze die Funktion mit einem gültigen Datum, zum Beispiel /readingfor 09-01.
+data-deleted = Alle Daten über dich wurden gelöscht.
+no-data-to-delete = Es sind keine Daten über dich gespeichert, daher wurde nichts gelöscht.
+bible-version-set = Die Bibellese wird mit der Bibelübersetzung { $version } verlinkt.
+button-marked-read = ✅ Gelesen
+read-button-enabled = Die tägliche Erinnerung wird jetzt mit dem Knopf "Als gelesen markieren" statt mit einer Umfrage bestätigt.
+read-button-disabled = Nach der täglichen Erinnerung wird wieder eine Umfrage gesendet.
+error-read-button = Bitte benutze /readbutton on oder /readbutton off.
+bible-version-unset = Die Links zur Bibellese wurden deaktiviert.
+error-bible-version = Ungültige Bibelübersetzung. Bitte benutze ihre Abkürzung, zum Beispiel /setversion LUT.
+paused = Die täglichen Erinnerungen sind pausiert. Deine Erinnerungszeit bleibt erhalten, mit /resume geht es weiter.
+resumed = Die täglichen Erinnerungen sind wieder aktiv.
+status-timer = Die tägliche Erinnerung ist auf { $time } gesetzt.
+status-no-timer = Es ist keine tägliche Erinnerung gesetzt.
+status-paused = Die Erinnerungen sind pausiert.
+status-active = Die Erinnerungen sind aktiv.
+feedback-sent = Danke für dein Feedback! Es wurde an den Betreiber des Bots weitergeleitet.
+feedback-unavailable = Leider ist für diesen Bot kein Feedback möglich.
+error-feedback-empty = Bitte schreibe dein Feedback hinter den Befehl, zum Beispiel /feedback Danke für diesen Bot!
+group-enabled = Die täglichen Erinnerungen sind jetzt für diese Gruppe aktiviert.
+group-not-enabled = In Gruppen werden die täglichen Erinnerungen erst gesendet, nachdem sie mit /enable aktiviert wurden.
+enable-private-chat = In privaten Chats sind die täglichen Erinnerungen immer aktiviert.
+plan-started = Dein persönlicher Leseplan beginnt heute. Ab jetzt bekommst du die Bibellesen des Plans der Reihe nach, mit /startplan off folgst du wieder dem Kalender.
+plan-stopped = Die Bibellese folgt wieder dem Kalender.
+week-heading = 📅 Die Bibellesen der nächsten sieben Tage:
+week-day = { $date }: AT { $old_testament } | NT { $new_testament }
+week-day-not-found = { $date }: keine Bibellese eingeplant
+skipped-today = Heute bekommst du keine Erinnerung. Ab morgen geht es wie gewohnt weiter.
+psalm-label = Psalm
+wisdom-label = Weisheit
+no-data-to-export = Es sind keine Daten über dich gespeichert, daher gibt es nichts zu exportieren.
+data-imported = Deine Daten wurden importiert.
+error-import-invalid = Die Datei konnte nicht importiert werden. Bitte sende eine JSON-Datei, die mit /export erstellt wurde.
+error-import-no-document = Bitte antworte mit /importdata auf die JSON-Datei, die mit /export erstellt wurde, oder sende die Datei mit /importdata als Beschriftung.
+weekday-name = { $weekday ->
+    [1] Montag
+    [2] Dienstag
+    [3] Mittwoch
+    [4] Donnerstag
+    [5] Freitag
+    [6] Samstag
+   *[7] Sonntag
+}
+month-name = { $month ->
+    [1] Januar
+    [2] Februar
+    [3] März
+    [4] April
+    [5] Mai
+    [6] Juni
+    [7] Juli
+    [8] August
+    [9] September
+    [10] Oktober
+    [11] November
+   *[12] Dezember
+}
+date = { $weekday }, { $day }. { $month }
+error-poll = Bitte benutze /poll on oder /poll off.
+anonymous-poll-enabled = Die Umfrage nach der täglichen Erinnerung ist jetzt anonym. Anonyme Antworten können nicht für deine Serie gezählt werden.
+anonymous-poll-disabled = Die Umfrage nach der täglichen Erinnerung ist nicht mehr anonym (Umfragen in Gruppen sind immer anonym).
+error-anonymous-poll = Bitte benutze /pollanon on oder /pollanon off.
+history-heading = 📊 Deine Bibellesen der letzten { $days } Tage ({ $read_days } von { $days } gelesen):
+verse-text-unavailable = Der Text der Bibelstelle konnte nicht geladen werden, aber du kannst ihn hier lesen:
+error-verse-not-found = Die Bibelstelle { $reference } wurde nicht gefunden. Bitte überprüfe die Angabe.
+error-verse-reference = Bitte gib eine Bibelstelle an, zum Beispiel /verse Joh 3,16.
+error-snooze-until = Bitte benutze die Funktion mit einer späteren Uhrzeit von heute (zum Beispiel /snoozeuntil 20:00).
+reading-lines =
+    AT: { $old_testament }
+    NT: { $new_testament }
+custom-prefix-set = Deine Erinnerungen beginnen jetzt mit deiner eigenen Nachricht. Mit /setmessage ohne Text wird wieder die Standardnachricht verwendet.
+custom-prefix-unset = Deine Erinnerungen beginnen wieder mit der Standardnachricht.
+error-custom-prefix = Deine Nachricht ist zu lang. Bitte verwende höchstens { $max_length } Zeichen, zum Beispiel /setmessage Guten Morgen! Zeit zum Lesen:
+markdown-enabled = Deine Erinnerungen werden wieder formatiert (fetter Text und Links).
+markdown-disabled = Deine Erinnerungen werden jetzt als einfacher Text ohne Formatierung gesendet.
+error-markdown = Bitte benutze /markdown on oder /markdown off.
+weekly-summary = 📅 Deine Woche: Du hast an { $read_days } von 7 Tagen in der Bibel gelesen. Weiter so!
+weekly-summary-enabled = Jeden Sonntag bekommst du zusammen mit der Erinnerung eine Zusammenfassung deiner Woche.
+weekly-summary-disabled = Du bekommst keine wöchentliche Zusammenfassung mehr.
+error-weekly-summary = Bitte benutze /weeklysummary on oder /weeklysummary off.
+confirm-delete-data = Möchtest du wirklich alle Daten löschen, die über diesen Chat gespeichert sind? Das kann nicht rückgängig gemacht werden.
+button-delete-confirm = 🗑 Ja, löschen
+button-delete-cancel = Abbrechen
+deletion-cancelled = Deine Daten wurden nicht gelöscht.
+deletion-expired = Die Bestätigung ist abgelaufen, deine Daten wurden nicht gelöscht. Bitte benutze /deletemydata erneut.
+next-reminder-today = Deine nächste Erinnerung kommt heute um { $time }.
+next-reminder-tomorrow = Deine nächste Erinnerung kommt morgen um { $time }.
+next-reminder-on = Deine nächste Erinnerung kommt am { $date } um { $time }.
+next-reminder-no-timer = Es ist kein täglicher Timer eingestellt, daher bekommst du keine Erinnerungen. Benutze /settimer, um einen einzustellen.
+next-reminder-paused = Deine Erinnerungen sind pausiert. Benutze /resume, um sie wieder zu bekommen.
+user-information-header = Die folgenden Daten über dich sind auf dem Server gespeichert:
+no-data = Auf dem Server sind derzeit keine Daten über dich gespeichert.
+random-reading = Hier ist ein zufälliger Abschnitt:
+button-listen-old-testament = 🔊 Anhören (AT)
+button-listen-new-testament = 🔊 Anhören (NT)
+about =
+    DailyBible { $version } (Commit { $commit })
+    Sprachen: { $languages }
+    Quellcode:
+available-plans =
+    Die folgenden Lesepläne sind verfügbar: { $plans }
+    Dein aktueller Plan ist { $current }. Um ihn zu ändern, nutze /setplan gefolgt vom Namen des Plans.
+plan-set = Deine Lesungen kommen jetzt aus dem Leseplan { $plan }.
+error-unknown-plan = Es gibt keinen Leseplan mit dem Namen { $plan }.
+member-since = Mitglied seit { $date } ({ $days } Tage).
+member-since-unknown = Mitglied seit einem unbekannten Datum (registriert, bevor es gespeichert wurde).
+private-chat = Dies ist ein privater Chat.
+group-chat-enabled = Dies ist ein Gruppenchat, die Erinnerungen sind aktiviert.
+group-chat-not-enabled = Dies ist ein Gruppenchat, die Erinnerungen sind noch nicht aktiviert (verwende /enable).
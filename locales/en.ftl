@@ -0,0 +1,138 @@
+# The English messages of the DailyBible bot.
+# Messages which are formatted with MarkdownV2 (the Bible readings) must escape the reserved characters.
+
+biblereading = *📖 This is a reminder to read the Bible today*:
+
+    OT: { $old_testament }
+    NT: { $new_testament }
+verse-of-the-day = ✨ Verse of the day
+next-biblereading = *📖 Tomorrow's reading*:
+
+    OT: { $old_testament }
+    NT: { $new_testament }
+next-biblereading-not-found = There is no Bible reading scheduled for tomorrow.
+biblereading-not-found = This is a reminder to read your bible!
+button-mark-read = ✅ Mark as read
+button-snooze = ⏰ Remind me later
+marked-as-read = Well done! You have read the Bible { $streak } day(s) in a row.
+poll-stale = This poll belongs to an earlier reminder, so your answer is not counted. Please answer the poll of today's reminder.
+snoozed = I will remind you again at { $time }.
+language-set = Language set to English.
+poll-question = Have you read the Bible today?
+poll-yes = Yes
+poll-no = No
+not-implemented-yet = This feature has not been implemented yet.
+select-language = Please choose which language you would like to set.
+timer-updated = The daily timer has been updated to { $time }.
+timer-unset = The daily timer has been unset
+error-timer-update = The format was not valid. Please use the function with a valid time (for example /settimer 08:00).
+biblereading-not-found-for-date = There is no Bible reading scheduled for { $date }.
+error-invalid-date = The date was not valid. Please use the function with a valid date (for example /readingfor 09-01).
+data-deleted = All data about you has been deleted.
+no-data-to-delete = There is no data saved about you, so nothing has been deleted.
+bible-version-set = The readings will be linked to the Bible version { $version }.
+button-marked-read = ✅ Read
+read-button-enabled = The daily reminder is now confirmed with the "Mark as read" button instead of a poll.
+read-button-disabled = The daily reminder is followed by a poll again.
+error-read-button = Please use /readbutton on or /readbutton off.
+bible-version-unset = The links to the readings have been disabled.
+error-bible-version = The Bible version was not valid. Please use its abbreviation, for example /setversion ESV.
+paused = The daily reminders are paused. Your timer is kept, use /resume to continue.
+resumed = The daily reminders are active again.
+status-timer = The daily timer is set to { $time }.
+status-no-timer = No daily timer is set.
+status-paused = The reminders are paused.
+status-active = The reminders are active.
+feedback-sent = Thank you for your feedback! It has been forwarded to the operator of the bot.
+feedback-unavailable = Sorry, feedback is not available for this bot.
+error-feedback-empty = Please write your feedback after the command, for example /feedback Thank you for this bot!
+group-enabled = The daily reminders are now enabled for this group.
+group-not-enabled = In groups the daily reminders are only sent after they have been enabled with /enable.
+enable-private-chat = In private chats the daily reminders are always enabled.
+plan-started = Your personal reading plan starts today. From now on you receive the readings of the schedule one after another, use /startplan off to follow the calendar again.
+plan-stopped = The readings follow the calendar again.
+week-heading = 📅 The readings of the next seven days:
+week-day = { $date }: OT { $old_testament } | NT { $new_testament }
+week-day-not-found = { $date }: no reading scheduled
+skipped-today = You will not receive a reminder today. Tomorrow the reminders continue as usual.
+psalm-label = Psalm
+wisdom-label = Wisdom
+no-data-to-export = There is no data saved about you, so there is nothing to export.
+data-imported = Your data has been imported.
+error-import-invalid = The file could not be imported. Please send a JSON file which has been created with /export.
+error-import-no-document = Please reply with /importdata to the JSON file which has been created with /export, or send the file with /importdata as caption.
+weekday-name = { $weekday ->
+    [1] Monday
+    [2] Tuesday
+    [3] Wednesday
+    [4] Thursday
+    [5] Friday
+    [6] Saturday
+   *[7] Sunday
+}
+month-name = { $month ->
+    [1] January
+    [2] February
+    [3] March
+    [4] April
+    [5] May
+    [6] June
+    [7] July
+    [8] August
+    [9] September
+    [10] October
+    [11] November
+   *[12] December
+}
+date = { $weekday }, { $day } { $month }
+error-poll = Please use /poll on or /poll off.
+anonymous-poll-enabled = The poll after the daily reminder is now anonymous. Anonymous answers can not be counted for your streak.
+anonymous-poll-disabled = The poll after the daily reminder is no longer anonymous (polls in groups are always anonymous).
+error-anonymous-poll = Please use /pollanon on or /pollanon off.
+history-heading = 📊 Your readings of the last { $days } days ({ $read_days } of { $days } read):
+verse-text-unavailable = The text of the passage could not be loaded, but you can read it here:
+error-verse-not-found = The passage { $reference } could not be found. Please check the reference.
+error-verse-reference = Please add a Bible reference, for example /verse John 3,16.
+error-snooze-until = Please use the function with a later time of today (for example /snoozeuntil 20:00).
+reading-lines =
+    OT: { $old_testament }
+    NT: { $new_testament }
+custom-prefix-set = Your reminders now start with your own message. Use /setmessage without text to restore the default.
+custom-prefix-unset = Your reminders start with the default message again.
+error-custom-prefix = Your message is too long. Please use at most { $max_length } characters, for example /setmessage Good morning! Time to read:
+markdown-enabled = Your reminders are formatted again (bold text and links).
+markdown-disabled = Your reminders are now sent as plain text without formatting.
+error-markdown = Please use /markdown on or /markdown off.
+weekly-summary = 📅 Your week: you have read the Bible on { $read_days } of 7 days. Keep it up!
+weekly-summary-enabled = Every Sunday you will receive a summary of your week together with the reminder.
+weekly-summary-disabled = You will no longer receive a weekly summary.
+error-weekly-summary = Please use /weeklysummary on or /weeklysummary off.
+confirm-delete-data = Do you really want to delete all data which is saved about this chat? This can not be undone.
+button-delete-confirm = 🗑 Yes, delete
+button-delete-cancel = Cancel
+deletion-cancelled = Your data has not been deleted.
+deletion-expired = The confirmation has expired, your data has not been deleted. Please use /deletemydata again.
+next-reminder-today = Your next reminder is today at { $time }.
+next-reminder-tomorrow = Your next reminder is tomorrow at { $time }.
+next-reminder-on = Your next reminder is on { $date } at { $time }.
+next-reminder-no-timer = No daily timer is set, so you will not receive reminders. Use /settimer to set one.
+next-reminder-paused = Your reminders are paused. Use /resume to receive them again.
+user-information-header = The following data about you is saved on the server:
+no-data = There is currently no data saved on the server concerning you.
+random-reading = Here's a random passage:
+button-listen-old-testament = 🔊 Listen (OT)
+button-listen-new-testament = 🔊 Listen (NT)
+about =
+    DailyBible { $version } (commit { $commit })
+    Languages: { $languages }
+    Source code:
+available-plans =
+    The following reading plans are available: { $plans }
+    Your current plan is { $current }. To change it, use /setplan followed by the name of the plan.
+plan-set = Your readings are now taken from the reading plan { $plan }.
+error-unknown-plan = There is no reading plan named { $plan }.
+member-since = Member since { $date } ({ $days } days).
+member-since-unknown = Member since an unknown date (registered before this was recorded).
+private-chat = This is a private chat.
+group-chat-enabled = This is a group chat, the reminders are enabled.
+group-chat-not-enabled = This is a group chat, the reminders are not enabled yet (use /enable).
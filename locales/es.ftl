@@ -0,0 +1,138 @@
+# Los mensajes en español del bot DailyBible.
+# Los mensajes con formato MarkdownV2 (las lecturas bíblicas) deben escapar los caracteres reservados.
+
+biblereading = *📖 Este es un recordatorio para leer la Biblia hoy*:
+
+    AT: { $old_testament }
+    NT: { $new_testament }
+verse-of-the-day = ✨ Versículo del día
+next-biblereading = *📖 La lectura de mañana*:
+
+    AT: { $old_testament }
+    NT: { $new_testament }
+next-biblereading-not-found = No hay ninguna lectura bíblica programada para mañana.
+biblereading-not-found = Este es un recordatorio para leer la Biblia hoy.
+button-mark-read = ✅ Marcar como leído
+button-snooze = ⏰ Recuérdame más tarde
+marked-as-read = ¡Bien hecho! Has leído la Biblia { $streak } día(s) seguidos.
+poll-stale = Esta encuesta pertenece a un recordatorio anterior, por lo que tu respuesta no se cuenta. Por favor, responde la encuesta del recordatorio de hoy.
+snoozed = Te lo recordaré de nuevo a las { $time }.
+language-set = El idioma se ha cambiado a español.
+poll-question = ¿Has leído la Biblia hoy?
+poll-yes = Sí
+poll-no = No
+not-implemented-yet = Esta función aún no se ha implementado.
+select-language = Por favor, elige el idioma que deseas configurar.
+timer-updated = El recordatorio diario se ha configurado a las { $time }.
+timer-unset = El recordatorio diario se ha desactivado
+error-timer-update = El formato no es válido. Por favor, usa la función con una hora válida (por ejemplo /settimer 08:00).
+biblereading-not-found-for-date = No hay ninguna lectura bíblica programada para el { $date }.
+error-invalid-date = La fecha no es válida. Por favor, usa la función con una fecha válida (por ejemplo /readingfor 09-01).
+data-deleted = Se han eliminado todos tus datos.
+no-data-to-delete = No hay datos guardados sobre ti, así que no se ha eliminado nada.
+bible-version-set = Las lecturas se enlazarán con la versión de la Biblia { $version }.
+button-marked-read = ✅ Leído
+read-button-enabled = El recordatorio diario ahora se confirma con el botón "Marcar como leído" en lugar de una encuesta.
+read-button-disabled = Después del recordatorio diario se vuelve a enviar una encuesta.
+error-read-button = Por favor, usa /readbutton on o /readbutton off.
+bible-version-unset = Los enlaces a las lecturas se han desactivado.
+error-bible-version = La versión de la Biblia no es válida. Por favor, usa su abreviatura, por ejemplo /setversion RVR1960.
+paused = Los recordatorios diarios están en pausa. Tu hora se conserva, usa /resume para continuar.
+resumed = Los recordatorios diarios vuelven a estar activos.
+status-timer = El recordatorio diario está configurado a las { $time }.
+status-no-timer = No hay ningún recordatorio diario configurado.
+status-paused = Los recordatorios están en pausa.
+status-active = Los recordatorios están activos.
+feedback-sent = ¡Gracias por tus comentarios! Se han enviado al operador del bot.
+feedback-unavailable = Lo sentimos, este bot no admite comentarios.
+error-feedback-empty = Por favor, escribe tus comentarios después del comando, por ejemplo /feedback ¡Gracias por este bot!
+group-enabled = Los recordatorios diarios ya están activados para este grupo.
+group-not-enabled = En los grupos, los recordatorios diarios solo se envían después de activarlos con /enable.
+enable-private-chat = En los chats privados, los recordatorios diarios siempre están activados.
+plan-started = Tu plan de lectura personal empieza hoy. A partir de ahora recibirás las lecturas del plan una tras otra, usa /startplan off para seguir el calendario de nuevo.
+plan-stopped = Las lecturas vuelven a seguir el calendario.
+week-heading = 📅 Las lecturas de los próximos siete días:
+week-day = { $date }: AT { $old_testament } | NT { $new_testament }
+week-day-not-found = { $date }: ninguna lectura programada
+skipped-today = Hoy no recibirás ningún recordatorio. Mañana los recordatorios continúan como siempre.
+psalm-label = Salmo
+wisdom-label = Sabiduría
+no-data-to-export = No hay datos guardados sobre ti, así que no hay nada que exportar.
+data-imported = Tus datos han sido importados.
+error-import-invalid = No se pudo importar el archivo. Por favor, envía un archivo JSON creado con /export.
+error-import-no-document = Por favor, responde con /importdata al archivo JSON creado con /export, o envía el archivo con /importdata como descripción.
+weekday-name = { $weekday ->
+    [1] lunes
+    [2] martes
+    [3] miércoles
+    [4] jueves
+    [5] viernes
+    [6] sábado
+   *[7] domingo
+}
+month-name = { $month ->
+    [1] enero
+    [2] febrero
+    [3] marzo
+    [4] abril
+    [5] mayo
+    [6] junio
+    [7] julio
+    [8] agosto
+    [9] septiembre
+    [10] octubre
+    [11] noviembre
+   *[12] diciembre
+}
+date = { $weekday }, { $day } de { $month }
+error-poll = Por favor, usa /poll on o /poll off.
+anonymous-poll-enabled = La encuesta después del recordatorio diario ahora es anónima. Las respuestas anónimas no se cuentan para tu racha.
+anonymous-poll-disabled = La encuesta después del recordatorio diario ya no es anónima (las encuestas en grupos siempre son anónimas).
+error-anonymous-poll = Por favor, usa /pollanon on o /pollanon off.
+history-heading = 📊 Tus lecturas de los últimos { $days } días ({ $read_days } de { $days } leídas):
+verse-text-unavailable = No se pudo cargar el texto del pasaje, pero puedes leerlo aquí:
+error-verse-not-found = No se encontró el pasaje { $reference }. Por favor, revisa la referencia.
+error-verse-reference = Por favor, indica una referencia bíblica, por ejemplo /verse Juan 3,16.
+error-snooze-until = Por favor, usa la función con una hora posterior de hoy (por ejemplo /snoozeuntil 20:00).
+reading-lines =
+    AT: { $old_testament }
+    NT: { $new_testament }
+custom-prefix-set = Tus recordatorios ahora empiezan con tu propio mensaje. Usa /setmessage sin texto para volver al mensaje predeterminado.
+custom-prefix-unset = Tus recordatorios vuelven a empezar con el mensaje predeterminado.
+error-custom-prefix = Tu mensaje es demasiado largo. Por favor, usa como máximo { $max_length } caracteres, por ejemplo /setmessage ¡Buenos días! Hora de leer:
+markdown-enabled = Tus recordatorios vuelven a tener formato (texto en negrita y enlaces).
+markdown-disabled = Tus recordatorios ahora se envían como texto simple sin formato.
+error-markdown = Por favor, usa /markdown on o /markdown off.
+weekly-summary = 📅 Tu semana: has leído la Biblia { $read_days } de 7 días. ¡Sigue así!
+weekly-summary-enabled = Cada domingo recibirás un resumen de tu semana junto con el recordatorio.
+weekly-summary-disabled = Ya no recibirás un resumen semanal.
+error-weekly-summary = Por favor, usa /weeklysummary on o /weeklysummary off.
+confirm-delete-data = ¿De verdad quieres borrar todos los datos guardados sobre este chat? No se puede deshacer.
+button-delete-confirm = 🗑 Sí, borrar
+button-delete-cancel = Cancelar
+deletion-cancelled = Tus datos no han sido borrados.
+deletion-expired = La confirmación ha caducado, tus datos no han sido borrados. Por favor, usa /deletemydata de nuevo.
+next-reminder-today = Tu próximo recordatorio es hoy a las { $time }.
+next-reminder-tomorrow = Tu próximo recordatorio es mañana a las { $time }.
+next-reminder-on = Tu próximo recordatorio es el { $date } a las { $time }.
+next-reminder-no-timer = No hay ningún temporizador diario, así que no recibirás recordatorios. Usa /settimer para configurar uno.
+next-reminder-paused = Tus recordatorios están en pausa. Usa /resume para recibirlos de nuevo.
+user-information-header = Los siguientes datos sobre ti están guardados en el servidor:
+no-data = Actualmente no hay datos guardados sobre ti en el servidor.
+random-reading = Aquí tienes un pasaje al azar:
+button-listen-old-testament = 🔊 Escuchar (AT)
+button-listen-new-testament = 🔊 Escuchar (NT)
+about =
+    DailyBible { $version } (commit { $commit })
+    Idiomas: { $languages }
+    Código fuente:
+available-plans =
+    Están disponibles los siguientes planes de lectura: { $plans }
+    Tu plan actual es { $current }. Para cambiarlo, usa /setplan seguido del nombre del plan.
+plan-set = Tus lecturas ahora se toman del plan de lectura { $plan }.
+error-unknown-plan = No existe ningún plan de lectura llamado { $plan }.
+member-since = Miembro desde { $date } ({ $days } días).
+member-since-unknown = Miembro desde una fecha desconocida (registrado antes de que se guardara).
+private-chat = Este es un chat privado.
+group-chat-enabled = Este es un chat de grupo, los recordatorios están activados.
+group-chat-not-enabled = Este es un chat de grupo, los recordatorios aún no están activados (usa /enable).
@@ -0,0 +1,138 @@
+# I messaggi in italiano del bot DailyBible.
+# I messaggi in formato MarkdownV2 (le letture bibliche) devono fare l'escape dei caratteri riservati.
+
+biblereading = *📖 Questo è un promemoria per leggere la Bibbia oggi*:
+
+    AT: { $old_testament }
+    NT: { $new_testament }
+verse-of-the-day = ✨ Versetto del giorno
+next-biblereading = *📖 La lettura di domani*:
+
+    AT: { $old_testament }
+    NT: { $new_testament }
+next-biblereading-not-found = Non c'è nessuna lettura biblica prevista per domani.
+biblereading-not-found = Questo è un promemoria per leggere la Bibbia oggi.
+button-mark-read = ✅ Segna come letto
+button-snooze = ⏰ Ricordamelo più tardi
+marked-as-read = Ben fatto! Hai letto la Bibbia per { $streak } giorno/i di fila.
+poll-stale = Questo sondaggio appartiene a un promemoria precedente, quindi la tua risposta non viene contata. Per favore, rispondi al sondaggio del promemoria di oggi.
+snoozed = Te lo ricorderò di nuovo alle { $time }.
+language-set = La lingua è stata impostata sull'italiano.
+poll-question = Hai letto la Bibbia oggi?
+poll-yes = Sì
+poll-no = No
+not-implemented-yet = Questa funzione non è ancora stata implementata.
+select-language = Per favore, scegli la lingua che vuoi impostare.
+timer-updated = Il promemoria giornaliero è stato impostato alle { $time }.
+timer-unset = Il promemoria giornaliero è stato disattivato
+error-timer-update = Il formato non è valido. Per favore, usa la funzione con un orario valido (ad esempio /settimer 08:00).
+biblereading-not-found-for-date = Non c'è nessuna lettura biblica prevista per il { $date }.
+error-invalid-date = La data non è valida. Per favore, usa la funzione con una data valida (ad esempio /readingfor 09-01).
+data-deleted = Tutti i tuoi dati sono stati eliminati.
+no-data-to-delete = Non ci sono dati salvati su di te, quindi non è stato eliminato nulla.
+bible-version-set = Le letture verranno collegate alla versione della Bibbia { $version }.
+button-marked-read = ✅ Letto
+read-button-enabled = Il promemoria giornaliero ora si conferma con il pulsante "Segna come letto" invece che con un sondaggio.
+read-button-disabled = Dopo il promemoria giornaliero viene di nuovo inviato un sondaggio.
+error-read-button = Per favore, usa /readbutton on o /readbutton off.
+bible-version-unset = I link alle letture sono stati disattivati.
+error-bible-version = La versione della Bibbia non è valida. Per favore, usa la sua abbreviazione, ad esempio /setversion CEI.
+paused = I promemoria giornalieri sono in pausa. Il tuo orario viene mantenuto, usa /resume per continuare.
+resumed = I promemoria giornalieri sono di nuovo attivi.
+status-timer = Il promemoria giornaliero è impostato alle { $time }.
+status-no-timer = Non è impostato nessun promemoria giornaliero.
+status-paused = I promemoria sono in pausa.
+status-active = I promemoria sono attivi.
+feedback-sent = Grazie per il tuo feedback! È stato inviato al gestore del bot.
+feedback-unavailable = Spiacenti, questo bot non accetta feedback.
+error-feedback-empty = Per favore, scrivi il tuo feedback dopo il comando, ad esempio /feedback Grazie per questo bot!
+group-enabled = I promemoria giornalieri sono ora attivati per questo gruppo.
+group-not-enabled = Nei gruppi, i promemoria giornalieri vengono inviati solo dopo averli attivati con /enable.
+enable-private-chat = Nelle chat private, i promemoria giornalieri sono sempre attivati.
+plan-started = Il tuo piano di lettura personale inizia oggi. D'ora in poi riceverai le letture del piano una dopo l'altra, usa /startplan off per seguire di nuovo il calendario.
+plan-stopped = Le letture seguono di nuovo il calendario.
+week-heading = 📅 Le letture dei prossimi sette giorni:
+week-day = { $date }: AT { $old_testament } | NT { $new_testament }
+week-day-not-found = { $date }: nessuna lettura prevista
+skipped-today = Oggi non riceverai nessun promemoria. Domani i promemoria continuano come sempre.
+psalm-label = Salmo
+wisdom-label = Sapienza
+no-data-to-export = Non ci sono dati salvati su di te, quindi non c'è nulla da esportare.
+data-imported = I tuoi dati sono stati importati.
+error-import-invalid = Non è stato possibile importare il file. Per favore, invia un file JSON creato con /export.
+error-import-no-document = Per favore, rispondi con /importdata al file JSON creato con /export, oppure invia il file con /importdata come didascalia.
+weekday-name = { $weekday ->
+    [1] lunedì
+    [2] martedì
+    [3] mercoledì
+    [4] giovedì
+    [5] venerdì
+    [6] sabato
+   *[7] domenica
+}
+month-name = { $month ->
+    [1] gennaio
+    [2] febbraio
+    [3] marzo
+    [4] aprile
+    [5] maggio
+    [6] giugno
+    [7] luglio
+    [8] agosto
+    [9] settembre
+    [10] ottobre
+    [11] novembre
+   *[12] dicembre
+}
+date = { $weekday } { $day } { $month }
+error-poll = Per favore, usa /poll on o /poll off.
+anonymous-poll-enabled = Il sondaggio dopo il promemoria giornaliero ora è anonimo. Le risposte anonime non contano per la tua serie.
+anonymous-poll-disabled = Il sondaggio dopo il promemoria giornaliero non è più anonimo (i sondaggi nei gruppi sono sempre anonimi).
+error-anonymous-poll = Per favore, usa /pollanon on o /pollanon off.
+history-heading = 📊 Le tue letture degli ultimi { $days } giorni ({ $read_days } su { $days } lette):
+verse-text-unavailable = Non è stato possibile caricare il testo del brano, ma puoi leggerlo qui:
+error-verse-not-found = Il brano { $reference } non è stato trovato. Per favore, controlla il riferimento.
+error-verse-reference = Per favore, indica un riferimento biblico, ad esempio /verse Giovanni 3,16.
+error-snooze-until = Per favore, usa la funzione con un orario successivo di oggi (ad esempio /snoozeuntil 20:00).
+reading-lines =
+    AT: { $old_testament }
+    NT: { $new_testament }
+custom-prefix-set = I tuoi promemoria ora iniziano con il tuo messaggio. Usa /setmessage senza testo per tornare al messaggio predefinito.
+custom-prefix-unset = I tuoi promemoria iniziano di nuovo con il messaggio predefinito.
+error-custom-prefix = Il tuo messaggio è troppo lungo. Per favore, usa al massimo { $max_length } caratteri, ad esempio /setmessage Buongiorno! È ora di leggere:
+markdown-enabled = I tuoi promemoria sono di nuovo formattati (testo in grassetto e link).
+markdown-disabled = I tuoi promemoria ora vengono inviati come testo semplice senza formattazione.
+error-markdown = Per favore, usa /markdown on o /markdown off.
+weekly-summary = 📅 La tua settimana: hai letto la Bibbia in { $read_days } giorni su 7. Continua così!
+weekly-summary-enabled = Ogni domenica riceverai un riepilogo della tua settimana insieme al promemoria.
+weekly-summary-disabled = Non riceverai più un riepilogo settimanale.
+error-weekly-summary = Per favore, usa /weeklysummary on o /weeklysummary off.
+confirm-delete-data = Vuoi davvero eliminare tutti i dati salvati su questa chat? L'operazione non può essere annullata.
+button-delete-confirm = 🗑 Sì, elimina
+button-delete-cancel = Annulla
+deletion-cancelled = I tuoi dati non sono stati eliminati.
+deletion-expired = La conferma è scaduta, i tuoi dati non sono stati eliminati. Per favore, usa di nuovo /deletemydata.
+next-reminder-today = Il tuo prossimo promemoria è oggi alle { $time }.
+next-reminder-tomorrow = Il tuo prossimo promemoria è domani alle { $time }.
+next-reminder-on = Il tuo prossimo promemoria è { $date } alle { $time }.
+next-reminder-no-timer = Non c'è nessun orario giornaliero, quindi non riceverai promemoria. Usa /settimer per impostarne uno.
+next-reminder-paused = I tuoi promemoria sono in pausa. Usa /resume per riceverli di nuovo.
+user-information-header = I seguenti dati su di te sono salvati sul server:
+no-data = Al momento non ci sono dati salvati su di te sul server.
+random-reading = Ecco un brano a caso:
+button-listen-old-testament = 🔊 Ascolta (AT)
+button-listen-new-testament = 🔊 Ascolta (NT)
+about =
+    DailyBible { $version } (commit { $commit })
+    Lingue: { $languages }
+    Codice sorgente:
+available-plans =
+    Sono disponibili i seguenti piani di lettura: { $plans }
+    Il tuo piano attuale è { $current }. Per cambiarlo, usa /setplan seguito dal nome del piano.
+plan-set = Le tue letture ora provengono dal piano di lettura { $plan }.
+error-unknown-plan = Non esiste nessun piano di lettura chiamato { $plan }.
+member-since = Membro dal { $date } ({ $days } giorni).
+member-since-unknown = Membro da una data sconosciuta (registrato prima che venisse salvata).
+private-chat = Questa è una chat privata.
+group-chat-enabled = Questa è una chat di gruppo, i promemoria sono attivati.
+group-chat-not-enabled = Questa è una chat di gruppo, i promemoria non sono ancora attivati (usa /enable).
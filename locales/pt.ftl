@@ -0,0 +1,138 @@
+# As mensagens em português do bot DailyBible.
+# As mensagens com formato MarkdownV2 (as leituras bíblicas) devem escapar os caracteres reservados.
+
+biblereading = *📖 Este é um lembrete para ler a Bíblia hoje*:
+
+    AT: { $old_testament }
+    NT: { $new_testament }
+verse-of-the-day = ✨ Versículo do dia
+next-biblereading = *📖 A leitura de amanhã*:
+
+    AT: { $old_testament }
+    NT: { $new_testament }
+next-biblereading-not-found = Não há nenhuma leitura bíblica programada para amanhã.
+biblereading-not-found = Este é um lembrete para ler a Bíblia hoje.
+button-mark-read = ✅ Marcar como lido
+button-snooze = ⏰ Lembre-me mais tarde
+marked-as-read = Muito bem! Você leu a Bíblia { $streak } dia(s) seguidos.
+poll-stale = Esta enquete pertence a um lembrete anterior, por isso a sua resposta não é contada. Por favor, responda à enquete do lembrete de hoje.
+snoozed = Vou lembrá-lo novamente às { $time }.
+language-set = O idioma foi alterado para português.
+poll-question = Você leu a Bíblia hoje?
+poll-yes = Sim
+poll-no = Não
+not-implemented-yet = Esta função ainda não foi implementada.
+select-language = Por favor, escolha o idioma que deseja configurar.
+timer-updated = O lembrete diário foi configurado para as { $time }.
+timer-unset = O lembrete diário foi desativado
+error-timer-update = O formato não é válido. Por favor, use a função com um horário válido (por exemplo /settimer 08:00).
+biblereading-not-found-for-date = Não há nenhuma leitura bíblica programada para { $date }.
+error-invalid-date = A data não é válida. Por favor, use a função com uma data válida (por exemplo /readingfor 09-01).
+data-deleted = Todos os seus dados foram apagados.
+no-data-to-delete = Não há dados guardados sobre você, por isso nada foi apagado.
+bible-version-set = As leituras serão ligadas à versão da Bíblia { $version }.
+button-marked-read = ✅ Lido
+read-button-enabled = O lembrete diário agora é confirmado com o botão "Marcar como lido" em vez de uma enquete.
+read-button-disabled = Depois do lembrete diário volta a ser enviada uma enquete.
+error-read-button = Por favor, use /readbutton on ou /readbutton off.
+bible-version-unset = Os links para as leituras foram desativados.
+error-bible-version = A versão da Bíblia não é válida. Por favor, use a sua abreviatura, por exemplo /setversion ARC.
+paused = Os lembretes diários estão em pausa. O seu horário é mantido, use /resume para continuar.
+resumed = Os lembretes diários estão ativos novamente.
+status-timer = O lembrete diário está configurado para as { $time }.
+status-no-timer = Não há nenhum lembrete diário configurado.
+status-paused = Os lembretes estão em pausa.
+status-active = Os lembretes estão ativos.
+feedback-sent = Obrigado pelo seu comentário! Ele foi enviado ao operador do bot.
+feedback-unavailable = Lamentamos, este bot não aceita comentários.
+error-feedback-empty = Por favor, escreva o seu comentário depois do comando, por exemplo /feedback Obrigado por este bot!
+group-enabled = Os lembretes diários estão agora ativados para este grupo.
+group-not-enabled = Nos grupos, os lembretes diários só são enviados depois de ativá-los com /enable.
+enable-private-chat = Nos chats privados, os lembretes diários estão sempre ativados.
+plan-started = O seu plano de leitura pessoal começa hoje. A partir de agora você receberá as leituras do plano uma após a outra, use /startplan off para seguir o calendário novamente.
+plan-stopped = As leituras voltam a seguir o calendário.
+week-heading = 📅 As leituras dos próximos sete dias:
+week-day = { $date }: AT { $old_testament } | NT { $new_testament }
+week-day-not-found = { $date }: nenhuma leitura programada
+skipped-today = Hoje você não receberá nenhum lembrete. Amanhã os lembretes continuam como sempre.
+psalm-label = Salmo
+wisdom-label = Sabedoria
+no-data-to-export = Não há dados guardados sobre você, por isso não há nada para exportar.
+data-imported = Os seus dados foram importados.
+error-import-invalid = Não foi possível importar o arquivo. Por favor, envie um arquivo JSON criado com /export.
+error-import-no-document = Por favor, responda com /importdata ao arquivo JSON criado com /export, ou envie o arquivo com /importdata como legenda.
+weekday-name = { $weekday ->
+    [1] segunda-feira
+    [2] terça-feira
+    [3] quarta-feira
+    [4] quinta-feira
+    [5] sexta-feira
+    [6] sábado
+   *[7] domingo
+}
+month-name = { $month ->
+    [1] janeiro
+    [2] fevereiro
+    [3] março
+    [4] abril
+    [5] maio
+    [6] junho
+    [7] julho
+    [8] agosto
+    [9] setembro
+    [10] outubro
+    [11] novembro
+   *[12] dezembro
+}
+date = { $weekday }, { $day } de { $month }
+error-poll = Por favor, use /poll on ou /poll off.
+anonymous-poll-enabled = A enquete depois do lembrete diário agora é anônima. As respostas anônimas não contam para a sua sequência.
+anonymous-poll-disabled = A enquete depois do lembrete diário já não é anônima (as enquetes em grupos são sempre anônimas).
+error-anonymous-poll = Por favor, use /pollanon on ou /pollanon off.
+history-heading = 📊 As suas leituras dos últimos { $days } dias ({ $read_days } de { $days } lidas):
+verse-text-unavailable = Não foi possível carregar o texto da passagem, mas você pode lê-lo aqui:
+error-verse-not-found = A passagem { $reference } não foi encontrada. Por favor, verifique a referência.
+error-verse-reference = Por favor, indique uma referência bíblica, por exemplo /verse João 3,16.
+error-snooze-until = Por favor, use a função com um horário posterior de hoje (por exemplo /snoozeuntil 20:00).
+reading-lines =
+    AT: { $old_testament }
+    NT: { $new_testament }
+custom-prefix-set = Os seus lembretes agora começam com a sua própria mensagem. Use /setmessage sem texto para voltar à mensagem padrão.
+custom-prefix-unset = Os seus lembretes voltam a começar com a mensagem padrão.
+error-custom-prefix = A sua mensagem é demasiado longa. Por favor, use no máximo { $max_length } caracteres, por exemplo /setmessage Bom dia! Hora de ler:
+markdown-enabled = Os seus lembretes voltam a ter formatação (texto em negrito e links).
+markdown-disabled = Os seus lembretes agora são enviados como texto simples sem formatação.
+error-markdown = Por favor, use /markdown on ou /markdown off.
+weekly-summary = 📅 A sua semana: você leu a Bíblia em { $read_days } de 7 dias. Continue assim!
+weekly-summary-enabled = Todos os domingos você receberá um resumo da sua semana junto com o lembrete.
+weekly-summary-disabled = Você já não receberá um resumo semanal.
+error-weekly-summary = Por favor, use /weeklysummary on ou /weeklysummary off.
+confirm-delete-data = Quer mesmo apagar todos os dados guardados sobre este chat? Não é possível desfazer.
+button-delete-confirm = 🗑 Sim, apagar
+button-delete-cancel = Cancelar
+deletion-cancelled = Os seus dados não foram apagados.
+deletion-expired = A confirmação expirou, os seus dados não foram apagados. Por favor, use /deletemydata novamente.
+next-reminder-today = O seu próximo lembrete é hoje às { $time }.
+next-reminder-tomorrow = O seu próximo lembrete é amanhã às { $time }.
+next-reminder-on = O seu próximo lembrete é { $date } às { $time }.
+next-reminder-no-timer = Não há nenhum horário diário, por isso você não receberá lembretes. Use /settimer para configurar um.
+next-reminder-paused = Os seus lembretes estão em pausa. Use /resume para recebê-los novamente.
+user-information-header = Os seguintes dados sobre você estão guardados no servidor:
+no-data = Atualmente não há dados guardados sobre você no servidor.
+random-reading = Aqui está uma passagem aleatória:
+button-listen-old-testament = 🔊 Ouvir (AT)
+button-listen-new-testament = 🔊 Ouvir (NT)
+about =
+    DailyBible { $version } (commit { $commit })
+    Idiomas: { $languages }
+    Código-fonte:
+available-plans =
+    Os seguintes planos de leitura estão disponíveis: { $plans }
+    O seu plano atual é { $current }. Para alterá-lo, use /setplan seguido do nome do plano.
+plan-set = As suas leituras agora vêm do plano de leitura { $plan }.
+error-unknown-plan = Não existe nenhum plano de leitura chamado { $plan }.
+member-since = Membro desde { $date } ({ $days } dias).
+member-since-unknown = Membro desde uma data desconhecida (registrado antes de isso ser guardado).
+private-chat = Este é um chat privado.
+group-chat-enabled = Este é um chat de grupo, os lembretes estão ativados.
+group-chat-not-enabled = Este é um chat de grupo, os lembretes ainda não estão ativados (use /enable).
@@ -1,126 +1,1654 @@
-/// In this unit, all the logic for the bible reading references is going to be implemented.
+//! In this unit, all the logic for the bible reading references is going to be implemented.
 
 use core::fmt;
+use std::{collections::HashMap, env, fs, path::Path, sync::{Arc, OnceLock, PoisonError, RwLock}, time::SystemTime};
 
-use chrono::{Local, NaiveDate};
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+use rand::{seq::SliceRandom, Rng};
+use url::Url;
+
+use crate::error::DailyBibleError;
+
+/// The default file path of the schedule file which contains the bible readings
+const DEFAULT_SCHEDULE_FILE_PATH: &str = "schedule.csv";
+
+/// The name of the environment variable where the path of the schedule file can be specified.
+/// If it is not set, `DEFAULT_SCHEDULE_FILE_PATH` is used.
+pub const SCHEDULE_FILE_ENV: &str = "DAILYBIBLE_SCHEDULE_FILE";
+
+/// The default directory which contains the schedule files of several reading plans
+const DEFAULT_SCHEDULES_DIRECTORY: &str = "schedules";
+
+/// The name of the environment variable where the directory with the schedule files of several reading plans can be
+/// specified. Every `.csv` file in it is a plan which is named after the file (`chronological.csv` is `chronological`).
+/// If the directory contains no schedule, the single file of `SCHEDULE_FILE_ENV` is used.
+pub const SCHEDULES_DIR_ENV: &str = "DAILYBIBLE_SCHEDULES_DIR";
+
+/// The schedules of all reading plans by their name, which are read at startup (see `load_plans`).
+/// The plans are indexed again and a changed schedule is swapped in by `reload_plans` once it has been validated.
+static PLANS: OnceLock<RwLock<HashMap<String, Arc<RwLock<Schedule>>>>> = OnceLock::new();
+
+/// The default format of the dates in the first column of the schedule file (for example `09-01-24`)
+pub const DEFAULT_DATE_FORMAT: &str = "%m-%d-%y";
+
+/// The name of the environment variable where the format of the dates in the schedule file can be specified
+/// (for example `%d.%m` or `%m-%d`). If it is not set, `DEFAULT_DATE_FORMAT` is used.
+pub const SCHEDULE_DATE_FORMAT_ENV: &str = "SCHEDULE_DATE_FORMAT";
+
+/// The name of the environment variable where the kind of the schedule (`annual` or `weekly`) can be specified.
+/// If it is not set, the kind is detected from the header of the schedule file.
+pub const SCHEDULE_KIND_ENV: &str = "SCHEDULE_KIND";
+
+/// The number of days which are contained in the readings of a week (see `get_biblereadings_for_week`)
+pub const DAYS_PER_WEEK: i64 = 7;
+
+/// A leap year which is used to validate month-day strings, so that `02-29` is always accepted as input
+pub(crate) const LEAP_YEAR: i32 = 2000;
+
+/// The name of the environment variable which sets how the reading of Feb 29 is handled in years without Feb 29
+/// (`skip`, `feb28` or `mar1`, see `LeapDay`). If it is not set, the reading is skipped.
+pub const LEAP_DAY_ENV: &str = "DAILYBIBLE_LEAP_DAY";
+
+/// How the reading of Feb 29 is handled in years without Feb 29. The reading of Feb 29 is the row of Feb 29
+/// in `LEAP_YEAR`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LeapDay {
+    /// The reading of Feb 29 is left out (the default)
+    Skip,
+    /// The reading of Feb 29 is read on Feb 28 after the reading of Feb 28
+    February28,
+    /// The reading of Feb 29 is read on Mar 1 before the reading of Mar 1
+    March1,
+}
+
+impl LeapDay {
+    /// Returns the date in the given year without Feb 29 on which the reading of Feb 29 is read,
+    /// or `None` if it is skipped or the year has a Feb 29.
+    fn folded_date(&self, year: i32) -> Option<NaiveDate> {
+        if NaiveDate::from_ymd_opt(year, 2, 29).is_some() {
+            return None;
+        }
+        match self {
+            LeapDay::Skip => None,
+            LeapDay::February28 => NaiveDate::from_ymd_opt(year, 2, 28),
+            LeapDay::March1 => NaiveDate::from_ymd_opt(year, 3, 1),
+        }
+    }
+
+    /// Returns the bible reading of `search_date` which is looked up with `lookup`. If the reading of Feb 29 is read
+    /// on `search_date`, its passages are added to the reading. A missing reading of Feb 29 is ignored.
+    fn biblereading_for_date<E>(&self, search_date: NaiveDate, lookup: impl Fn(NaiveDate) -> Result<BibleReading, E>) -> Result<BibleReading, E> {
+        let biblereading = lookup(search_date)?;
+        if self.folded_date(search_date.year()) != Some(search_date) {
+            return Ok(biblereading);
+        }
+
+        match NaiveDate::from_ymd_opt(LEAP_YEAR, 2, 29).map(lookup) {
+            Some(Ok(leap_biblereading)) => Ok(biblereading.with_leap_day(leap_biblereading, *self)),
+            _ => Ok(biblereading),
+        }
+    }
+}
+
+/// The kind of reading plan which is contained in the schedule file
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScheduleKind {
+    /// Every row belongs to a date (the default)
+    Annual,
+    /// The schedule has one row per weekday (`Mon` until `Sun` in the first column) which is repeated every week
+    Weekly,
+}
 
 #[derive(Debug, Clone)]
 pub struct BibleReading {
     pub date: NaiveDate,
-    pub old_testament_reading: String,
-    pub new_testament_reading: String,
-}   
+    /// The Old Testament passages of the day. A cell of the schedule contains several passages separated by `;`.
+    pub old_testament_readings: Vec<String>,
+    /// The New Testament passages of the day, separated by `;` in the schedule like the Old Testament passages
+    pub new_testament_readings: Vec<String>,
+    /// The optional daily psalm of plans with 5 or 6 columns
+    pub psalm: Option<String>,
+    /// The optional daily proverb (or other wisdom reading) of plans with 5 or 6 columns
+    pub wisdom: Option<String>,
+    /// The optional links to audio recordings of the readings (see `AudioColumns`)
+    pub audio: AudioLinks,
+}
 
-#[derive(Debug, Clone)]
-enum ErrorCause {
-    InputFileNotFound,
-    DateDoesNotExist,
-    InvalidFormat,
+/// The links to audio recordings of the Old and New Testament readings of a day
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AudioLinks {
+    pub old_testament: Option<String>,
+    pub new_testament: Option<String>,
 }
 
-#[derive(Debug, Clone)]
-pub struct BibleReadingNotFoundError {
-    error_cause: ErrorCause,
-    error_string: String,
+impl AudioLinks {
+    /// Returns the links without the ones which are no valid http(s) URL, which are reported by `Schedule::validate`.
+    fn valid(self) -> Self {
+        AudioLinks {
+            old_testament: self.old_testament.filter(|url| is_audio_url(url)),
+            new_testament: self.new_testament.filter(|url| is_audio_url(url)),
+        }
+    }
 }
 
-impl BibleReadingNotFoundError {
-    fn new(error_cause: ErrorCause) -> BibleReadingNotFoundError {
-        BibleReadingNotFoundError {
-            error_cause,
-            error_string: String::from(""),
+impl BibleReading {
+    /// Creates the bible reading of a row of the schedule. The row has the columns date, New Testament and
+    /// Old Testament, optionally followed by the psalm and the wisdom reading (see `weekdays_column`).
+    fn from_record(string_record: &csv::StringRecord, date: NaiveDate) -> Self {
+        let optional_column = |index: usize| {
+            string_record.get(index)
+                .filter(|_| string_record.len() >= 5)
+                .map(|reading| reading.trim())
+                .filter(|reading| !reading.is_empty())
+                .map(|reading| reading.to_string())
+        };
+
+        BibleReading {
+            date,
+            old_testament_readings: split_readings(string_record.get(2).unwrap()),
+            new_testament_readings: split_readings(string_record.get(1).unwrap()),
+            psalm: optional_column(3),
+            wisdom: optional_column(4),
+            audio: AudioLinks::default(),
         }
     }
+
+    /// Adds the audio links of the row, leaving out the invalid ones.
+    fn with_audio(self, audio: AudioLinks) -> Self {
+        BibleReading { audio: audio.valid(), ..self }
+    }
+
+    /// Returns all Old Testament passages of the day separated by `;` (like in the schedule).
+    pub fn old_testament_reading(&self) -> String {
+        self.old_testament_readings.join("; ")
+    }
+
+    /// Returns all New Testament passages of the day separated by `;` (like in the schedule).
+    pub fn new_testament_reading(&self) -> String {
+        self.new_testament_readings.join("; ")
+    }
 }
 
-impl fmt::Display for BibleReadingNotFoundError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.error_cause {
-            ErrorCause::DateDoesNotExist => write!(f, "There exists no entry with bible reading for today's date."),
-            ErrorCause::InputFileNotFound => write!(f, "The input file has not been found."),
-            ErrorCause::InvalidFormat => write!(f, "The format of the csv file seems to be invalid: {}", self.error_string)
+/// Splits a cell of the schedule into its passages, which are separated by `;` (for example `Gen 1; Ps 2`).
+/// Commas are kept, as they separate chapters or verses within a passage (like `Psalm 135,136`).
+fn split_readings(cell: &str) -> Vec<String> {
+    let readings: Vec<String> = cell.split(';')
+        .map(|reading| reading.trim())
+        .filter(|reading| !reading.is_empty())
+        .map(|reading| reading.to_string())
+        .collect();
+
+    if readings.is_empty() {
+        return vec![cell.trim().to_string()];
+    }
+    readings
+}
+
+/// Checks whether a row of an annual schedule has a valid number of columns: date, New Testament and Old Testament,
+/// optionally followed by the weekdays (4 columns), by the psalm and the wisdom reading (5 columns) or by all of them (6 columns).
+fn has_valid_length(string_record: &csv::StringRecord) -> bool {
+    (3..=6).contains(&string_record.len())
+}
+
+/// Returns the optional column with the weekdays of a row, which is the last column of rows with 4 or 6 columns.
+fn weekdays_column(string_record: &csv::StringRecord) -> Option<&str> {
+    match string_record.len() {
+        4 => string_record.get(3),
+        6 => string_record.get(5),
+        _ => None,
+    }
+}
+
+impl BibleReading {
+    /// Adds the passages of the reading of Feb 29, after the passages for `LeapDay::February28` and before
+    /// them for `LeapDay::March1`.
+    fn with_leap_day(mut self, mut leap_biblereading: BibleReading, leap_day: LeapDay) -> Self {
+        match leap_day {
+            LeapDay::March1 => {
+                leap_biblereading.old_testament_readings.append(&mut self.old_testament_readings);
+                leap_biblereading.new_testament_readings.append(&mut self.new_testament_readings);
+                BibleReading {
+                    old_testament_readings: leap_biblereading.old_testament_readings,
+                    new_testament_readings: leap_biblereading.new_testament_readings,
+                    ..self
+                }
+            },
+            _ => {
+                self.old_testament_readings.append(&mut leap_biblereading.old_testament_readings);
+                self.new_testament_readings.append(&mut leap_biblereading.new_testament_readings);
+                self
+            },
         }
     }
 }
 
-pub fn get_todays_biblereading() -> Result<BibleReading, BibleReadingNotFoundError> {
+/// The index of the optional year column of an annual schedule (see `has_year_column`)
+const YEAR_COLUMN: usize = 3;
+
+/// Checks whether an annual schedule has a year column, which is detected by the header `Year` of the 4th column.
+/// Schedules with a year column are multi-year plans, whose rows belong to the year of the column instead of every year.
+fn has_year_column(headers: &csv::StringRecord) -> bool {
+    headers.get(YEAR_COLUMN).is_some_and(|header| header.trim().eq_ignore_ascii_case("year"))
+}
+
+/// Removes the year column from a row, so that the other columns have the same positions as in a schedule without years.
+///
+/// # Params
+/// - `string_record`: The row of the schedule
+/// - `year_column`: `true` if the schedule has a year column (see `has_year_column`)
+///
+/// # Returns
+/// The row without the year column and the content of the year column, which is `None` if the schedule has no year column.
+fn split_year_column(string_record: &csv::StringRecord, year_column: bool) -> (csv::StringRecord, Option<String>) {
+    if !year_column {
+        return (string_record.clone(), None);
+    }
+
+    let mut row = csv::StringRecord::new();
+    for (index, field) in string_record.iter().enumerate() {
+        if index != YEAR_COLUMN {
+            row.push_field(field);
+        }
+    }
+    row.set_position(string_record.position().cloned());
+    (row, Some(string_record.get(YEAR_COLUMN).unwrap_or_default().trim().to_string()))
+}
+
+/// The header of the optional column with the links to the audio recordings of the Old Testament readings
+const OLD_TESTAMENT_AUDIO_HEADER: &str = "OT Audio";
+
+/// The header of the optional column with the links to the audio recordings of the New Testament readings
+const NEW_TESTAMENT_AUDIO_HEADER: &str = "NT Audio";
+
+/// The positions of the optional audio columns, which are detected by their headers (`OT Audio` and `NT Audio`)
+/// and can be placed anywhere in the schedule.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct AudioColumns {
+    old_testament: Option<usize>,
+    new_testament: Option<usize>,
+}
+
+impl AudioColumns {
+    /// Finds the audio columns in the header of the schedule.
+    fn from_headers(headers: &csv::StringRecord) -> Self {
+        let position = |name: &str| headers.iter().position(|header| header.trim().eq_ignore_ascii_case(name));
+        AudioColumns {
+            old_testament: position(OLD_TESTAMENT_AUDIO_HEADER),
+            new_testament: position(NEW_TESTAMENT_AUDIO_HEADER),
+        }
+    }
+
+    /// Removes the audio columns from a row, so that the other columns have the same positions as in a schedule without audio.
+    ///
+    /// # Returns
+    /// The row without the audio columns and the links of the audio columns (empty cells are `None`).
+    fn split(&self, string_record: &csv::StringRecord) -> (csv::StringRecord, AudioLinks) {
+        if self.old_testament.is_none() && self.new_testament.is_none() {
+            return (string_record.clone(), AudioLinks::default());
+        }
+
+        let link = |column: Option<usize>| column
+            .and_then(|index| string_record.get(index))
+            .map(|url| url.trim())
+            .filter(|url| !url.is_empty())
+            .map(|url| url.to_string());
+        let audio = AudioLinks { old_testament: link(self.old_testament), new_testament: link(self.new_testament) };
+
+        let mut row = csv::StringRecord::new();
+        for (index, field) in string_record.iter().enumerate() {
+            if Some(index) != self.old_testament && Some(index) != self.new_testament {
+                row.push_field(field);
+            }
+        }
+        row.set_position(string_record.position().cloned());
+        (row, audio)
+    }
+}
+
+/// Checks whether the link of an audio column is an absolute http(s) URL.
+fn is_audio_url(url: &str) -> bool {
+    Url::parse(url).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+}
+
+/// Returns today's bible reading of the given reading plan (see `plan_schedule`).
+pub fn get_todays_biblereading(plan: &str) -> Result<BibleReading, DailyBibleError> {
     let today: NaiveDate = Local::now().date_naive();
-    get_biblereading_for_date(today)
+    get_biblereading_for_date(plan, today)
+}
+
+/// Parses a date string in the format `mm-dd` (for example `09-01`) into a month and a day.
+/// 
+/// # Returns
+/// `Some((month, day))` if the string is a valid month-day combination (including `02-29`), otherwise `None`.
+pub fn parse_month_day(date_string: &str) -> Option<(u32, u32)> {
+    match NaiveDate::parse_from_str(&format!("{}-{}", date_string.trim(), LEAP_YEAR), "%m-%d-%Y") {
+        Ok(date) => Some((date.month(), date.day())),
+        Err(_) => None,
+    }
+}
+
+/// Returns the bible reading for a given month and day in the given year.
+/// If the month-day combination does not exist in the given year (which is the case for Feb 29 in non leap years),
+/// a `DateDoesNotExist` error is returned instead of the reading.
+pub fn get_biblereading_for_month_day(plan: &str, month: u32, day: u32, year: i32) -> Result<BibleReading, DailyBibleError> {
+    match NaiveDate::from_ymd_opt(year, month, day) {
+        Some(date) => get_biblereading_for_date(plan, date),
+        None => Err(DailyBibleError::DateDoesNotExist),
+    }
+}
+
+/// Returns how the reading of Feb 29 is handled in years without Feb 29, which is configured with `LEAP_DAY_ENV`
+pub fn leap_day() -> LeapDay {
+    match env::var(LEAP_DAY_ENV).unwrap_or_default().to_lowercase().as_str() {
+        "feb28" => LeapDay::February28,
+        "mar1" => LeapDay::March1,
+        _ => LeapDay::Skip,
+    }
+}
+
+/// Returns the bible reading for the given date from the schedule of the reading plan (see `plan_schedule`).
+/// 
+/// # Date format
+/// The dates of the schedule are parsed with the format which is configured with `SCHEDULE_DATE_FORMAT_ENV`.
+/// If the format contains no year (like `%m-%d`), every row is valid in every year.
+/// 
+/// # Leap days
+/// A row of Feb 29 is found like any other row in its leap year. In years without Feb 29, the reading of Feb 29
+/// (the row of Feb 29 in `LEAP_YEAR`) is skipped by default. With `LEAP_DAY_ENV` set to `feb28` or `mar1`
+/// (see `LeapDay`), its passages are added to the reading of Feb 28 or Mar 1 of these years instead.
+/// 
+/// # Weekdays
+/// A row can contain an optional 4th column with a comma-separated list of weekday abbreviations
+/// (for example `Mon,Tue,Wed,Thu,Fri,Sat`). The reading of such a row is only returned if the weekday
+/// of `search_date` is contained in the list. Rows without the column (or with an empty column) are valid on every weekday.
+/// 
+/// # Years
+/// A schedule whose 4th column is named `Year` in the header row is a multi-year plan. Every row then belongs to the
+/// full date of its date and year column (the year column is not counted for the other optional columns), so
+/// a chronological plan over several years can be used with a format without year like `%m-%d`.
+/// Without a year column, a date format without year keeps every row valid in every year.
+/// 
+/// # Weekly plans
+/// If the schedule is a weekly plan (see `ScheduleKind`), the row of the weekday of `search_date` is returned instead.
+pub fn get_biblereading_for_date(plan: &str, search_date: NaiveDate) -> Result<BibleReading, DailyBibleError> {
+    with_plan_schedule(plan, |schedule| schedule.biblereading_for_date(search_date))
+}
+
+/// Returns all dates of the schedule of the reading plan which have a reading (see `Schedule::available_dates`).
+/// The rows of a weekly plan are repeated every week, so the days of the week of `today` are returned for it.
+pub fn available_dates(plan: &str, today: NaiveDate) -> Vec<NaiveDate> {
+    with_plan_schedule(plan, |schedule| {
+        if schedule.kind == ScheduleKind::Weekly {
+            let monday = today - Duration::days(i64::from(today.weekday().num_days_from_monday()));
+            return monday.iter_days().take(7).collect();
+        }
+
+        match schedule.available_dates(today.year()) {
+            Ok(dates) => dates,
+            Err(error) => {
+                log::error!("The dates of the schedule can not be read: {}", error.to_string());
+                Vec::new()
+            }
+        }
+    })
+}
+
+/// Picks one of the dates with the given random number generator. The generator is passed in, so the choice can be
+/// reproduced in the tests with a seeded generator.
+///
+/// # Returns
+/// The picked date, or `None` if there are no dates.
+pub fn pick_date(dates: &[NaiveDate], rng: &mut impl Rng) -> Option<NaiveDate> {
+    dates.choose(rng).copied()
+}
+
+/// Returns the reading of a random date of the schedule of the reading plan (see `available_dates` and `pick_date`).
+///
+/// # Returns
+/// The bible reading, or a `DateDoesNotExist` error if the schedule has no dates.
+pub fn get_random_biblereading(plan: &str, today: NaiveDate, rng: &mut impl Rng) -> Result<BibleReading, DailyBibleError> {
+    match pick_date(&available_dates(plan, today), rng) {
+        Some(date) => get_biblereading_for_date(plan, date),
+        None => Err(DailyBibleError::DateDoesNotExist),
+    }
+}
+
+/// Returns the bible readings of `DAYS_PER_WEEK` days starting with `start_date`.
+/// Days without a reading (or with a broken schedule) are contained with `None`, so a missing day does not hide the others.
+///
+/// # Params
+/// - `plan_day`: The plan day of `start_date` if the user follows a personal reading plan (see `get_biblereading_for_plan_day`),
+///   `None` to follow the dates of the schedule
+pub fn get_biblereadings_for_week(plan: &str, start_date: NaiveDate, plan_day: Option<usize>) -> Vec<(NaiveDate, Option<BibleReading>)> {
+    with_plan_schedule(plan, |schedule| {
+        (0..DAYS_PER_WEEK)
+            .map(|offset| {
+                let date = start_date + Duration::days(offset);
+                let biblereading = match plan_day {
+                    Some(plan_day) => schedule.nth_biblereading(plan_day + offset as usize, date),
+                    None => schedule.biblereading_for_date(date),
+                };
+                (date, biblereading.ok())
+            })
+            .collect()
+    })
+}
+
+/// Returns the bible reading of a personal reading plan, which starts with the first row of the schedule
+/// on the day the user started the plan instead of following the dates of the schedule.
+///
+/// # Params
+/// - `plan_day`: The number of days since the start of the plan (`0` is the first row of the schedule)
+/// - `date`: The date which the returned reading belongs to
+///
+/// # Returns
+/// The reading of the row, or a `DateDoesNotExist` error if the plan has fewer rows (so it is finished).
+pub fn get_biblereading_for_plan_day(plan: &str, plan_day: usize, date: NaiveDate) -> Result<BibleReading, DailyBibleError> {
+    with_plan_schedule(plan, |schedule| schedule.nth_biblereading(plan_day, date))
 }
 
-fn get_biblereading_for_date(search_date: NaiveDate) -> Result<BibleReading, BibleReadingNotFoundError> {
-    let csv_reader_result = csv::Reader::from_path("schedule.csv");
-    if csv_reader_result.is_err() {
-        return Err(BibleReadingNotFoundError::new(ErrorCause::InputFileNotFound));
+/// Returns the kind of the schedule which is configured with `SCHEDULE_KIND_ENV`.
+/// If the variable is not set (or invalid), a schedule whose first column is named `Weekday` in the header is a weekly plan
+/// and every other schedule an annual plan.
+fn schedule_kind(headers: Option<&csv::StringRecord>) -> ScheduleKind {
+    match env::var(SCHEDULE_KIND_ENV).unwrap_or_default().to_lowercase().as_str() {
+        "annual" => ScheduleKind::Annual,
+        "weekly" => ScheduleKind::Weekly,
+        _ => match headers.and_then(|headers| headers.get(0)) {
+            Some(header) if header.trim().eq_ignore_ascii_case("weekday") => ScheduleKind::Weekly,
+            _ => ScheduleKind::Annual,
+        },
+    }
+}
+
+/// Returns the path of the schedule file which is configured with `SCHEDULE_FILE_ENV`
+pub fn schedule_file_path() -> String {
+    env::var(SCHEDULE_FILE_ENV).unwrap_or(DEFAULT_SCHEDULE_FILE_PATH.to_string())
+}
+
+/// Returns the directory with the schedules of several reading plans which is configured with `SCHEDULES_DIR_ENV`
+fn schedules_directory() -> String {
+    env::var(SCHEDULES_DIR_ENV).unwrap_or(DEFAULT_SCHEDULES_DIRECTORY.to_string())
+}
+
+/// Returns the file paths of the schedules of all reading plans by their name: the `.csv` files in the directory
+/// of `SCHEDULES_DIR_ENV` or, if it contains no schedule, the single file of `SCHEDULE_FILE_ENV`.
+fn plan_files() -> HashMap<String, String> {
+    let directory = schedules_directory();
+    let mut plan_files = index_plans(Path::new(&directory));
+    if plan_files.is_empty() {
+        let file_path = schedule_file_path();
+        let name = Path::new(&file_path).file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+        plan_files.insert(name, file_path);
+    } else {
+        log::info!("Found {} reading plan(s) in {}: {}", plan_files.len(), directory, sorted_plan_names(&plan_files).join(", "));
+    }
+    plan_files
+}
+
+/// Reads the schedules of all reading plans (see `plan_files`) into memory, where the readings are looked up.
+/// A schedule with problems is still used, so that only the dates of the broken rows are affected (see `validate_schedule`).
+pub fn load_plans() {
+    let date_format = schedule_date_format();
+    let plans: HashMap<String, Arc<RwLock<Schedule>>> = plan_files().into_iter()
+        .map(|(name, file_path)| (name, Arc::new(RwLock::new(Schedule::with_date_format(&file_path, &date_format)))))
+        .collect();
+
+    if PLANS.set(RwLock::new(plans)).is_err() {
+        log::warn!("The reading plans have already been loaded.");
+    }
+}
+
+/// Indexes the reading plans again and reads their schedules (see `load_plans`). A changed schedule only replaces the
+/// schedule in use if it is valid (see `validate_plan_schedule`), otherwise the previous version of the plan is kept.
+/// A new plan is only added if its schedule is valid, a plan whose file has been removed is removed.
+///
+/// # Returns
+/// `true` if all schedules have been reloaded, `false` if a schedule has been rejected and an error has been logged.
+pub fn reload_plans() -> bool {
+    let plans_lock = match PLANS.get() {
+        Some(plans_lock) => plans_lock,
+        None => {
+            log::warn!("The reading plans have not been loaded yet.");
+            return false;
+        }
+    };
+
+    let previous_plans = plans_lock.read().unwrap_or_else(PoisonError::into_inner).clone();
+    let (plans, reloaded) = reload_schedules(&previous_plans, plan_files(), &schedule_date_format());
+
+    // Without any valid schedule the previous plans are kept, so that the readings can still be sent
+    if !plans.is_empty() {
+        *plans_lock.write().unwrap_or_else(PoisonError::into_inner) = plans;
+    }
+    reloaded
+}
+
+/// Reads the schedules of the given plan files and validates them (see `reload_plans`).
+/// A valid schedule of an existing plan is written into the `Arc` of the plan, so that it is swapped for all users of the plan.
+///
+/// # Params
+/// - `previous_plans`: The schedules which are used at the moment by the name of their plan
+/// - `plan_files`: The file paths of the schedules by the name of their plan (see `plan_files`)
+/// - `date_format`: The format of the dates in the schedule files
+///
+/// # Returns
+/// The schedules of the reloaded plans and `true` if all schedules are valid.
+fn reload_schedules(previous_plans: &HashMap<String, Arc<RwLock<Schedule>>>, plan_files: HashMap<String, String>, date_format: &str) -> (HashMap<String, Arc<RwLock<Schedule>>>, bool) {
+    let mut plans: HashMap<String, Arc<RwLock<Schedule>>> = HashMap::new();
+    let mut reloaded = true;
+    for (name, file_path) in plan_files {
+        let schedule = Schedule::with_date_format(&file_path, date_format);
+        let valid = validate_plan_schedule(&schedule);
+        match (valid, previous_plans.get(&name)) {
+            (true, Some(previous_schedule)) => {
+                *previous_schedule.write().unwrap_or_else(PoisonError::into_inner) = schedule;
+                plans.insert(name, previous_schedule.clone());
+            },
+            (true, None) => {
+                plans.insert(name, Arc::new(RwLock::new(schedule)));
+            },
+            (false, Some(previous_schedule)) => {
+                log::error!("The changed schedule {} is not used, the reading plan {} keeps its previous schedule.", file_path, name);
+                plans.insert(name, previous_schedule.clone());
+                reloaded = false;
+            },
+            (false, None) => {
+                log::error!("The schedule {} is not used, the reading plan {} is not added.", file_path, name);
+                reloaded = false;
+            },
+        }
+    }
+    (plans, reloaded)
+}
+
+/// Returns the file paths of all `.csv` files in the directory by their file name without extension.
+/// A directory which does not exist contains no plans.
+fn index_plans(directory: &Path) -> HashMap<String, String> {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(_) => return HashMap::new(),
+    };
+
+    entries.flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("csv")))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_string_lossy().to_string();
+            Some((name, path.to_string_lossy().to_string()))
+        })
+        .collect()
+}
+
+/// Returns the names of the plans in alphabetical order. The first one is the default plan.
+fn sorted_plan_names<T>(plans: &HashMap<String, T>) -> Vec<String> {
+    let mut names: Vec<String> = plans.keys().cloned().collect();
+    names.sort();
+    names
+}
+
+/// Returns the plan with the given name. An empty or unknown name selects the default plan,
+/// which is the first plan in alphabetical order.
+fn select_plan<'a, T>(plans: &'a HashMap<String, T>, plan: &str) -> Option<&'a T> {
+    plans.get(plan).or_else(|| sorted_plan_names(plans).first().and_then(|name| plans.get(name)))
+}
+
+/// Returns the schedules of all reading plans by their name (see `load_plans`), or `None` if they have not been loaded.
+fn loaded_plans() -> Option<HashMap<String, Arc<RwLock<Schedule>>>> {
+    PLANS.get().map(|plans_lock| plans_lock.read().unwrap_or_else(PoisonError::into_inner).clone())
+}
+
+/// Returns the names of all reading plans in alphabetical order (see `load_plans`).
+pub fn plan_names() -> Vec<String> {
+    loaded_plans().as_ref().map(sorted_plan_names).unwrap_or_default()
+}
+
+/// Returns the name of the reading plan which is used for the given plan name of a user (see `select_plan`).
+pub fn plan_name(plan: &str) -> String {
+    let names = plan_names();
+    if names.iter().any(|name| name == plan) {
+        plan.to_string()
+    } else {
+        names.into_iter().next().unwrap_or_default()
+    }
+}
+
+/// Calls `f` with the schedule of the reading plan with the given name (see `select_plan`).
+/// If the plans have not been loaded, the schedule of `SCHEDULE_FILE_ENV` is read from the file.
+fn with_plan_schedule<T>(plan: &str, f: impl FnOnce(&Schedule) -> T) -> T {
+    match loaded_plans().as_ref().and_then(|plans| select_plan(plans, plan)) {
+        Some(schedule) => f(&schedule.read().unwrap_or_else(PoisonError::into_inner)),
+        None => f(&Schedule::with_date_format(&schedule_file_path(), &schedule_date_format())),
+    }
+}
+
+/// Returns the file paths of the schedules of all reading plans in the order of their names.
+pub fn plan_file_paths() -> Vec<String> {
+    match loaded_plans() {
+        Some(plans) => sorted_plan_names(&plans).iter()
+            .map(|name| plans[name].read().unwrap_or_else(PoisonError::into_inner).file_path.clone())
+            .collect(),
+        None => vec![schedule_file_path()],
+    }
+}
+
+/// Returns the format of the dates in the schedule file which is configured with `SCHEDULE_DATE_FORMAT_ENV`
+pub fn schedule_date_format() -> String {
+    env::var(SCHEDULE_DATE_FORMAT_ENV).unwrap_or(DEFAULT_DATE_FORMAT.to_string())
+}
+
+/// The date of a row in the schedule file (or in another file which uses the same date format, like the verses of the day)
+pub(crate) struct ScheduleDate {
+    /// The parsed date. If the date format contains no year, the date is placed in `LEAP_YEAR`.
+    date: NaiveDate,
+    /// `true` if the date format contains no year, so that the row is valid in every year
+    every_year: bool,
+}
+
+impl ScheduleDate {
+    /// Parses the date of a row with the given chrono format string (for example `%m-%d-%y` or `%d.%m`).
+    pub(crate) fn parse(date_string: &str, date_format: &str) -> Result<Self, chrono::ParseError> {
+        let every_year = !["%Y", "%y", "%F", "%D"].iter().any(|year_specifier| date_format.contains(year_specifier));
+
+        let date = if every_year {
+            // chrono can not parse a date without a year, so the leap year is appended (which also accepts Feb 29)
+            NaiveDate::parse_from_str(&format!("{} {}", date_string.trim(), LEAP_YEAR), &format!("{} %Y", date_format))?
+        } else {
+            NaiveDate::parse_from_str(date_string.trim(), date_format)?
+        };
+        Ok(ScheduleDate { date, every_year })
+    }
+
+    /// Places the date in the year of the year column (see `has_year_column`), so that the row is only valid in this year.
+    ///
+    /// # Returns
+    /// `None` if the year is invalid or does not contain the day (like Feb 29 in non leap years).
+    fn in_year(self, year_string: &str) -> Option<Self> {
+        let year: i32 = year_string.trim().parse().ok()?;
+        Some(ScheduleDate { date: self.date.with_year(year)?, every_year: false })
+    }
+
+    /// Checks whether the row belongs to the given date.
+    pub(crate) fn matches(&self, search_date: NaiveDate) -> bool {
+        if self.every_year {
+            self.date.month() == search_date.month() && self.date.day() == search_date.day()
+        } else {
+            self.date == search_date
+        }
+    }
+}
+
+/// A problem in a single row of the schedule file which has been found by `Schedule::validate`
+#[derive(Debug, Clone)]
+pub struct ScheduleError {
+    /// The line number of the row in the schedule file, or `None` if the problem concerns the whole file
+    pub line: Option<u64>,
+    pub message: String,
+}
+
+impl fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "Line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// A summary of the schedule file which is created by `Schedule::summary`
+#[derive(Debug, Clone)]
+pub struct ScheduleSummary {
+    /// The number of rows which can be used for a bible reading
+    pub valid_rows: usize,
+    /// The dates which occur more than once in the schedule
+    pub duplicate_dates: Vec<NaiveDate>,
+    /// The dates between the first and the last date of the schedule which have no entry
+    pub missing_dates: Vec<NaiveDate>,
+}
+
+/// The schedule file which contains the bible readings. The file is read once when the schedule is created,
+/// the readings are looked up in the rows in memory afterwards.
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    file_path: String,
+    date_format: String,
+    /// Whether the schedule is an annual or a weekly plan (see `schedule_kind`)
+    kind: ScheduleKind,
+    /// The optional audio columns, which are detected from the header
+    audio_columns: AudioColumns,
+    /// `true` if the schedule has a year column (see `has_year_column`)
+    year_column: bool,
+    /// The rows of the schedule file, or the reason why the file can not be read
+    records: Result<Vec<csv::StringRecord>, String>,
+    /// The rows which can not be read as CSV. They are skipped by the lookups and reported by `validate`.
+    unreadable_rows: Vec<ScheduleError>,
+}
+
+impl Schedule {
+    /// Reads the schedule with the `DEFAULT_DATE_FORMAT`
+    #[cfg(test)]
+    pub fn new(file_path: &str) -> Self {
+        Schedule::with_date_format(file_path, DEFAULT_DATE_FORMAT)
+    }
+
+    /// Reads the schedule whose dates are parsed with the given chrono format string (for example `%d.%m`).
+    /// If the file can not be read, every lookup returns an `InputFileNotFound` error.
+    pub fn with_date_format(file_path: &str, date_format: &str) -> Self {
+        let mut schedule = Schedule {
+            file_path: file_path.to_string(),
+            date_format: date_format.to_string(),
+            kind: schedule_kind(None),
+            audio_columns: AudioColumns::default(),
+            year_column: false,
+            records: Ok(Vec::new()),
+            unreadable_rows: Vec::new(),
+        };
+
+        let mut csv_reader = match csv::ReaderBuilder::new().flexible(true).from_path(file_path) {
+            Ok(csv_reader) => csv_reader,
+            Err(error) => {
+                schedule.records = Err(error.to_string());
+                return schedule;
+            }
+        };
+
+        if let Ok(headers) = csv_reader.headers() {
+            schedule.kind = schedule_kind(Some(headers));
+            schedule.audio_columns = AudioColumns::from_headers(headers);
+            schedule.year_column = has_year_column(&schedule.audio_columns.split(headers).0);
+        }
+
+        let mut records = Vec::new();
+        for record in csv_reader.into_records() {
+            match record {
+                Ok(string_record) => records.push(string_record),
+                Err(error) => schedule.unreadable_rows.push(ScheduleError {
+                    line: error.position().map(|position| position.line()),
+                    message: error.to_string()
+                }),
+            }
+        }
+        schedule.records = Ok(records);
+        schedule
+    }
+
+    /// Returns the readable rows of the schedule file, or an `InputFileNotFound` error if the file can not be read.
+    fn records(&self) -> Result<&[csv::StringRecord], DailyBibleError> {
+        self.records.as_deref().map_err(|_| DailyBibleError::InputFileNotFound(self.file_path.clone()))
+    }
+
+    /// Returns the bible reading for the given date (see `get_biblereading_for_date`).
+    pub fn biblereading_for_date(&self, search_date: NaiveDate) -> Result<BibleReading, DailyBibleError> {
+        match self.kind {
+            ScheduleKind::Annual => leap_day().biblereading_for_date(search_date, |date| self.annual_biblereading(date)),
+            ScheduleKind::Weekly => self.weekly_biblereading(search_date),
+        }
+    }
+
+    /// Returns the bible reading of the row of an annual schedule which belongs to `search_date`.
+    fn annual_biblereading(&self, search_date: NaiveDate) -> Result<BibleReading, DailyBibleError> {
+        for string_record in self.records()? {
+            let (string_record, audio) = self.audio_columns.split(string_record);
+            let (string_record, year) = split_year_column(&string_record, self.year_column);
+            if !has_valid_length(&string_record) {
+                return Err(DailyBibleError::InvalidFormat("The length of the row is not always between 3 and 6".to_string()));
+            }
+
+            let date: Result<ScheduleDate, chrono::ParseError> = ScheduleDate::parse(string_record.get(0).unwrap(), &self.date_format);
+
+            match date {
+                // The date can be parsed from string and we have a ScheduleDate
+                Ok(schedule_date) => {
+                    // In multi-year plans the row only belongs to the year of its year column
+                    let schedule_date = match year {
+                        Some(year_string) => match schedule_date.in_year(&year_string) {
+                            Some(schedule_date) => schedule_date,
+                            None => {
+                                return Err(DailyBibleError::InvalidFormat(format!("Can not parse year {}", year_string)))
+                            }
+                        },
+                        None => schedule_date,
+                    };
+                    if schedule_date.matches(search_date) {
+                        // Rows which are restricted to other weekdays are skipped
+                        if let Some(weekdays_string) = weekdays_column(&string_record) {
+                            match parse_weekdays(weekdays_string) {
+                                Some(weekdays) => {
+                                    if !weekdays.is_empty() && !weekdays.contains(&search_date.weekday()) {
+                                        continue;
+                                    }
+                                },
+                                None => {
+                                    return Err(DailyBibleError::InvalidFormat(format!("Can not parse weekdays {}", weekdays_string)))
+                                }
+                            }
+                        }
+
+                        return Ok(BibleReading::from_record(&string_record, search_date).with_audio(audio))
+                    }
+                },
+                // The date can not be parsed from string (most likely because of an invalid format)
+                Err(_) => {
+                    return Err(DailyBibleError::InvalidFormat(format!("Can not parse date {}", string_record.get(0).unwrap())))
+                }
+            }
+        }
+
+        // If nothing has been found, we return an DateDoesNotExist Error
+        Err(DailyBibleError::DateDoesNotExist)
+    }
+
+    /// Returns the bible reading of a weekly plan for the weekday of `search_date`.
+    fn weekly_biblereading(&self, search_date: NaiveDate) -> Result<BibleReading, DailyBibleError> {
+        for string_record in self.records()? {
+            let (string_record, audio) = self.audio_columns.split(string_record);
+            if string_record.len() != 3 {
+                return Err(DailyBibleError::InvalidFormat("The length of the row of the weekly plan is not always 3".to_string()));
+            }
+
+            let weekday_string = string_record.get(0).unwrap();
+            match weekday_string.trim().parse::<Weekday>() {
+                Ok(weekday) => {
+                    if weekday == search_date.weekday() {
+                        return Ok(BibleReading::from_record(&string_record, search_date).with_audio(audio))
+                    }
+                },
+                Err(_) => {
+                    return Err(DailyBibleError::InvalidFormat(format!("Can not parse weekday {}", weekday_string)))
+                }
+            }
+        }
+
+        Err(DailyBibleError::DateDoesNotExist)
+    }
+
+    /// Returns the bible reading in the row with the given index (not counting the header) of the schedule.
+    fn nth_biblereading(&self, index: usize, date: NaiveDate) -> Result<BibleReading, DailyBibleError> {
+        match self.records()?.get(index).map(|string_record| self.audio_columns.split(string_record)) {
+            Some((string_record, audio)) => {
+                let string_record = split_year_column(&string_record, self.year_column).0;
+                if string_record.len() < 3 {
+                    return Err(DailyBibleError::InvalidFormat(format!("The row {} of the schedule has less than 3 columns", index + 1)));
+                }
+                Ok(BibleReading::from_record(&string_record, date).with_audio(audio))
+            },
+            None => Err(DailyBibleError::DateDoesNotExist),
+        }
+    }
+
+    /// Checks whether the date of the first row can be parsed with the date format of the schedule.
+    /// If this is not the case, the format most likely does not fit to the schedule at all.
+    ///
+    /// # Returns
+    /// `Ok(())` if the first row can be parsed (or the schedule has no rows), otherwise a `ScheduleError`
+    /// which names the date format.
+    pub fn check_date_format(&self) -> Result<(), ScheduleError> {
+        let records = self.records.as_ref().map_err(|error| ScheduleError {
+            line: None,
+            message: format!("The schedule file {} can not be read: {}", self.file_path, error)
+        })?;
+
+        match records.first() {
+            Some(string_record) => {
+                let date_string = string_record.get(0).unwrap_or_default();
+                match ScheduleDate::parse(date_string, &self.date_format) {
+                    Ok(_) => Ok(()),
+                    Err(_) => Err(ScheduleError {
+                        line: string_record.position().map(|position| position.line()),
+                        message: format!(
+                            "The date format \"{}\" can not parse the date \"{}\" of the first row, please check {}",
+                            self.date_format, date_string, SCHEDULE_DATE_FORMAT_ENV
+                        )
+                    }),
+                }
+            },
+            None => Ok(()),
+        }
     }
-    let csv_reader = csv_reader_result.unwrap();
 
-    for record in csv_reader.into_records() {
-        match record {
-            Ok(string_record) => {
-                if string_record.len() != 3 {
-                    return Err(BibleReadingNotFoundError {
-                        error_cause: ErrorCause::InvalidFormat,
-                        error_string: "The length of the row is not always 3".to_string()
+    /// Checks every row of the schedule file: the number of columns, the date, the optional weekday column and the audio links.
+    /// In contrast to the lookup of a reading, the validation does not stop at the first problem.
+    ///
+    /// # Returns
+    /// A list of all problems with their line numbers. The list is empty if the schedule is valid.
+    pub fn validate(&self) -> Vec<ScheduleError> {
+        let records = match &self.records {
+            Ok(records) => records,
+            Err(error) => {
+                return vec![ScheduleError {
+                    line: None,
+                    message: format!("The schedule file {} can not be read: {}", self.file_path, error)
+                }];
+            }
+        };
+
+        let mut schedule_errors = self.unreadable_rows.clone();
+        for string_record in records {
+            let line = string_record.position().map(|position| position.line());
+            let (string_record, audio) = self.audio_columns.split(string_record);
+            let (string_record, year) = split_year_column(&string_record, self.year_column);
+
+            for url in [&audio.old_testament, &audio.new_testament].into_iter().flatten() {
+                if !is_audio_url(url) {
+                    schedule_errors.push(ScheduleError {
+                        line,
+                        message: format!("The audio link {} is no http(s) URL", url)
                     });
                 }
+            }
 
-                let date: Result<NaiveDate, chrono::ParseError> = NaiveDate::parse_from_str(string_record.get(0).unwrap(), "%m-%d-%y");
-
-                match date {
-                    // The date can be parsed from string and we have a NaiveDate
-                    Ok(unwrapped_date) => {
-                        if unwrapped_date == search_date {
-                            return Ok(
-                                BibleReading {
-                                    date: unwrapped_date,
-                                    old_testament_reading: string_record.get(2).unwrap().to_string(),
-                                    new_testament_reading: string_record.get(1).unwrap().to_string(),
-                                }
-                            )
+            if !has_valid_length(&string_record) {
+                schedule_errors.push(ScheduleError {
+                    line,
+                    message: format!("The row has {} columns instead of 3 to 6", string_record.len())
+                });
+                continue;
+            }
+
+            let date_string = string_record.get(0).unwrap();
+            match ScheduleDate::parse(date_string, &self.date_format) {
+                Ok(schedule_date) => {
+                    if let Some(year_string) = year {
+                        if schedule_date.in_year(&year_string).is_none() {
+                            schedule_errors.push(ScheduleError {
+                                line,
+                                message: format!("Can not parse year {} of date {}", year_string, date_string)
+                            });
                         }
-                    },
-                    // The date can not be parsed from string (most likely because of an invalid format)
-                    Err(_) => { 
-                        return Err(BibleReadingNotFoundError {
-                            error_cause: ErrorCause::InvalidFormat,
-                            error_string: format!("Can not parse date {}", string_record.get(0).unwrap())
-                        })
                     }
+                },
+                Err(_) => {
+                    schedule_errors.push(ScheduleError {
+                        line,
+                        message: format!("Can not parse date {}", date_string)
+                    });
+                }
+            }
+
+            if let Some(weekdays_string) = weekdays_column(&string_record) {
+                if parse_weekdays(weekdays_string).is_none() {
+                    schedule_errors.push(ScheduleError {
+                        line,
+                        message: format!("Can not parse weekdays {}", weekdays_string)
+                    });
+                }
+            }
+        }
+
+        // The unreadable rows are reported in the order of the file together with the other problems
+        schedule_errors.sort_by_key(|schedule_error| schedule_error.line);
+        schedule_errors
+    }
+
+    /// Returns the dates of all valid rows of the schedule file in the order of the file.
+    /// Invalid rows are ignored here, they are reported by `validate`.
+    ///
+    /// # Returns
+    /// The dates of the rows or an `InputFileNotFound` error if the file can not be read.
+    fn valid_dates(&self) -> Result<Vec<ScheduleDate>, DailyBibleError> {
+        let mut dates: Vec<ScheduleDate> = Vec::new();
+        for string_record in self.records()? {
+            let (string_record, _) = self.audio_columns.split(string_record);
+            let (string_record, year) = split_year_column(&string_record, self.year_column);
+            if !has_valid_length(&string_record) {
+                continue;
+            }
+            if let Some(weekdays_string) = weekdays_column(&string_record) {
+                if parse_weekdays(weekdays_string).is_none() {
+                    continue;
+                }
+            }
+            let schedule_date = ScheduleDate::parse(string_record.get(0).unwrap(), &self.date_format).ok()
+                .and_then(|schedule_date| match &year {
+                    Some(year_string) => schedule_date.in_year(year_string),
+                    None => Some(schedule_date),
+                });
+            if let Some(schedule_date) = schedule_date {
+                dates.push(schedule_date);
+            }
+        }
+        Ok(dates)
+    }
+
+    /// Returns all dates with a reading in the schedule file, sorted and without duplicates.
+    /// Rows which are valid in every year (see `SCHEDULE_DATE_FORMAT_ENV`) are placed in the given year,
+    /// a Feb 29 row is left out if the year is no leap year.
+    ///
+    /// # Returns
+    /// The dates or an `InputFileNotFound` error if the file can not be read.
+    pub fn available_dates(&self, year: i32) -> Result<Vec<NaiveDate>, DailyBibleError> {
+        let mut dates: Vec<NaiveDate> = self.valid_dates()?.into_iter()
+            .filter_map(|schedule_date| {
+                if schedule_date.every_year {
+                    schedule_date.date.with_year(year)
+                } else {
+                    Some(schedule_date.date)
+                }
+            })
+            .collect();
+        dates.sort();
+        dates.dedup();
+        Ok(dates)
+    }
+
+    /// Counts the valid rows of the schedule file and detects duplicate and missing dates.
+    /// Invalid rows are ignored here, they are reported by `validate`.
+    ///
+    /// # Returns
+    /// The `ScheduleSummary` or an `InputFileNotFound` error if the file can not be read.
+    pub fn summary(&self) -> Result<ScheduleSummary, DailyBibleError> {
+        let mut dates: Vec<NaiveDate> = self.valid_dates()?.into_iter().map(|schedule_date| schedule_date.date).collect();
+
+        let valid_rows = dates.len();
+        dates.sort();
+
+        let mut duplicate_dates = Vec::new();
+        let mut missing_dates = Vec::new();
+        for pair in dates.windows(2) {
+            if pair[0] == pair[1] {
+                if !duplicate_dates.contains(&pair[0]) {
+                    duplicate_dates.push(pair[0]);
+                }
+            } else {
+                let mut date = pair[0].succ_opt();
+                while let Some(missing_date) = date {
+                    if missing_date >= pair[1] {
+                        break;
+                    }
+                    missing_dates.push(missing_date);
+                    date = missing_date.succ_opt();
                 }
+            }
+        }
+
+        Ok(ScheduleSummary { valid_rows, duplicate_dates, missing_dates })
+    }
+}
+
+/// Validates the schedules of all reading plans (see `validate_plan_schedule`).
+///
+/// # Returns
+/// `true` if all schedules can be used without problems, `false` if an error has been logged.
+pub fn validate_schedule() -> bool {
+    match loaded_plans() {
+        Some(plans) => {
+            let mut valid = true;
+            for name in sorted_plan_names(&plans) {
+                valid &= validate_plan_schedule(&plans[&name].read().unwrap_or_else(PoisonError::into_inner));
+            }
+            valid
+        },
+        None => validate_plan_schedule(&Schedule::with_date_format(&schedule_file_path(), &schedule_date_format())),
+    }
+}
+
+/// Validates a schedule and logs the result: every invalid row, the number of valid rows,
+/// duplicate dates and dates without an entry. If the file can not be read at all or the configured date format
+/// does not fit to the schedule, an error is logged, but the bot continues to run so that all other commands still work.
+///
+/// # Returns
+/// `true` if the schedule can be used without problems, `false` if an error has been logged.
+fn validate_plan_schedule(schedule: &Schedule) -> bool {
+    let file_path = &schedule.file_path;
+
+    if schedule.kind == ScheduleKind::Weekly {
+        // A weekly plan has no dates, so it is enough to check that every weekday can be found
+        log::info!("The schedule {} is a weekly plan.", file_path);
+        let monday = NaiveDate::from_isoywd_opt(LEAP_YEAR, 1, Weekday::Mon).unwrap();
+        let mut valid = true;
+        for date in monday.iter_days().take(7) {
+            if let Err(error) = schedule.weekly_biblereading(date) {
+                log::error!("The weekly plan {} has no reading for {}: {}", file_path, date.weekday(), error.to_string());
+                valid = false;
+            }
+        }
+        return valid;
+    }
+
+    // If the format does not fit, every single row would be reported, so only this error is logged
+    if let Err(schedule_error) = schedule.check_date_format() {
+        log::error!("The schedule {} can not be used: {}", file_path, schedule_error);
+        return false;
+    }
+
+    let schedule_errors = schedule.validate();
+    if !schedule_errors.is_empty() {
+        log::error!("The schedule {} contains {} problem(s):", file_path, schedule_errors.len());
+        for schedule_error in schedule_errors.iter() {
+            log::error!("{}", schedule_error);
+        }
+    }
+    let mut valid = schedule_errors.is_empty();
+
+    match schedule.summary() {
+        Ok(summary) => {
+            log::info!("The schedule {} contains {} valid row(s).", file_path, summary.valid_rows);
+            if !summary.duplicate_dates.is_empty() {
+                log::warn!("The following dates occur more than once in the schedule: {:?}", summary.duplicate_dates);
+            }
+            if !summary.missing_dates.is_empty() {
+                log::warn!("{} date(s) have no entry in the schedule: {:?}", summary.missing_dates.len(), summary.missing_dates);
+            }
+        },
+        Err(error) => {
+            log::error!("The schedule {} can not be read: {}", file_path, error.to_string());
+            valid = false;
+        }
+    }
+    valid
+}
+
+/// Returns the time when the schedule file of a reading plan or the directory of the reading plans
+/// (see `SCHEDULES_DIR_ENV`) has been modified the last time, or `None` if none of them exists.
+/// A plan which is added to or removed from the directory changes the time of the directory.
+pub fn schedule_modified_time() -> Option<SystemTime> {
+    plan_file_paths().into_iter()
+        .chain(std::iter::once(schedules_directory()))
+        .filter_map(|file_path| fs::metadata(file_path).and_then(|metadata| metadata.modified()).ok())
+        .max()
+}
+
+/// Splits a reading of the schedule into its single references at commas and semicolons
+/// (for example `Genesis 1,2; Mt 5:1-12` into `Genesis 1`, `Genesis 2` and `Mt 5:1-12`).
+/// Parts without a book name (like the `2` in `Genesis 1,2`) get the book of the previous reference,
+/// and parts after a reference with verses (like the `30` in `Mt 5:27,30`) also get its chapter.
+/// 
+/// # Returns
+/// The list of references, which contains the whole reading if it cannot be split.
+pub fn split_references(reading: &str) -> Vec<String> {
+    let mut references: Vec<String> = Vec::new();
+
+    for part in reading.split([',', ';']) {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+
+        let has_book = part.chars().any(|character| character.is_alphabetic());
+        match references.last() {
+            Some(previous) if !has_book => {
+                let (book, passage) = previous.rsplit_once(' ').unwrap_or(("", previous));
+                let prefix = match passage.split_once(':') {
+                    Some((chapter, _)) if !part.contains(':') => format!("{} {}:", book, chapter),
+                    _ => format!("{} ", book),
+                };
+                references.push(format!("{}{}", prefix.trim_start(), part));
             },
-            Err(_) => { }
+            _ => references.push(part.to_string()),
         }
     }
 
-    // If nothing has been found, we return an DateDoesNotExist Error
-    Err(BibleReadingNotFoundError {
-        error_cause: ErrorCause::DateDoesNotExist,
-        error_string: String::from("")
-    })
+    if references.is_empty() {
+        references.push(reading.trim().to_string());
+    }
+    references
+}
+
+/// Parses a comma-separated list of weekday abbreviations (for example `Mon,Tue,Wed`).
+/// 
+/// # Returns
+/// The list of weekdays (which is empty for an empty string) or `None` if one of the weekdays is invalid.
+fn parse_weekdays(weekdays_string: &str) -> Option<Vec<Weekday>> {
+    let mut weekdays = Vec::new();
+    for weekday_string in weekdays_string.split(',') {
+        let weekday_string = weekday_string.trim();
+        if weekday_string.is_empty() {
+            continue;
+        }
+        weekdays.push(weekday_string.parse::<Weekday>().ok()?);
+    }
+    Some(weekdays)
 }
 
 #[cfg(test)]
 mod tests {
 
+    use rand::{rngs::StdRng, SeedableRng};
+
     use super::*;
 
     #[test]
     fn date_can_be_found() {
-        let search_result = get_biblereading_for_date(NaiveDate::from_ymd_opt(2024, 9, 1).unwrap());
+        let search_result = get_biblereading_for_date("", NaiveDate::from_ymd_opt(2024, 9, 1).unwrap());
         assert!(search_result.is_ok());
         
         let biblereading = search_result.unwrap();
-        assert_eq!(biblereading.old_testament_reading, "Psalm 135,136");
-        assert_eq!(biblereading.new_testament_reading, "1Kor12");
+        assert_eq!(biblereading.old_testament_readings, vec!["Psalm 135,136"]);
+        assert_eq!(biblereading.new_testament_readings, vec!["1Kor12"]);
+    }
+
+    #[test]
+    fn readings_are_split_at_semicolons() {
+        assert_eq!(split_readings("Gen 1; Gen 2 ;Ps 3"), vec!["Gen 1", "Gen 2", "Ps 3"]);
+        assert_eq!(split_readings("Psalm 135,136"), vec!["Psalm 135,136"]);
+        assert_eq!(split_readings(" "), vec![""]);
     }
 
     #[test]
     fn date_cannot_be_found() {
         let date = NaiveDate::from_ymd_opt(2012, 7, 3).unwrap();
 
-        let search_result = get_biblereading_for_date(date);
+        let search_result = get_biblereading_for_date("", date);
         assert!(search_result.is_err());
     }
+
+    #[test]
+    fn date_can_be_found_in_other_file() {
+        let search_result = Schedule::with_date_format("testdata/test_schedule.csv", DEFAULT_DATE_FORMAT).annual_biblereading(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        assert!(search_result.is_ok());
+        assert_eq!(search_result.unwrap().new_testament_reading(), "Mk 2");
+    }
+
+    #[test]
+    fn weekday_column_is_honored() {
+        // 08-31-24 is a Saturday and 09-01-24 a Sunday, both restricted to Monday until Saturday
+        let search_result = Schedule::with_date_format("testdata/test_schedule_weekdays.csv", DEFAULT_DATE_FORMAT).annual_biblereading(NaiveDate::from_ymd_opt(2024, 8, 31).unwrap());
+        assert_eq!(search_result.unwrap().new_testament_reading(), "1Kor 11");
+
+        let search_result = Schedule::with_date_format("testdata/test_schedule_weekdays.csv", DEFAULT_DATE_FORMAT).annual_biblereading(NaiveDate::from_ymd_opt(2024, 9, 1).unwrap());
+        assert!(matches!(search_result.unwrap_err(), DailyBibleError::DateDoesNotExist));
+
+        // A row with an empty weekday column is valid on every day
+        let search_result = Schedule::with_date_format("testdata/test_schedule_weekdays.csv", DEFAULT_DATE_FORMAT).annual_biblereading(NaiveDate::from_ymd_opt(2024, 9, 2).unwrap());
+        assert_eq!(search_result.unwrap().new_testament_reading(), "1Kor 13");
+    }
+
+    #[test]
+    fn weekdays_can_be_parsed() {
+        assert_eq!(parse_weekdays("Mon, Tue,Sat"), Some(vec![Weekday::Mon, Weekday::Tue, Weekday::Sat]));
+        assert_eq!(parse_weekdays(""), Some(vec![]));
+        assert_eq!(parse_weekdays("Mon,Holiday"), None);
+    }
+
+    #[test]
+    fn schedule_is_valid() {
+        assert!(Schedule::new("schedule.csv").validate().is_empty());
+        assert!(Schedule::new("testdata/test_schedule_weekdays.csv").validate().is_empty());
+    }
+
+    #[test]
+    fn schedule_errors_are_collected() {
+        let schedule_errors = Schedule::new("testdata/test_schedule_invalid.csv").validate();
+        let lines: Vec<Option<u64>> = schedule_errors.iter().map(|schedule_error| schedule_error.line).collect();
+        assert_eq!(lines, vec![Some(3), Some(4), Some(5)]);
+
+        let schedule_errors = Schedule::new("testdata/does_not_exist.csv").validate();
+        assert_eq!(schedule_errors.len(), 1);
+        assert!(schedule_errors[0].line.is_none());
+    }
+
+    #[test]
+    fn schedule_summary_detects_duplicate_and_missing_dates() {
+        let summary = Schedule::new("testdata/test_schedule_duplicates.csv").summary().unwrap();
+        assert_eq!(summary.valid_rows, 4);
+        assert_eq!(summary.duplicate_dates, vec![NaiveDate::from_ymd_opt(2024, 3, 2).unwrap()]);
+        assert_eq!(summary.missing_dates, vec![NaiveDate::from_ymd_opt(2024, 3, 3).unwrap()]);
+
+        // Invalid rows are not counted
+        let summary = Schedule::new("testdata/test_schedule_invalid.csv").summary().unwrap();
+        assert_eq!(summary.valid_rows, 2);
+        assert_eq!(summary.missing_dates.len(), 3);
+
+        assert!(Schedule::new("testdata/does_not_exist.csv").summary().is_err());
+    }
+
+    #[test]
+    fn missing_file_cannot_be_read() {
+        let search_result = Schedule::with_date_format("testdata/does_not_exist.csv", DEFAULT_DATE_FORMAT).annual_biblereading(NaiveDate::from_ymd_opt(2024, 9, 1).unwrap());
+        let error = search_result.unwrap_err();
+        assert!(matches!(error, DailyBibleError::InputFileNotFound(_)));
+        assert!(error.to_string().contains("testdata/does_not_exist.csv"));
+
+        let search_result = Schedule::new("testdata/does_not_exist.csv").nth_biblereading(0, NaiveDate::from_ymd_opt(2024, 9, 1).unwrap());
+        assert!(search_result.unwrap_err().to_string().contains("testdata/does_not_exist.csv"));
+    }
+
+    #[test]
+    fn month_day_can_be_parsed() {
+        assert_eq!(parse_month_day("09-01"), Some((9, 1)));
+        assert_eq!(parse_month_day("02-29"), Some((2, 29)));
+        assert_eq!(parse_month_day("13-01"), None);
+        assert_eq!(parse_month_day("02-30"), None);
+        assert_eq!(parse_month_day("tomorrow"), None);
+    }
+
+    #[test]
+    fn month_day_can_be_found() {
+        let search_result = get_biblereading_for_month_day("", 9, 1, 2024);
+        assert!(search_result.is_ok());
+        assert_eq!(search_result.unwrap().new_testament_reading(), "1Kor12");
+
+        // Feb 29 does not exist in 2025, so no reading can be found
+        assert!(get_biblereading_for_month_day("", 2, 29, 2025).is_err());
+    }
+
+    #[test]
+    fn leap_day_lookup_reports_missing_date() {
+        // In a leap year Feb 29 is a valid date which is looked up like any other date
+        let search_result = get_biblereading_for_month_day("", 2, 29, 2024);
+        assert!(matches!(search_result.unwrap_err(), DailyBibleError::DateDoesNotExist));
+
+        // In a non leap year Feb 29 must not be reported as an invalid schedule
+        let search_result = get_biblereading_for_month_day("", 2, 29, 2025);
+        assert!(matches!(search_result.unwrap_err(), DailyBibleError::DateDoesNotExist));
+    }
+
+    /// Returns a reading whose passages are named after the date, for example `OT 02-28` and `NT 02-28`
+    fn leap_day_test_reading(date: NaiveDate) -> Result<BibleReading, ()> {
+        Ok(BibleReading {
+            date,
+            old_testament_readings: vec![format!("OT {}", date.format("%m-%d"))],
+            new_testament_readings: vec![format!("NT {}", date.format("%m-%d"))],
+            psalm: None,
+            wisdom: None,
+            audio: AudioLinks::default(),
+        })
+    }
+
+    #[test]
+    fn leap_day_reading_is_folded_in_years_without_feb_29() {
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let reading = |search_date, leap_day: LeapDay| leap_day.biblereading_for_date(search_date, leap_day_test_reading).unwrap();
+
+        // By default the reading of Feb 29 is skipped
+        assert_eq!(reading(date(2025, 2, 28), LeapDay::Skip).new_testament_reading(), "NT 02-28");
+        assert_eq!(reading(date(2025, 3, 1), LeapDay::Skip).new_testament_reading(), "NT 03-01");
+
+        let biblereading = reading(date(2025, 2, 28), LeapDay::February28);
+        assert_eq!(biblereading.old_testament_reading(), "OT 02-28; OT 02-29");
+        assert_eq!(biblereading.new_testament_reading(), "NT 02-28; NT 02-29");
+        assert_eq!(biblereading.date, date(2025, 2, 28));
+        assert_eq!(reading(date(2025, 3, 1), LeapDay::February28).new_testament_reading(), "NT 03-01");
+
+        assert_eq!(reading(date(2025, 2, 28), LeapDay::March1).new_testament_reading(), "NT 02-28");
+        assert_eq!(reading(date(2025, 3, 1), LeapDay::March1).new_testament_reading(), "NT 02-29; NT 03-01");
+
+        // In leap years Feb 29 has its own reading
+        assert_eq!(reading(date(2024, 2, 28), LeapDay::February28).new_testament_reading(), "NT 02-28");
+        assert_eq!(reading(date(2024, 3, 1), LeapDay::March1).new_testament_reading(), "NT 03-01");
+
+        // Without a reading of Feb 29 only the reading of the day is returned
+        let biblereading = LeapDay::February28.biblereading_for_date(date(2025, 2, 28), |search_date| {
+            if search_date.day() == 29 { Err(()) } else { leap_day_test_reading(search_date) }
+        });
+        assert_eq!(biblereading.unwrap().new_testament_reading(), "NT 02-28");
+    }
+
+    #[test]
+    fn date_format_without_year_is_valid_every_year() {
+        let search_result = Schedule::with_date_format("testdata/test_schedule_iso.csv", "%m-%d").annual_biblereading(NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+        let biblereading = search_result.unwrap();
+        assert_eq!(biblereading.new_testament_reading(), "Mk 3");
+        assert_eq!(biblereading.date, NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+
+        let search_result = Schedule::with_date_format("testdata/test_schedule_iso.csv", "%m-%d").annual_biblereading(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        assert_eq!(search_result.unwrap().new_testament_reading(), "Mk 2");
+
+        let search_result = Schedule::with_date_format("testdata/test_schedule_dotted.csv", "%d.%m").annual_biblereading(NaiveDate::from_ymd_opt(2026, 2, 28).unwrap());
+        assert_eq!(search_result.unwrap().new_testament_reading(), "Mk 1");
+
+        assert!(Schedule::with_date_format("testdata/test_schedule_iso.csv", "%m-%d").validate().is_empty());
+        assert!(Schedule::with_date_format("testdata/test_schedule_dotted.csv", "%d.%m").validate().is_empty());
+    }
+
+    #[test]
+    fn wrong_date_format_is_detected() {
+        assert!(Schedule::new("schedule.csv").check_date_format().is_ok());
+        assert!(Schedule::with_date_format("testdata/test_schedule_dotted.csv", "%d.%m").check_date_format().is_ok());
+
+        let schedule_error = Schedule::with_date_format("testdata/test_schedule_dotted.csv", "%m-%d").check_date_format().unwrap_err();
+        assert_eq!(schedule_error.line, Some(2));
+        assert!(schedule_error.message.contains("%m-%d"));
+
+        // The default format expects a year, so the ISO month-day schedule can not be parsed
+        assert!(Schedule::new("testdata/test_schedule_iso.csv").check_date_format().is_err());
+    }
+
+    #[test]
+    fn weekly_plan_is_detected() {
+        assert_eq!(Schedule::new("testdata/test_schedule_weekly.csv").kind, ScheduleKind::Weekly);
+        assert_eq!(Schedule::new("schedule.csv").kind, ScheduleKind::Annual);
+        assert_eq!(Schedule::new("testdata/does_not_exist.csv").kind, ScheduleKind::Annual);
+    }
+
+    #[test]
+    fn weekly_plan_uses_weekday() {
+        // 09-02-24 is a Monday and 09-08-24 a Sunday
+        let search_result = Schedule::new("testdata/test_schedule_weekly.csv").weekly_biblereading(NaiveDate::from_ymd_opt(2024, 9, 2).unwrap());
+        let biblereading = search_result.unwrap();
+        assert_eq!(biblereading.new_testament_reading(), "Mt 5");
+        assert_eq!(biblereading.old_testament_reading(), "Psalm 1");
+
+        let search_result = Schedule::new("testdata/test_schedule_weekly.csv").weekly_biblereading(NaiveDate::from_ymd_opt(2024, 9, 8).unwrap());
+        assert_eq!(search_result.unwrap().new_testament_reading(), "Mt 11");
+    }
+
+    #[test]
+    fn references_are_split() {
+        assert_eq!(split_references("1Kor12"), vec!["1Kor12"]);
+        assert_eq!(split_references("Psalm 135,136"), vec!["Psalm 135", "Psalm 136"]);
+        assert_eq!(split_references("Genesis 1, 2; Mt 5:1-12"), vec!["Genesis 1", "Genesis 2", "Mt 5:1-12"]);
+        assert_eq!(split_references("1. Mose 1-3; 2. Mose 4"), vec!["1. Mose 1-3", "2. Mose 4"]);
+        assert_eq!(split_references("Mt 5:27,30"), vec!["Mt 5:27", "Mt 5:30"]);
+        assert_eq!(split_references("Mt 5:27-48, 6:1"), vec!["Mt 5:27-48", "Mt 6:1"]);
+    }
+
+    #[test]
+    fn plan_day_uses_row_of_schedule() {
+        let date = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+
+        let biblereading = Schedule::new("schedule.csv").nth_biblereading(0, date).unwrap();
+        assert_eq!(biblereading.new_testament_reading(), "Mt 1");
+        assert_eq!(biblereading.old_testament_reading(), "Genesis 1,2,3");
+        assert_eq!(biblereading.date, date);
+
+        assert_eq!(Schedule::new("schedule.csv").nth_biblereading(4, date).unwrap().new_testament_reading(), "Mt 5:1-26");
+
+        let search_result = Schedule::new("schedule.csv").nth_biblereading(100000, date);
+        assert!(matches!(search_result.unwrap_err(), DailyBibleError::DateDoesNotExist));
+    }
+
+    #[test]
+    fn week_follows_plan_day() {
+        let date = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+
+        let days = get_biblereadings_for_week("", date, Some(4));
+        assert_eq!(days.len(), DAYS_PER_WEEK as usize);
+        assert_eq!(days[0].0, date);
+        assert_eq!(days[0].1.as_ref().unwrap().new_testament_reading(), "Mt 5:1-26");
+        assert_eq!(days[1].1.as_ref().unwrap().new_testament_reading(), Schedule::new("schedule.csv").nth_biblereading(5, date).unwrap().new_testament_reading());
+
+        assert!(get_biblereadings_for_week("", date, Some(100000)).iter().all(|(_, biblereading)| biblereading.is_none()));
+    }
+
+    #[test]
+    fn psalm_and_wisdom_columns_are_read() {
+        let date = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        let biblereading = Schedule::with_date_format("testdata/test_schedule_psalm_wisdom.csv", DEFAULT_DATE_FORMAT).annual_biblereading(date).unwrap();
+        assert_eq!(biblereading.new_testament_reading(), "1Kor 12");
+        assert_eq!(biblereading.psalm.as_deref(), Some("Psalm 1"));
+        assert_eq!(biblereading.wisdom.as_deref(), Some("Spr 1"));
+
+        // The sixth column contains the weekdays, 09-02-24 is a Monday
+        let date = NaiveDate::from_ymd_opt(2024, 9, 2).unwrap();
+        let biblereading = Schedule::with_date_format("testdata/test_schedule_psalm_wisdom.csv", DEFAULT_DATE_FORMAT).annual_biblereading(date).unwrap();
+        assert_eq!(biblereading.psalm.as_deref(), Some("Psalm 2"));
+        assert_eq!(biblereading.wisdom, None);
+
+        // Plans with 3 columns have no psalm and wisdom readings
+        let biblereading = Schedule::with_date_format("testdata/test_schedule.csv", DEFAULT_DATE_FORMAT).annual_biblereading(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()).unwrap();
+        assert_eq!(biblereading.psalm, None);
+        assert_eq!(biblereading.wisdom, None);
+
+        assert!(Schedule::new("testdata/test_schedule_psalm_wisdom.csv").validate().is_empty());
+    }
+
+    #[test]
+    fn multi_year_plan_uses_year_column() {
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let reading = |search_date| Schedule::with_date_format("testdata/test_schedule_years.csv", "%m-%d").annual_biblereading(search_date);
+
+        assert_eq!(reading(date(2024, 12, 31)).unwrap().new_testament_reading(), "Offb 22");
+        assert_eq!(reading(date(2025, 1, 1)).unwrap().new_testament_reading(), "Mt 1");
+        assert_eq!(reading(date(2026, 1, 1)).unwrap().new_testament_reading(), "Mt 3");
+        assert_eq!(reading(date(2028, 2, 29)).unwrap().old_testament_reading(), "Numbers 1");
+        // The rows are not repeated in the years which are not part of the plan
+        assert!(matches!(reading(date(2027, 1, 1)).unwrap_err(), DailyBibleError::DateDoesNotExist));
+        assert!(matches!(reading(date(2025, 12, 31)).unwrap_err(), DailyBibleError::DateDoesNotExist));
+        // The weekdays follow the year column, 01-02-25 is a Thursday
+        assert!(matches!(reading(date(2025, 1, 2)).unwrap_err(), DailyBibleError::DateDoesNotExist));
+
+        let schedule = Schedule::with_date_format("testdata/test_schedule_years.csv", "%m-%d");
+        assert!(schedule.validate().is_empty());
+        let summary = schedule.summary().unwrap();
+        assert_eq!(summary.valid_rows, 5);
+        assert!(summary.duplicate_dates.is_empty());
+    }
+
+    #[test]
+    fn available_dates_are_listed() {
+        let dates = Schedule::new("testdata/test_schedule.csv").available_dates(2030).unwrap();
+        assert!(!dates.is_empty());
+        assert!(dates.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // Rows of every year are placed in the requested year, Feb 29 only in leap years
+        let dates = Schedule::with_date_format("testdata/test_schedule_iso.csv", "%m-%d").available_dates(2025).unwrap();
+        assert!(dates.contains(&NaiveDate::from_ymd_opt(2025, 2, 28).unwrap()));
+        assert!(dates.iter().all(|date| date.year() == 2025));
+        let dates = Schedule::with_date_format("testdata/test_schedule_iso.csv", "%m-%d").available_dates(2024).unwrap();
+        assert!(dates.contains(&NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()));
+
+        // The rows of multi-year plans keep their year
+        let dates = Schedule::with_date_format("testdata/test_schedule_years.csv", "%m-%d").available_dates(2030).unwrap();
+        assert_eq!(dates.first(), NaiveDate::from_ymd_opt(2024, 12, 31).as_ref());
+        assert_eq!(dates.len(), 5);
+
+        assert!(Schedule::new("testdata/does_not_exist.csv").available_dates(2030).is_err());
+    }
+
+    #[test]
+    fn random_date_is_picked_from_the_dates() {
+        let dates = Schedule::new("testdata/test_schedule.csv").available_dates(2030).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..10 {
+            let date = pick_date(&dates, &mut rng).unwrap();
+            assert!(dates.contains(&date));
+            assert!(Schedule::with_date_format("testdata/test_schedule.csv", DEFAULT_DATE_FORMAT).annual_biblereading(date).is_ok());
+        }
+        // The same seed picks the same date
+        assert_eq!(pick_date(&dates, &mut StdRng::seed_from_u64(7)), pick_date(&dates, &mut StdRng::seed_from_u64(7)));
+        assert_eq!(pick_date(&[], &mut rng), None);
+    }
+
+    #[test]
+    fn audio_columns_are_read() {
+        let file_path = "testdata/test_schedule_audio.csv";
+        let reading = |date| Schedule::with_date_format(file_path, DEFAULT_DATE_FORMAT).annual_biblereading(date).unwrap();
+
+        let biblereading = reading(NaiveDate::from_ymd_opt(2024, 9, 1).unwrap());
+        assert_eq!(biblereading.old_testament_readings, vec!["Hiob 2"]);
+        assert_eq!(biblereading.new_testament_readings, vec!["1Kor 12"]);
+        assert_eq!(biblereading.audio, AudioLinks {
+            old_testament: Some(String::from("https://example.com/ot/hiob-2.mp3")),
+            new_testament: Some(String::from("https://example.com/nt/1kor-12.mp3")),
+        });
+        assert!(biblereading.psalm.is_none());
+
+        let biblereading = reading(NaiveDate::from_ymd_opt(2024, 9, 2).unwrap());
+        assert!(biblereading.audio.old_testament.is_none());
+        assert!(biblereading.audio.new_testament.is_some());
+
+        // A link which is no http(s) URL is left out and reported by the validation
+        let biblereading = reading(NaiveDate::from_ymd_opt(2024, 9, 3).unwrap());
+        assert_eq!(biblereading.audio, AudioLinks::default());
+        let lines: Vec<Option<u64>> = Schedule::new(file_path).validate().iter().map(|schedule_error| schedule_error.line).collect();
+        assert_eq!(lines, vec![Some(4)]);
+
+        let biblereading = Schedule::new(file_path).nth_biblereading(1, NaiveDate::from_ymd_opt(2030, 1, 1).unwrap()).unwrap();
+        assert_eq!(biblereading.audio.new_testament.as_deref(), Some("https://example.com/nt/1kor-13.mp3"));
+    }
+
+    #[test]
+    fn schedule_without_audio_columns_has_no_audio() {
+        let biblereading = Schedule::with_date_format("testdata/test_schedule.csv", DEFAULT_DATE_FORMAT).annual_biblereading(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()).unwrap();
+        assert_eq!(biblereading.audio, AudioLinks::default());
+
+        // The year column is counted without the audio columns
+        let headers = csv::StringRecord::from(vec!["Date", "New Testament", "OT Audio", "Old Testament", "Year"]);
+        let audio_columns = AudioColumns::from_headers(&headers);
+        assert_eq!(audio_columns, AudioColumns { old_testament: Some(2), new_testament: None });
+        assert!(has_year_column(&audio_columns.split(&headers).0));
+    }
+
+    #[test]
+    fn plans_are_indexed_by_file_name() {
+        let plans = index_plans(Path::new("testdata"));
+        assert!(plans.contains_key("test_schedule"));
+        assert!(plans.contains_key("test_schedule_weekly"));
+        // Only CSV files are schedules
+        assert!(!plans.contains_key("test_userstate_loading"));
+        assert_eq!(plans["test_schedule_iso"], "testdata/test_schedule_iso.csv");
+
+        assert_eq!(select_plan(&plans, "test_schedule_weekly").unwrap(), "testdata/test_schedule_weekly.csv");
+        // An empty or unknown plan selects the first plan in alphabetical order
+        assert_eq!(select_plan(&plans, "").unwrap(), "testdata/test_schedule.csv");
+        assert_eq!(select_plan(&plans, "does_not_exist").unwrap(), "testdata/test_schedule.csv");
+
+        let biblereading = Schedule::new(select_plan(&plans, "test_schedule_weekly").unwrap()).biblereading_for_date(NaiveDate::from_ymd_opt(2024, 9, 2).unwrap());
+        assert!(biblereading.is_ok());
+
+        assert!(index_plans(Path::new("testdata/does_not_exist")).is_empty());
+        assert!(select_plan(&HashMap::<String, String>::new(), "").is_none());
+    }
+
+    #[test]
+    fn only_valid_schedules_are_reloaded() {
+        let schedule = |file_path: &str| Arc::new(RwLock::new(Schedule::new(file_path)));
+        let file_path = |plans: &HashMap<String, Arc<RwLock<Schedule>>>, name: &str| plans[name].read().unwrap().file_path.clone();
+        let previous_plans = HashMap::from([
+            ("annual".to_string(), schedule("testdata/test_schedule.csv")),
+            ("weekdays".to_string(), schedule("testdata/test_schedule.csv")),
+            ("removed".to_string(), schedule("testdata/test_schedule.csv")),
+        ]);
+
+        let plan_files = HashMap::from([
+            ("annual".to_string(), "testdata/test_schedule_invalid.csv".to_string()),
+            ("weekdays".to_string(), "testdata/test_schedule_weekdays.csv".to_string()),
+            ("added".to_string(), "schedule.csv".to_string()),
+            ("missing".to_string(), "testdata/does_not_exist.csv".to_string()),
+        ]);
+        let (plans, reloaded) = reload_schedules(&previous_plans, plan_files, DEFAULT_DATE_FORMAT);
+        assert!(!reloaded);
+        assert_eq!(sorted_plan_names(&plans), vec!["added", "annual", "weekdays"]);
+
+        // An invalid schedule keeps the previous one, a valid one is swapped in the same Arc
+        assert_eq!(file_path(&plans, "annual"), "testdata/test_schedule.csv");
+        assert_eq!(file_path(&plans, "weekdays"), "testdata/test_schedule_weekdays.csv");
+        assert!(Arc::ptr_eq(&plans["weekdays"], &previous_plans["weekdays"]));
+        assert_eq!(file_path(&previous_plans, "weekdays"), "testdata/test_schedule_weekdays.csv");
+        assert_eq!(file_path(&plans, "added"), "schedule.csv");
+
+        let plan_files = HashMap::from([("annual".to_string(), "schedule.csv".to_string())]);
+        let (plans, reloaded) = reload_schedules(&previous_plans, plan_files, DEFAULT_DATE_FORMAT);
+        assert!(reloaded);
+        assert_eq!(file_path(&plans, "annual"), "schedule.csv");
+    }
+
+    #[test]
+    fn year_column_is_detected_from_header() {
+        let headers = csv::StringRecord::from(vec!["Date", "New Testament", "Old Testament", " year "]);
+        assert!(has_year_column(&headers));
+        let headers = csv::StringRecord::from(vec!["Date", "New Testament", "Old Testament", "Weekdays"]);
+        assert!(!has_year_column(&headers));
+
+        let row = csv::StringRecord::from(vec!["01-01", "Mt 1", "Genesis 1", "2025", "Mon"]);
+        let (row_without_year, year) = split_year_column(&row, true);
+        assert_eq!(row_without_year, csv::StringRecord::from(vec!["01-01", "Mt 1", "Genesis 1", "Mon"]));
+        assert_eq!(year.as_deref(), Some("2025"));
+        assert_eq!(split_year_column(&row, false), (row.clone(), None));
+
+        // Without a year column, a format without year stays valid in every year
+        let search_result = Schedule::with_date_format("testdata/test_schedule_iso.csv", "%m-%d").annual_biblereading(NaiveDate::from_ymd_opt(2031, 2, 28).unwrap());
+        assert_eq!(search_result.unwrap().new_testament_reading(), "Mk 1");
+    }
 }
-    
\ No newline at end of file
@@ -0,0 +1,199 @@
+//! In this unit, the text of Bible passages is fetched from a configurable Bible text API for the `/verse` command.
+//! Recent passages are cached in memory, so that popular passages do not lead to repeated requests.
+
+use std::{collections::{HashMap, VecDeque}, env, fmt, time::Duration};
+
+use serde::Deserialize;
+use tokio::sync::RwLock;
+
+use crate::localize::Language;
+
+/// The name of the environment variable with the endpoint of the Bible text API. Without it, `/verse` only sends links.
+/// The endpoint is called with the query parameters `reference` and `language` (for example `en`) and has to
+/// answer with a JSON object which contains the passage in the field `text`.
+pub const BIBLE_API_URL_ENV: &str = "BIBLE_API_URL";
+
+/// The name of the environment variable with the optional key of the Bible text API, which is sent in the `api-key` header
+pub const BIBLE_API_KEY_ENV: &str = "BIBLE_API_KEY";
+
+/// The number of passages which are kept in the cache
+const CACHE_CAPACITY: usize = 100;
+
+/// The time after which a request to the Bible text API is cancelled
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+
+/// The reason why the text of a passage could not be returned
+#[derive(Debug, Clone, PartialEq)]
+pub enum BibleTextError {
+    /// No endpoint is configured with `BIBLE_API_URL_ENV`
+    NotConfigured,
+    /// The API does not know the reference
+    PassageNotFound,
+    /// The API could not be reached or returned an invalid response
+    Request(String),
+}
+
+impl fmt::Display for BibleTextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BibleTextError::NotConfigured => write!(f, "No Bible text API is configured in {}.", BIBLE_API_URL_ENV),
+            BibleTextError::PassageNotFound => write!(f, "The Bible text API does not know the passage."),
+            BibleTextError::Request(error) => write!(f, "The request to the Bible text API failed: {}", error),
+        }
+    }
+}
+
+/// The response of the Bible text API
+#[derive(Deserialize)]
+struct PassageResponse {
+    text: Option<String>,
+}
+
+
+/// The passages which have been fetched most recently. If the cache is full, the oldest passage is removed.
+struct PassageCache {
+    capacity: usize,
+    passages: HashMap<(String, Language), String>,
+    order: VecDeque<(String, Language)>,
+}
+
+impl PassageCache {
+    fn new(capacity: usize) -> Self {
+        PassageCache {
+            capacity,
+            passages: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&self, reference: &str, language: &Language) -> Option<String> {
+        self.passages.get(&(normalize_reference(reference), language.clone())).cloned()
+    }
+
+    fn insert(&mut self, reference: &str, language: &Language, text: String) {
+        let key = (normalize_reference(reference), language.clone());
+        if self.passages.insert(key.clone(), text).is_some() {
+            return;
+        }
+
+        self.order.push_back(key);
+        if self.order.len() > self.capacity {
+            if let Some(oldest_key) = self.order.pop_front() {
+                self.passages.remove(&oldest_key);
+            }
+        }
+    }
+}
+
+/// Normalizes a reference for the cache, so that `John 3,16` and `john  3,16` are the same passage.
+fn normalize_reference(reference: &str) -> String {
+    reference.split_whitespace().collect::<Vec<&str>>().join(" ").to_lowercase()
+}
+
+
+/// Fetches the text of Bible passages from the API which is configured with `BIBLE_API_URL_ENV`.
+pub struct BibleTextClient {
+    endpoint: Option<String>,
+    api_key: Option<String>,
+    client: reqwest::Client,
+    cache: RwLock<PassageCache>,
+}
+
+impl BibleTextClient {
+    /// Creates the client with the endpoint and the key from the environment variables.
+    pub fn from_env() -> Self {
+        BibleTextClient {
+            endpoint: env::var(BIBLE_API_URL_ENV).ok().filter(|endpoint| !endpoint.trim().is_empty()),
+            api_key: env::var(BIBLE_API_KEY_ENV).ok(),
+            client: reqwest::Client::new(),
+            cache: RwLock::new(PassageCache::new(CACHE_CAPACITY)),
+        }
+    }
+
+    /// Returns the text of the passage in the given language.
+    ///
+    /// # Params
+    /// - `reference`: The Bible reference as typed by the user (for example `John 3,16`)
+    /// - `language`: The language of the user, which selects the translation of the API
+    ///
+    /// # Returns
+    /// The text of the passage or the reason why it could not be fetched.
+    pub async fn get_passage(&self, reference: &str, language: &Language) -> Result<String, BibleTextError> {
+        let endpoint = self.endpoint.as_ref().ok_or(BibleTextError::NotConfigured)?;
+
+        if let Some(text) = self.cache.read().await.get(reference, language) {
+            return Ok(text);
+        }
+
+        let mut request = self.client
+            .get(endpoint)
+            .query(&[("reference", reference.trim()), ("language", language.locale_id())])
+            .timeout(REQUEST_TIMEOUT);
+        if let Some(api_key) = &self.api_key {
+            request = request.header("api-key", api_key);
+        }
+
+        let response = request.send().await.map_err(|error| BibleTextError::Request(error.to_string()))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(BibleTextError::PassageNotFound);
+        }
+        let response = response.error_for_status().map_err(|error| BibleTextError::Request(error.to_string()))?;
+        let body = response.text().await.map_err(|error| BibleTextError::Request(error.to_string()))?;
+
+        let text = parse_passage(&body)?;
+        self.cache.write().await.insert(reference, language, text.clone());
+        Ok(text)
+    }
+}
+
+/// Reads the text of the passage from the response of the API. A response without text (or with an empty text)
+/// means that the API could not resolve the reference.
+fn parse_passage(body: &str) -> Result<String, BibleTextError> {
+    let response: PassageResponse = serde_json::from_str(body).map_err(|error| BibleTextError::Request(error.to_string()))?;
+    match response.text.map(|text| text.trim().to_string()) {
+        Some(text) if !text.is_empty() => Ok(text),
+        _ => Err(BibleTextError::PassageNotFound),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passage_is_parsed() {
+        assert_eq!(parse_passage(r#"{"text": " For God so loved the world "}"#), Ok(String::from("For God so loved the world")));
+        assert_eq!(parse_passage(r#"{"text": ""}"#), Err(BibleTextError::PassageNotFound));
+        assert_eq!(parse_passage(r#"{"error": "unknown book"}"#), Err(BibleTextError::PassageNotFound));
+        assert!(matches!(parse_passage("<html>"), Err(BibleTextError::Request(_))));
+    }
+
+    #[test]
+    fn test_cache_removes_oldest_passage() {
+        let mut cache = PassageCache::new(2);
+        cache.insert("John 3,16", &Language::English, String::from("For God so loved the world"));
+        cache.insert("Joh 3,16", &Language::German, String::from("Denn also hat Gott die Welt geliebt"));
+
+        // The reference is normalized and the language is part of the key
+        assert_eq!(cache.get("john  3,16", &Language::English), Some(String::from("For God so loved the world")));
+        assert_eq!(cache.get("John 3,16", &Language::German), None);
+
+        cache.insert("Ps 23", &Language::English, String::from("The Lord is my shepherd"));
+        assert_eq!(cache.get("John 3,16", &Language::English), None);
+        assert!(cache.get("Joh 3,16", &Language::German).is_some());
+        assert!(cache.get("Ps 23", &Language::English).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_client_without_endpoint() {
+        let client = BibleTextClient {
+            endpoint: None,
+            api_key: None,
+            client: reqwest::Client::new(),
+            cache: RwLock::new(PassageCache::new(CACHE_CAPACITY)),
+        };
+        assert_eq!(client.get_passage("John 3,16", &Language::English).await, Err(BibleTextError::NotConfigured));
+    }
+}
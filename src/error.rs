@@ -0,0 +1,50 @@
+//! In this unit, the error type of the bot is defined. It covers the errors of the schedule, of the persistence
+//! of the user states and of the requests to Telegram, so that callers can decide by the variant how to react.
+
+use thiserror::Error;
+
+
+/// The errors which can occur while reading the schedule, persisting the user states or sending messages
+#[derive(Debug, Error)]
+pub enum DailyBibleError {
+    /// The schedule file (or another input file) could not be read
+    #[error("The input file {0} has not been found.")]
+    InputFileNotFound(String),
+    /// The schedule contains no reading for the requested date
+    #[error("There exists no entry with bible reading for today's date.")]
+    DateDoesNotExist,
+    /// The schedule file contains a row which can not be parsed
+    #[error("The format of the csv file seems to be invalid: {0}")]
+    InvalidFormat(String),
+    /// A file could not be read or written
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The user states (or another JSON file) could not be serialized or deserialized
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    /// A CSV file could not be parsed
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+    /// The SQLite database of the user states could not be used
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+    /// A request to Telegram failed
+    #[error("Telegram error: {0}")]
+    Telegram(#[from] teloxide::RequestError),
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors_can_be_matched() {
+        let error: DailyBibleError = serde_json::from_str::<u32>("no json").unwrap_err().into();
+        assert!(matches!(error, DailyBibleError::Json(_)));
+        assert!(error.to_string().starts_with("JSON error: "));
+
+        let error = DailyBibleError::InputFileNotFound(String::from("schedule.csv"));
+        assert_eq!(error.to_string(), "The input file schedule.csv has not been found.");
+    }
+}
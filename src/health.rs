@@ -0,0 +1,116 @@
+//! In this unit, the HTTP server for health checks and metrics (for example for Kubernetes) is implemented.
+
+use std::sync::Arc;
+
+use axum::{extract::State, routing::get, Json, Router};
+use chrono::Local;
+use serde::Serialize;
+
+use crate::biblereading;
+use crate::error::DailyBibleError;
+use crate::metrics::Metrics;
+use crate::userstate::UserStateWrapper;
+
+
+/// The state which is shared with the handlers of the health check server
+#[derive(Clone)]
+struct HealthState {
+    user_state_wrapper: Arc<UserStateWrapper>,
+    metrics: Arc<Metrics>,
+}
+
+
+/// The JSON body which is returned by the `/health` route
+#[derive(Debug, Serialize)]
+struct HealthReport {
+    status: &'static str,
+    /// Whether the schedule file can be read and parsed
+    schedule_loaded: bool,
+    /// The number of users with a timer who receive the reminders (which are not paused and enabled in groups)
+    active_users: usize,
+}
+
+
+/// Runs the health check server on the given port until the shutdown future completes.
+/// 
+/// # Routes
+/// - `/healthz`: Returns 200 while the bot is running
+/// - `/health`: Returns 200 with a JSON report (whether the schedule is loaded and the number of active users)
+/// - `/metrics`: Returns the counters in plain text (total users, reminders sent today, send failures, polls sent, skipped users)
+pub async fn run_health_server(
+    port: u16,
+    user_state_wrapper: Arc<UserStateWrapper>,
+    metrics: Arc<Metrics>,
+    shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+) -> std::io::Result<()> {
+    let app = Router::new()
+        .route("/healthz", get(healthz))
+        .route("/health", get(health))
+        .route("/metrics", get(metrics_handler))
+        .with_state(HealthState { user_state_wrapper, metrics });
+
+    let listener = tokio::net::TcpListener::bind(("0.0.0.0", port)).await?;
+    log::info!("Health check server listening on port {}", port);
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown)
+        .await
+}
+
+async fn healthz() -> &'static str {
+    "OK"
+}
+
+async fn health(State(state): State<HealthState>) -> Json<HealthReport> {
+    // A missing reading for today is not a problem of the schedule file itself (the default reading plan is checked)
+    let schedule_loaded = match biblereading::get_todays_biblereading("") {
+        Ok(_) => true,
+        Err(error) => matches!(error, DailyBibleError::DateDoesNotExist),
+    };
+
+    let active_users = state.user_state_wrapper.user_states.read().await
+        .values()
+        .filter(|user_state| user_state.timer.is_some() && user_state.receives_reminders())
+        .count();
+
+    Json(HealthReport { status: "ok", schedule_loaded, active_users })
+}
+
+async fn metrics_handler(State(state): State<HealthState>) -> String {
+    let total_users = state.user_state_wrapper.user_states.read().await.len();
+
+    format!(
+        "dailybible_users_total {}\ndailybible_reminders_sent_today {}\ndailybible_send_failures_total {}\ndailybible_polls_sent_total {}\ndailybible_users_skipped_total {}\n",
+        total_users,
+        state.metrics.reminders_sent_today(Local::now().date_naive()),
+        state.metrics.send_failures(),
+        state.metrics.polls_sent(),
+        state.metrics.users_skipped()
+    )
+}
+
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveTime;
+    use teloxide::types::ChatId;
+
+    use crate::userstate::UserState;
+    use crate::userstore::JsonFileStore;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_health_counts_active_users() {
+        let user_state_wrapper = Arc::new(UserStateWrapper::new(Arc::new(JsonFileStore::new("testfile_health.json"))));
+        let timer = NaiveTime::from_hms_opt(8, 0, 0);
+        user_state_wrapper.update_userstate(UserState { timer, ..UserState::new(ChatId(1)) }).await;
+        user_state_wrapper.update_userstate(UserState { timer, paused: true, ..UserState::new(ChatId(2)) }).await;
+        user_state_wrapper.update_userstate(UserState::new(ChatId(3))).await;
+
+        let state = HealthState { user_state_wrapper, metrics: Arc::new(Metrics::new()) };
+        let report = health(State(state)).await.0;
+        assert_eq!(report.active_users, 1);
+        assert!(report.schedule_loaded);
+    }
+}
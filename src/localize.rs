@@ -1,97 +1,1111 @@
-use chrono::NaiveTime;
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime};
 use serde::{Deserialize, Serialize};
-use teloxide::utils::markdown::escape;
+use teloxide::utils::markdown::{escape, escape_code, escape_link_url};
+use url::Url;
 
-use crate::biblereading::BibleReading;
+use crate::biblereading::{split_references, BibleReading, LEAP_YEAR};
+use crate::verses::VerseOfTheDay;
+
+mod english;
+mod ftl;
+mod german;
+mod italian;
+mod portuguese;
+mod spanish;
+mod template;
+
+pub use ftl::load_locales;
+pub use template::load_reminder_templates;
 
 /// This enum contains the list of all supported languages for the bot
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum Language {
     English,
-    German
+    German,
+    Spanish,
+    Portuguese,
+    Italian
 }
 
-pub fn msg_biblereading(lang: &Language, biblereading: BibleReading) -> String {
-    match lang {
-        Language::English => {
-            format!(
-                "*📖 This is a reminder to read the Bible today*: \n\nOT: {}\nNT: {}", 
-                escape(&biblereading.old_testament_reading),
-                escape(&biblereading.new_testament_reading)
-            )
+impl Language {
+    /// All supported languages, in the order in which they are offered to the user
+    pub const ALL: [Language; 5] = [Language::English, Language::German, Language::Spanish, Language::Portuguese, Language::Italian];
+
+    /// Returns the locale identifier of the language, which is also the name of its `.ftl` file
+    pub fn locale_id(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::German => "de",
+            Language::Spanish => "es",
+            Language::Portuguese => "pt",
+            Language::Italian => "it",
+        }
+    }
+
+    /// Returns the name of the language in the language itself (for example `Deutsch`)
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+            Language::Spanish => "Español",
+            Language::Portuguese => "Português",
+            Language::Italian => "Italiano",
+        }
+    }
+
+    /// Returns the implementation of all messages in this language. The messages from the `.ftl` file
+    /// of the language are used if it has been loaded, otherwise the built-in messages.
+    pub fn localizer(&self) -> &'static dyn Localizer {
+        if let Some(localizer) = ftl::fluent_localizer(self) {
+            return localizer;
+        }
+
+        match self {
+            Language::English => &english::English,
+            Language::German => &german::German,
+            Language::Spanish => &spanish::Spanish,
+            Language::Portuguese => &portuguese::Portuguese,
+            Language::Italian => &italian::Italian,
+        }
+    }
+}
+
+/// The messages of the bot in one language. Every language implements this trait in its own module,
+/// so all strings of a language are kept together and a new language only needs a new module.
+/// The same messages can be loaded from the `.ftl` files in the locales directory (see `load_locales`).
+/// The `msg_*` functions are thin wrappers which select the implementation with `Language::localizer`.
+pub trait Localizer: Sync {
+    fn biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String;
+    fn verse_of_the_day(&self) -> String;
+    fn next_biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String;
+    fn next_biblereading_not_found(&self) -> String;
+    fn biblereading_not_found(&self) -> String;
+    fn button_mark_read(&self) -> String;
+    fn button_snooze(&self) -> String;
+    fn marked_as_read(&self, streak: u32) -> String;
+    fn poll_stale(&self) -> String;
+    fn snoozed(&self, time: &NaiveTime) -> String;
+    fn language_set(&self) -> String;
+    fn poll(&self) -> PollText;
+    fn not_implemented_yet(&self) -> String;
+    fn select_language(&self) -> String;
+    fn timer_updated(&self, time: &NaiveTime) -> String;
+    fn timer_unset(&self) -> String;
+    fn error_timer_update(&self) -> String;
+    fn biblereading_not_found_for_date(&self, date_string: &str) -> String;
+    fn error_invalid_date(&self) -> String;
+    fn data_deleted(&self) -> String;
+    fn no_data_to_delete(&self) -> String;
+    fn bible_version_set(&self, version: &str) -> String;
+    fn button_marked_read(&self) -> String;
+    fn read_button_enabled(&self) -> String;
+    fn read_button_disabled(&self) -> String;
+    fn error_read_button(&self) -> String;
+    fn bible_version_unset(&self) -> String;
+    fn error_bible_version(&self) -> String;
+    fn paused(&self) -> String;
+    fn resumed(&self) -> String;
+    fn status(&self, timer: &Option<NaiveTime>, paused: bool) -> String;
+    fn feedback_sent(&self) -> String;
+    fn feedback_unavailable(&self) -> String;
+    fn error_feedback_empty(&self) -> String;
+    fn group_enabled(&self) -> String;
+    fn group_not_enabled(&self) -> String;
+    fn enable_private_chat(&self) -> String;
+    fn plan_started(&self) -> String;
+    fn plan_stopped(&self) -> String;
+    fn week_heading(&self) -> String;
+    fn week_day(&self, date_string: &str, biblereading: &BibleReading) -> String;
+    fn week_day_not_found(&self, date_string: &str) -> String;
+    fn skipped_today(&self) -> String;
+    fn psalm_label(&self) -> String;
+    fn wisdom_label(&self) -> String;
+    fn no_data_to_export(&self) -> String;
+    fn data_imported(&self) -> String;
+    fn error_import_invalid(&self) -> String;
+    fn error_import_no_document(&self) -> String;
+    fn date(&self, date: &NaiveDate) -> String;
+    fn error_poll(&self) -> String;
+    fn anonymous_poll_enabled(&self) -> String;
+    fn anonymous_poll_disabled(&self) -> String;
+    fn error_anonymous_poll(&self) -> String;
+    fn history_heading(&self, read_days: usize, days: usize) -> String;
+    fn verse_text_unavailable(&self) -> String;
+    fn error_verse_not_found(&self, reference: &str) -> String;
+    fn error_verse_reference(&self) -> String;
+    fn error_snooze_until(&self) -> String;
+    fn reading_lines(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String;
+    fn custom_prefix_set(&self) -> String;
+    fn custom_prefix_unset(&self) -> String;
+    fn error_custom_prefix(&self, max_length: usize) -> String;
+    fn markdown_enabled(&self) -> String;
+    fn markdown_disabled(&self) -> String;
+    fn error_markdown(&self) -> String;
+    fn weekly_summary(&self, read_days: usize) -> String;
+    fn weekly_summary_enabled(&self) -> String;
+    fn weekly_summary_disabled(&self) -> String;
+    fn error_weekly_summary(&self) -> String;
+    fn confirm_delete_data(&self) -> String;
+    fn button_delete_confirm(&self) -> String;
+    fn button_delete_cancel(&self) -> String;
+    fn deletion_cancelled(&self) -> String;
+    fn deletion_expired(&self) -> String;
+    fn next_reminder_today(&self, time: &NaiveTime) -> String;
+    fn next_reminder_tomorrow(&self, time: &NaiveTime) -> String;
+    fn next_reminder_on(&self, date: &str, time: &NaiveTime) -> String;
+    fn next_reminder_no_timer(&self) -> String;
+    fn next_reminder_paused(&self) -> String;
+    fn user_information_header(&self) -> String;
+    fn no_data(&self) -> String;
+    fn random_reading(&self) -> String;
+    fn button_listen_old_testament(&self) -> String;
+    fn button_listen_new_testament(&self) -> String;
+    fn about(&self, version: &str, commit: &str, languages: &str) -> String;
+    fn available_plans(&self, plans: &str, current: &str) -> String;
+    fn plan_set(&self, plan: &str) -> String;
+    fn error_unknown_plan(&self, plan: &str) -> String;
+    fn member_since(&self, date: &str, days: i64) -> String;
+    fn member_since_unknown(&self) -> String;
+    fn private_chat(&self) -> String;
+    fn group_chat_enabled(&self) -> String;
+    fn group_chat_not_enabled(&self) -> String;
+}
+
+/// The maximum length of the text of a passage which is sent by `/verse` (in UTF-16 code units),
+/// which leaves enough space for the reference below the length limit of Telegram
+const MAX_VERSE_TEXT_LENGTH: usize = 3500;
+
+/// Formats a Bible reference for MarkdownV2. If a Bible version is given, the reference is a link
+/// to the passage in this version on BibleGateway, otherwise only the escaped reference is returned.
+fn format_reference(reference: &str, bible_version: Option<&str>) -> String {
+    match bible_version {
+        Some(version) => {
+            match Url::parse_with_params("https://www.biblegateway.com/passage/", &[("search", reference), ("version", version)]) {
+                Ok(url) => format!("[{}]({})", escape(reference), escape_link_url(url.as_str())),
+                Err(_) => escape(reference),
+            }
         },
-        Language::German => {
-            format!(
-                "*📖 Dies ist eine Erinnerung, heute in der Bibel zu lesen*: \n\nAT: {}\nNT: {}", 
-                escape(&biblereading.old_testament_reading),
-                escape(&biblereading.new_testament_reading)
-            )
+        None => escape(reference),
+    }
+}
+
+/// Escapes a text of a user for MarkdownV2 and truncates it, so that the escaped text is at most `max_length`
+/// UTF-16 code units long (which is how Telegram measures the length of a message). A truncated text ends with `…`.
+pub fn escape_truncated(text: &str, max_length: usize) -> String {
+    let escaped = escape(text);
+    if escaped.encode_utf16().count() <= max_length {
+        return escaped;
+    }
+
+    let mut truncated = String::new();
+    let mut length = 0;
+    for character in text.chars() {
+        let escaped_character = escape(&character.to_string());
+        let character_length = escaped_character.encode_utf16().count();
+        // One code unit is reserved for the ellipsis
+        if length + character_length + 1 > max_length {
+            break;
         }
+        truncated.push_str(&escaped_character);
+        length += character_length;
     }
+    truncated.push('…');
+    truncated
 }
 
-pub fn msg_biblereading_not_found(lang: &Language) -> String {
-    match lang {
-        Language::English => "This is a reminder to read your bible!".to_string(),
-        Language::German => "Dies ist eine Erinnerung, heute in der Bibel zu lesen.".to_string()
+/// Formats a reading of the schedule for MarkdownV2. A reading with several references (like `Psalm 135,136`)
+/// is rendered as a bullet list with one reference per line, a single reference stays on the same line.
+fn format_reading(reading: &str, bible_version: Option<&str>) -> String {
+    let references = split_references(reading);
+    if references.len() == 1 {
+        return format_reference(&references[0], bible_version);
     }
+
+    references.iter()
+        .map(|reference| format!("\n• {}", format_reference(reference, bible_version)))
+        .collect()
 }
 
-pub fn msg_language_set(lang: &Language) -> String {
-    match lang {
-        Language::English => "Language set to English.".to_string(),
-        Language::German => "Die Sprache wurde auf Deutsch umgestellt.".to_string()
+/// Formats the optional psalm and wisdom readings of a plan with 5 or 6 columns for MarkdownV2 (one line each),
+/// which are appended to the Old and New Testament readings. For plans without them, an empty string is returned.
+fn format_psalm_and_wisdom(lang: &Language, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+    let mut lines = String::new();
+    if let Some(psalm) = &biblereading.psalm {
+        lines.push_str(&format!("\n{}: {}", escape(&lang.localizer().psalm_label()), format_reading(psalm, bible_version)));
+    }
+    if let Some(wisdom) = &biblereading.wisdom {
+        lines.push_str(&format!("\n{}: {}", escape(&lang.localizer().wisdom_label()), format_reading(wisdom, bible_version)));
+    }
+    lines
+}
+
+/// Returns the date which is shown for a reading. Dates without a real year (which are placed in `LEAP_YEAR`)
+/// are moved to the given year, so that the weekday is correct. Feb 29 is kept if the year is no leap year.
+fn display_date(date: NaiveDate, current_year: i32) -> NaiveDate {
+    if date.year() != LEAP_YEAR {
+        return date;
     }
+    date.with_year(current_year).unwrap_or(date)
 }
 
-pub fn msg_poll_text(lang: &Language) -> Vec<String> {
-    match lang {
-        Language::English => vec![
-            String::from("Have you read the Bible today?"),
-            String::from("Yes"),
-            String::from("No")
-        ],
-        Language::German => vec![
-            String::from("Hast du heute in der Bibel gelesen?"),
-            String::from("Ja"),
-            String::from("Nein")
-        ],
+/// Formats the verse of the day for MarkdownV2, which is appended to the daily Bible reading.
+fn format_verse(lang: &Language, verse: &VerseOfTheDay, bible_version: Option<&str>) -> String {
+    format!("\n\n*{}*\n{}\n— {}", lang.localizer().verse_of_the_day(), escape(&verse.text), format_reference(&verse.reference, bible_version))
+}
+
+/// The texts of the poll which is sent after the daily reminder
+#[derive(Clone, Debug, PartialEq)]
+pub struct PollText {
+    pub question: String,
+    pub yes: String,
+    pub no: String,
+}
+
+/// Formats the Bible reading of a day for MarkdownV2. The header is the custom prefix of the user if it is set,
+/// otherwise the reminder template of the operator or the built-in header of the language.
+pub fn msg_biblereading(lang: &Language, biblereading: BibleReading, bible_version: Option<&str>, verse: Option<&VerseOfTheDay>, custom_prefix: Option<&str>) -> String {
+    // The date of the reading is shown above the header, e.g. "Monday, 1 September"
+    let date = escape(&msg_date(lang, &display_date(biblereading.date, Local::now().year())));
+    let mut message = match (custom_prefix, template::reminder_template(lang)) {
+        (Some(custom_prefix), _) => format!(
+            "{}\n*{}*\n\n{}",
+            date,
+            escape(custom_prefix),
+            lang.localizer().reading_lines(&biblereading, bible_version)
+        ),
+        // The template of the operator replaces the built-in header and contains the date itself
+        (None, Some(reminder_template)) => template::render_reminder_template(
+            reminder_template,
+            &format_reading(&biblereading.old_testament_reading(), bible_version),
+            &format_reading(&biblereading.new_testament_reading(), bible_version),
+            &date
+        ),
+        (None, None) => format!("{}\n{}", date, lang.localizer().biblereading(&biblereading, bible_version)),
+    };
+    message.push_str(&format_psalm_and_wisdom(lang, &biblereading, bible_version));
+
+    if let Some(verse) = verse {
+        message.push_str(&format_verse(lang, verse, bible_version));
     }
+    message
+}
+
+/// Formats the Bible reading of a day as plain text for chats which do not use MarkdownV2 (see `msg_biblereading`).
+pub fn msg_biblereading_plain(lang: &Language, biblereading: BibleReading, bible_version: Option<&str>, verse: Option<&VerseOfTheDay>, custom_prefix: Option<&str>) -> String {
+    markdown_to_plain(&msg_biblereading(lang, biblereading, bible_version, verse, custom_prefix))
+}
+
+/// Converts a message for MarkdownV2 into plain text. The escaping and the formatting characters are removed
+/// and links are written as `text (url)`, so the plain text contains the same information.
+fn markdown_to_plain(markdown: &str) -> String {
+    let mut plain = String::new();
+    let mut link_text: Option<String> = None;
+    let mut characters = markdown.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        let character = match character {
+            '\\' => match characters.next() {
+                Some(escaped_character) => escaped_character,
+                None => continue,
+            },
+            '*' | '_' | '~' | '`' => continue,
+            '[' if link_text.is_none() => {
+                link_text = Some(String::new());
+                continue;
+            },
+            ']' if link_text.is_some() => {
+                let text = link_text.take().unwrap_or_default();
+                if characters.next_if_eq(&'(').is_none() {
+                    plain.push_str(&text);
+                    continue;
+                }
+
+                // Inside the url only `)` and `\` are escaped
+                let mut url = String::new();
+                while let Some(url_character) = characters.next() {
+                    match url_character {
+                        '\\' => url.extend(characters.next()),
+                        ')' => break,
+                        _ => url.push(url_character),
+                    }
+                }
+                plain.push_str(&format!("{} ({})", text, url));
+                continue;
+            },
+            _ => character,
+        };
+
+        match link_text.as_mut() {
+            Some(text) => text.push(character),
+            None => plain.push(character),
+        }
+    }
+    plain
+}
+
+pub fn msg_next_biblereading(lang: &Language, biblereading: BibleReading, bible_version: Option<&str>) -> String {
+    let mut message = lang.localizer().next_biblereading(&biblereading, bible_version);
+    message.push_str(&format_psalm_and_wisdom(lang, &biblereading, bible_version));
+    message
+}
+
+pub fn msg_next_biblereading_not_found(lang: &Language) -> String {
+    lang.localizer().next_biblereading_not_found()
+}
+
+pub fn msg_biblereading_not_found(lang: &Language) -> String {
+    lang.localizer().biblereading_not_found()
+}
+
+pub fn msg_button_mark_read(lang: &Language) -> String {
+    lang.localizer().button_mark_read()
+}
+
+pub fn msg_button_snooze(lang: &Language) -> String {
+    lang.localizer().button_snooze()
+}
+
+pub fn msg_marked_as_read(lang: &Language, streak: u32) -> String {
+    lang.localizer().marked_as_read(streak)
+}
+
+pub fn msg_poll_stale(lang: &Language) -> String {
+    lang.localizer().poll_stale()
+}
+
+pub fn msg_snoozed(lang: &Language, time: &NaiveTime) -> String {
+    lang.localizer().snoozed(time)
+}
+
+pub fn msg_language_set(lang: &Language) -> String {
+    lang.localizer().language_set()
+}
+
+pub fn msg_poll(lang: &Language) -> PollText {
+    lang.localizer().poll()
 }
 
 #[allow(dead_code)]
 pub fn msg_not_implemented_yet(lang: &Language) -> String {
-    match lang {
-        Language::English => "This feature has not been implemented yet.".to_string(),
-        Language::German => "Diese Funktion wurde noch nicht implementiert.".to_string()
-    }
+    lang.localizer().not_implemented_yet()
 }
 
 pub fn msg_select_language(lang: &Language) -> String {
-    match lang {
-        Language::English => String::from("Please choose which language you would like to set."),
-        Language::German => String::from("Bitte wählen Sie die Sprache aus, die sie einstellen möchten.")
-    }
+    lang.localizer().select_language()
 }
 
 pub fn msg_timer_updated(lang: &Language, time: &NaiveTime) -> String {
-    match lang {
-        Language::English => format!("The daily timer has been updated to {}.", time.to_string()),
-        Language::German => format!("Die tägliche Erinnerung wurde auf {} gesetzt.", time.to_string())
-    }
+    lang.localizer().timer_updated(time)
 }
 
 pub fn msg_timer_unset(lang: &Language) -> String {
-    match lang {
-        Language::English => format!("The daily timer has been unset"),
-        Language::German => format!("Die tägliche Erinnerung wurde deaktiviert"),
+    lang.localizer().timer_unset()
+}
+
+pub fn msg_error_timer_update(lang: &Language) -> String {
+    lang.localizer().error_timer_update()
+}
+
+pub fn msg_biblereading_not_found_for_date(lang: &Language, date_string: &str) -> String {
+    lang.localizer().biblereading_not_found_for_date(date_string)
+}
+
+pub fn msg_error_invalid_date(lang: &Language) -> String {
+    lang.localizer().error_invalid_date()
+}
+
+pub fn msg_data_deleted(lang: &Language) -> String {
+    lang.localizer().data_deleted()
+}
+
+pub fn msg_no_data_to_delete(lang: &Language) -> String {
+    lang.localizer().no_data_to_delete()
+}
+
+pub fn msg_bible_version_set(lang: &Language, version: &str) -> String {
+    lang.localizer().bible_version_set(version)
+}
+
+pub fn msg_button_marked_read(lang: &Language) -> String {
+    lang.localizer().button_marked_read()
+}
+
+pub fn msg_read_button_enabled(lang: &Language) -> String {
+    lang.localizer().read_button_enabled()
+}
+
+pub fn msg_read_button_disabled(lang: &Language) -> String {
+    lang.localizer().read_button_disabled()
+}
+
+pub fn msg_error_read_button(lang: &Language) -> String {
+    lang.localizer().error_read_button()
+}
+
+pub fn msg_bible_version_unset(lang: &Language) -> String {
+    lang.localizer().bible_version_unset()
+}
+
+pub fn msg_error_bible_version(lang: &Language) -> String {
+    lang.localizer().error_bible_version()
+}
+
+pub fn msg_paused(lang: &Language) -> String {
+    lang.localizer().paused()
+}
+
+pub fn msg_resumed(lang: &Language) -> String {
+    lang.localizer().resumed()
+}
+
+pub fn msg_status(lang: &Language, timer: &Option<NaiveTime>, paused: bool) -> String {
+    lang.localizer().status(timer, paused)
+}
+
+pub fn msg_feedback_sent(lang: &Language) -> String {
+    lang.localizer().feedback_sent()
+}
+
+pub fn msg_feedback_unavailable(lang: &Language) -> String {
+    lang.localizer().feedback_unavailable()
+}
+
+pub fn msg_error_feedback_empty(lang: &Language) -> String {
+    lang.localizer().error_feedback_empty()
+}
+
+pub fn msg_group_enabled(lang: &Language) -> String {
+    lang.localizer().group_enabled()
+}
+
+pub fn msg_group_not_enabled(lang: &Language) -> String {
+    lang.localizer().group_not_enabled()
+}
+
+pub fn msg_enable_private_chat(lang: &Language) -> String {
+    lang.localizer().enable_private_chat()
+}
+
+pub fn msg_plan_started(lang: &Language) -> String {
+    lang.localizer().plan_started()
+}
+
+pub fn msg_plan_stopped(lang: &Language) -> String {
+    lang.localizer().plan_stopped()
+}
+
+/// Formats the readings of several days (usually the next seven days) as one line per day.
+/// Days without a reading get a placeholder line.
+/// Formats the readings of several days with one line per day. Days which do not fit into `max_length`
+/// (in UTF-16 code units, like the limit of Telegram) are left out and replaced by `…`.
+pub fn msg_week(lang: &Language, days: &[(NaiveDate, Option<BibleReading>)], max_length: usize) -> String {
+    let localizer = lang.localizer();
+    let mut message = localizer.week_heading();
+    for (date, biblereading) in days {
+        let date_string = date.format("%m-%d").to_string();
+        let line = match biblereading {
+            Some(biblereading) => localizer.week_day(&date_string, biblereading),
+            None => localizer.week_day_not_found(&date_string),
+        };
+
+        let length = message.encode_utf16().count();
+        if length + 1 + line.encode_utf16().count() > max_length {
+            if length + 2 <= max_length {
+                message.push_str("\n…");
+            }
+            break;
+        }
+        message.push('\n');
+        message.push_str(&line);
     }
+    message
 }
 
+pub fn msg_skipped_today(lang: &Language) -> String {
+    lang.localizer().skipped_today()
+}
 
-pub fn msg_error_timer_update(lang: &Language) -> String {
-    match lang {
-        Language::English => String::from("The format was not valid. Please use the function with a valid time (for example /settimer 08:00)."),
-        Language::German => String::from("Ungültiges Format. Bitte benutze die Funktion mit einer gültigen Zeitangabe, zum Beispiel /settimer 08:00.")
+pub fn msg_no_data_to_export(lang: &Language) -> String {
+    lang.localizer().no_data_to_export()
+}
+
+pub fn msg_data_imported(lang: &Language) -> String {
+    lang.localizer().data_imported()
+}
+
+pub fn msg_error_import_invalid(lang: &Language) -> String {
+    lang.localizer().error_import_invalid()
+}
+
+pub fn msg_error_import_no_document(lang: &Language) -> String {
+    lang.localizer().error_import_no_document()
+}
+
+pub fn msg_date(lang: &Language, date: &NaiveDate) -> String {
+    lang.localizer().date(date)
+}
+
+pub fn msg_error_poll(lang: &Language) -> String {
+    lang.localizer().error_poll()
+}
+
+pub fn msg_anonymous_poll_enabled(lang: &Language) -> String {
+    lang.localizer().anonymous_poll_enabled()
+}
+
+pub fn msg_anonymous_poll_disabled(lang: &Language) -> String {
+    lang.localizer().anonymous_poll_disabled()
+}
+
+pub fn msg_error_anonymous_poll(lang: &Language) -> String {
+    lang.localizer().error_anonymous_poll()
+}
+
+/// Formats the days from `days - 1` days ago until today as a calendar with one row per week,
+/// where ✅ marks a day on which the reading has been marked as read and ⬜ a day without.
+pub fn msg_history(lang: &Language, completed_dates: &[NaiveDate], today: NaiveDate, days: usize) -> String {
+    let marks: Vec<&str> = (0..days)
+        .rev()
+        .map(|days_ago| today - chrono::Duration::days(days_ago as i64))
+        .map(|date| if completed_dates.contains(&date) { "✅" } else { "⬜" })
+        .collect();
+    let read_days = marks.iter().filter(|mark| **mark == "✅").count();
+
+    let mut lines = vec![lang.localizer().history_heading(read_days, days)];
+    lines.extend(marks.chunks(7).map(|week| week.concat()));
+    lines.join("\n")
+}
+
+/// Formats the text of a passage for MarkdownV2 with the (linked) reference as bold heading.
+/// Long passages are truncated, so that the message stays below the length limit of Telegram.
+pub fn msg_verse(reference: &str, text: &str, bible_version: Option<&str>) -> String {
+    format!("*{}*\n{}", format_reference(reference, bible_version), escape_truncated(text, MAX_VERSE_TEXT_LENGTH))
+}
+
+/// Formats the note for MarkdownV2 that the text of a passage is not available, followed by the reference
+/// (which is a link if the user has set up a Bible version).
+pub fn msg_verse_fallback(lang: &Language, reference: &str, bible_version: Option<&str>) -> String {
+    format!("{}\n{}", escape(&lang.localizer().verse_text_unavailable()), format_reference(reference, bible_version))
+}
+
+pub fn msg_error_verse_not_found(lang: &Language, reference: &str) -> String {
+    lang.localizer().error_verse_not_found(reference)
+}
+
+pub fn msg_error_verse_reference(lang: &Language) -> String {
+    lang.localizer().error_verse_reference()
+}
+
+pub fn msg_error_snooze_until(lang: &Language) -> String {
+    lang.localizer().error_snooze_until()
+}
+
+pub fn msg_custom_prefix_set(lang: &Language) -> String {
+    lang.localizer().custom_prefix_set()
+}
+
+pub fn msg_custom_prefix_unset(lang: &Language) -> String {
+    lang.localizer().custom_prefix_unset()
+}
+
+pub fn msg_error_custom_prefix(lang: &Language, max_length: usize) -> String {
+    lang.localizer().error_custom_prefix(max_length)
+}
+
+pub fn msg_markdown_enabled(lang: &Language) -> String {
+    lang.localizer().markdown_enabled()
+}
+
+pub fn msg_markdown_disabled(lang: &Language) -> String {
+    lang.localizer().markdown_disabled()
+}
+
+pub fn msg_error_markdown(lang: &Language) -> String {
+    lang.localizer().error_markdown()
+}
+
+pub fn msg_weekly_summary(lang: &Language, read_days: usize) -> String {
+    lang.localizer().weekly_summary(read_days)
+}
+
+pub fn msg_weekly_summary_enabled(lang: &Language) -> String {
+    lang.localizer().weekly_summary_enabled()
+}
+
+pub fn msg_weekly_summary_disabled(lang: &Language) -> String {
+    lang.localizer().weekly_summary_disabled()
+}
+
+pub fn msg_error_weekly_summary(lang: &Language) -> String {
+    lang.localizer().error_weekly_summary()
+}
+
+pub fn msg_confirm_delete_data(lang: &Language) -> String {
+    lang.localizer().confirm_delete_data()
+}
+
+pub fn msg_button_delete_confirm(lang: &Language) -> String {
+    lang.localizer().button_delete_confirm()
+}
+
+pub fn msg_button_delete_cancel(lang: &Language) -> String {
+    lang.localizer().button_delete_cancel()
+}
+
+pub fn msg_deletion_cancelled(lang: &Language) -> String {
+    lang.localizer().deletion_cancelled()
+}
+
+pub fn msg_deletion_expired(lang: &Language) -> String {
+    lang.localizer().deletion_expired()
+}
+
+/// Describes when the next reminder is sent, relative to today (today, tomorrow or on a later date).
+pub fn msg_next_reminder(lang: &Language, next_reminder: &NaiveDateTime, today: &NaiveDate) -> String {
+    let localizer = lang.localizer();
+    let time = next_reminder.time();
+    if next_reminder.date() == *today {
+        localizer.next_reminder_today(&time)
+    } else if today.succ_opt() == Some(next_reminder.date()) {
+        localizer.next_reminder_tomorrow(&time)
+    } else {
+        localizer.next_reminder_on(&localizer.date(&next_reminder.date()), &time)
     }
-}
\ No newline at end of file
+}
+
+pub fn msg_next_reminder_no_timer(lang: &Language) -> String {
+    lang.localizer().next_reminder_no_timer()
+}
+
+pub fn msg_next_reminder_paused(lang: &Language) -> String {
+    lang.localizer().next_reminder_paused()
+}
+
+pub fn msg_user_information_header(lang: &Language) -> String {
+    lang.localizer().user_information_header()
+}
+
+pub fn msg_no_data(lang: &Language) -> String {
+    lang.localizer().no_data()
+}
+
+/// Formats the data which is saved about the chat for `/userinformation` with MarkdownV2.
+/// The JSON is sent in a fenced code block, whose backticks stand on lines of their own.
+///
+/// # Params
+/// - `lang`: The language of the message
+/// - `description`: The description of the chat (plain text, which is escaped)
+/// - `user_state_json`: The saved data as JSON
+pub fn msg_user_information(lang: &Language, description: &str, user_state_json: &str) -> String {
+    format!(
+        "{}\n\n{}\n```json\n{}\n```",
+        escape(description),
+        escape(&msg_user_information_header(lang)),
+        // The data contains free text of the user (like the custom prefix), which must not end the code block
+        escape_code(user_state_json)
+    )
+}
+
+pub fn msg_random_reading(lang: &Language) -> String {
+    lang.localizer().random_reading()
+}
+
+pub fn msg_button_listen_old_testament(lang: &Language) -> String {
+    lang.localizer().button_listen_old_testament()
+}
+
+pub fn msg_button_listen_new_testament(lang: &Language) -> String {
+    lang.localizer().button_listen_new_testament()
+}
+
+/// Formats the information about the running build for `/about` with MarkdownV2.
+///
+/// # Params
+/// - `lang`: The language of the message
+/// - `version`: The version of the bot
+/// - `commit`: The git commit the bot has been built from
+/// - `repository`: The URL of the source code, which is linked in the last line
+pub fn msg_about(lang: &Language, version: &str, commit: &str, repository: &str) -> String {
+    let languages: Vec<&str> = Language::ALL.iter().map(Language::native_name).collect();
+    format!(
+        "{}\n[{}]({})",
+        escape(&lang.localizer().about(version, commit, &languages.join(", "))),
+        escape(repository),
+        escape_link_url(repository)
+    )
+}
+
+pub fn msg_available_plans(lang: &Language, plans: &str, current: &str) -> String {
+    lang.localizer().available_plans(plans, current)
+}
+
+pub fn msg_plan_set(lang: &Language, plan: &str) -> String {
+    lang.localizer().plan_set(plan)
+}
+
+pub fn msg_error_unknown_plan(lang: &Language, plan: &str) -> String {
+    lang.localizer().error_unknown_plan(plan)
+}
+
+pub fn msg_member_since(lang: &Language, date: &str, days: i64) -> String {
+    lang.localizer().member_since(date, days)
+}
+
+pub fn msg_member_since_unknown(lang: &Language) -> String {
+    lang.localizer().member_since_unknown()
+}
+
+pub fn msg_private_chat(lang: &Language) -> String {
+    lang.localizer().private_chat()
+}
+
+pub fn msg_group_chat_enabled(lang: &Language) -> String {
+    lang.localizer().group_chat_enabled()
+}
+
+pub fn msg_group_chat_not_enabled(lang: &Language) -> String {
+    lang.localizer().group_chat_not_enabled()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::biblereading::{get_biblereadings_for_week, AudioLinks};
+
+    /// The characters which have to be escaped in MarkdownV2 if they are not used for formatting
+    const MARKDOWN_V2_RESERVED: &str = "_*[]()~`>#+-=|{}.!";
+
+    /// Returns all reserved MarkdownV2 characters in the text which are not escaped with a backslash
+    fn unescaped_reserved_characters(text: &str) -> Vec<char> {
+        let mut unescaped = Vec::new();
+        let mut characters = text.chars();
+        while let Some(character) = characters.next() {
+            if character == '\\' {
+                characters.next();
+            } else if MARKDOWN_V2_RESERVED.contains(character) {
+                unescaped.push(character);
+            }
+        }
+        unescaped
+    }
+
+    #[test]
+    fn biblereading_is_valid_markdown_v2() {
+        let biblereading = BibleReading {
+            date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            old_testament_readings: vec![String::from("1. Mose 1-3 (Schöpfung)!")],
+            new_testament_readings: vec![String::from("Eph 5,1-16. [Teil 1]")],
+            psalm: None,
+            wisdom: None,
+            audio: AudioLinks::default(),
+        };
+
+        for lang in Language::ALL {
+            // Only the two asterisks of the bold header are allowed to be unescaped
+            let message = msg_biblereading(&lang, biblereading.clone(), None, None, None);
+            assert_eq!(unescaped_reserved_characters(&message), vec!['*', '*']);
+
+            let message = msg_next_biblereading(&lang, biblereading.clone(), None);
+            assert_eq!(unescaped_reserved_characters(&message), vec!['*', '*']);
+        }
+    }
+
+    #[test]
+    fn user_information_is_valid_markdown_v2() {
+        let user_state_json = "{\n  \"custom_prefix\": \"```Good morning!\\\\\"\n}";
+        for lang in Language::ALL {
+            let message = msg_user_information(&lang, "This is a private chat.\nMember since 2024-09-01 (3 days).", user_state_json);
+
+            let (text, code_block) = message.split_once("```json\n").unwrap();
+            assert!(text.ends_with('\n'));
+            assert!(text.contains(&escape(&msg_user_information_header(&lang))));
+            assert!(unescaped_reserved_characters(text).is_empty());
+
+            // The backticks and backslashes of the data are escaped, so that only the closing fence remains
+            let code = code_block.strip_suffix("\n```").unwrap();
+            assert!(!code.contains("```"));
+            assert_eq!(code.replace("\\`", "`").replace("\\\\", "\\"), user_state_json);
+        }
+    }
+
+    #[test]
+    fn biblereading_contains_links_for_version() {
+        let biblereading = BibleReading {
+            date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            old_testament_readings: vec![String::from("Psalm 135,136")],
+            new_testament_readings: vec![String::from("1Kor12")],
+            psalm: None,
+            wisdom: None,
+            audio: AudioLinks::default(),
+        };
+
+        let message = msg_biblereading(&Language::English, biblereading, Some("ESV"), None, None);
+        assert!(message.contains("\n• [Psalm 135](https://www.biblegateway.com/passage/?search=Psalm+135&version=ESV)"));
+        assert!(message.contains("\n• [Psalm 136](https://www.biblegateway.com/passage/?search=Psalm+136&version=ESV)"));
+        assert!(message.contains("[1Kor12](https://www.biblegateway.com/passage/?search=1Kor12&version=ESV)"));
+    }
+
+    #[test]
+    fn plain_reading_has_no_markdown() {
+        let biblereading = BibleReading {
+            date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            old_testament_readings: vec![String::from("1.Mose 1-3")],
+            new_testament_readings: vec![String::from("Psalm 135,136")],
+            psalm: None,
+            wisdom: None,
+            audio: AudioLinks::default(),
+        };
+
+        let message = msg_biblereading_plain(&Language::English, biblereading, Some("ESV"), None, None);
+        assert!(message.starts_with("Sunday, 1 September\n📖 This is a reminder to read the Bible today:\n\nOT: 1.Mose 1-3 (https://www.biblegateway.com/passage/?search=1.Mose+1-3&version=ESV)"));
+        assert!(message.contains("• Psalm 135 (https://www.biblegateway.com/passage/?search=Psalm+135&version=ESV)"));
+        assert!(!message.contains('\\') && !message.contains('*') && !message.contains('['));
+    }
+
+    #[test]
+    fn several_passages_are_listed() {
+        let biblereading = BibleReading {
+            date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            old_testament_readings: vec![String::from("Gen 1"), String::from("Ps 2"), String::from("Spr 3")],
+            new_testament_readings: vec![String::from("Mt 1"), String::from("2")],
+            psalm: None,
+            wisdom: None,
+            audio: AudioLinks::default(),
+        };
+
+        let message = msg_biblereading(&Language::English, biblereading, None, None, None);
+        assert!(message.ends_with("OT: \n• Gen 1\n• Ps 2\n• Spr 3\nNT: \n• Mt 1\n• Mt 2"));
+    }
+
+    #[test]
+    fn references_are_escaped_once() {
+        let biblereading = BibleReading {
+            date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            old_testament_readings: vec![String::from("1.Mose 1-3")],
+            new_testament_readings: vec![String::from("Psalm 135,136")],
+            psalm: None,
+            wisdom: None,
+            audio: AudioLinks::default(),
+        };
+
+        for lang in Language::ALL {
+            let message = msg_biblereading(&lang, biblereading.clone(), None, None, None);
+            assert_eq!(unescaped_reserved_characters(&message), vec!['*', '*']);
+            assert!(message.contains("1\\.Mose 1\\-3"));
+            assert!(!message.contains("\\\\"));
+
+            // Inside the link only the text is escaped, the url keeps its dots and hyphens
+            let message = msg_biblereading(&lang, biblereading.clone(), Some("ESV"), None, None);
+            assert!(message.contains("[1\\.Mose 1\\-3](https://www.biblegateway.com/passage/?search=1.Mose+1-3&version=ESV)"));
+            assert!(message.contains("[Psalm 135](https://www.biblegateway.com/passage/?search=Psalm+135&version=ESV)"));
+            assert!(!message.contains("\\\\"));
+        }
+    }
+
+    #[test]
+    fn verse_of_the_day_is_appended() {
+        let biblereading = BibleReading {
+            date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            old_testament_readings: vec![String::from("Psalm 135,136")],
+            new_testament_readings: vec![String::from("1Kor12")],
+            psalm: None,
+            wisdom: None,
+            audio: AudioLinks::default(),
+        };
+        let verse = VerseOfTheDay {
+            reference: String::from("Ps 118,24"),
+            text: String::from("This is the day that the LORD has made; let us rejoice and be glad in it."),
+        };
+
+        let message = msg_biblereading(&Language::German, biblereading.clone(), None, Some(&verse), None);
+        assert!(message.contains("*✨ Vers des Tages*"));
+        assert!(message.contains("let us rejoice and be glad in it\\."));
+        // The asterisks of both headings are the only unescaped characters
+        assert_eq!(unescaped_reserved_characters(&message), vec!['*', '*', '*', '*']);
+
+        // Without a verse the message is unchanged
+        assert!(!msg_biblereading(&Language::German, biblereading, None, None, None).contains("Vers des Tages"));
+    }
+
+    #[test]
+    fn psalm_and_wisdom_are_rendered() {
+        let biblereading = BibleReading {
+            date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            old_testament_readings: vec![String::from("Hiob 2")],
+            new_testament_readings: vec![String::from("1Kor 12")],
+            psalm: Some(String::from("Psalm 1")),
+            wisdom: Some(String::from("Spr 1")),
+            audio: AudioLinks::default(),
+        };
+
+        let message = msg_biblereading(&Language::German, biblereading.clone(), None, None, None);
+        assert!(message.ends_with("\nPsalm: Psalm 1\nWeisheit: Spr 1"));
+        assert_eq!(unescaped_reserved_characters(&message), vec!['*', '*']);
+
+        // Without the extra columns the message is unchanged
+        let biblereading = BibleReading { psalm: None, wisdom: None, ..biblereading };
+        assert!(msg_biblereading(&Language::German, biblereading, None, None, None).ends_with("NT: 1Kor 12"));
+    }
+
+    #[test]
+    fn date_is_localized() {
+        let date = NaiveDate::from_ymd_opt(2025, 9, 1).unwrap();
+        assert_eq!(msg_date(&Language::English, &date), "Monday, 1 September");
+        assert_eq!(msg_date(&Language::German, &date), "Montag, 1. September");
+        assert_eq!(msg_date(&Language::Spanish, &date), "lunes, 1 de septiembre");
+        assert_eq!(msg_date(&Language::Portuguese, &date), "segunda-feira, 1 de setembro");
+        assert_eq!(msg_date(&Language::Italian, &date), "lunedì 1 settembre");
+
+        let date = NaiveDate::from_ymd_opt(2024, 3, 17).unwrap();
+        assert_eq!(msg_date(&Language::English, &date), "Sunday, 17 March");
+        assert_eq!(msg_date(&Language::German, &date), "Sonntag, 17. März");
+        assert_eq!(msg_date(&Language::Spanish, &date), "domingo, 17 de marzo");
+        assert_eq!(msg_date(&Language::Portuguese, &date), "domingo, 17 de março");
+        assert_eq!(msg_date(&Language::Italian, &date), "domenica 17 marzo");
+    }
+
+    #[test]
+    fn reminder_starts_with_date() {
+        let biblereading = BibleReading {
+            date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            old_testament_readings: vec![String::from("Hiob 2")],
+            new_testament_readings: vec![String::from("1Kor 12")],
+            psalm: None,
+            wisdom: None,
+            audio: AudioLinks::default(),
+        };
+
+        let message = msg_biblereading(&Language::German, biblereading, None, None, None);
+        assert!(message.starts_with("Sonntag, 1\\. September\n*📖"));
+    }
+
+    #[test]
+    fn custom_prefix_replaces_header() {
+        let biblereading = BibleReading {
+            date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            old_testament_readings: vec![String::from("Hiob 2")],
+            new_testament_readings: vec![String::from("1Kor 12")],
+            psalm: None,
+            wisdom: None,
+            audio: AudioLinks::default(),
+        };
+
+        let message = msg_biblereading(&Language::German, biblereading, None, None, Some("Guten Morgen! Zeit zum Lesen:"));
+        assert_eq!(message, "Sonntag, 1\\. September\n*Guten Morgen\\! Zeit zum Lesen:*\n\nAT: Hiob 2\nNT: 1Kor 12");
+    }
+
+    #[test]
+    fn dates_without_year_are_shown_in_current_year() {
+        let date = NaiveDate::from_ymd_opt(LEAP_YEAR, 9, 1).unwrap();
+        assert_eq!(display_date(date, 2025), NaiveDate::from_ymd_opt(2025, 9, 1).unwrap());
+
+        // Dates with a real year are not changed
+        let date = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        assert_eq!(display_date(date, 2025), date);
+
+        // Feb 29 does not exist in 2025, so the date is kept
+        let date = NaiveDate::from_ymd_opt(LEAP_YEAR, 2, 29).unwrap();
+        assert_eq!(display_date(date, 2025), date);
+    }
+
+    #[test]
+    fn history_marks_completed_days() {
+        let today = NaiveDate::from_ymd_opt(2024, 9, 30).unwrap();
+        let completed_dates = vec![
+            NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 9, 2).unwrap(),
+            today,
+        ];
+
+        let message = msg_history(&Language::English, &completed_dates, today, 30);
+        let lines: Vec<&str> = message.lines().collect();
+        // The date outside of the last 30 days is not counted
+        assert_eq!(lines[0], "📊 Your readings of the last 30 days (3 of 30 read):");
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[1], "✅✅⬜⬜⬜⬜⬜");
+        assert_eq!(lines[5], "⬜✅");
+        assert_eq!(message.matches('✅').count(), 3);
+    }
+
+    #[test]
+    fn verse_is_escaped() {
+        let message = msg_verse("John 3,16", "For God so loved the world...", Some("ESV"));
+        assert_eq!(message, "*[John 3,16](https://www.biblegateway.com/passage/?search=John+3%2C16&version=ESV)*\nFor God so loved the world\\.\\.\\.");
+
+        let message = msg_verse_fallback(&Language::German, "Joh 3,16", None);
+        assert!(message.ends_with("\nJoh 3,16"));
+        assert_eq!(unescaped_reserved_characters(&message), Vec::<char>::new());
+    }
+
+    #[test]
+    fn about_is_valid_markdown_v2() {
+        for lang in Language::ALL {
+            let message = msg_about(&lang, "0.1.0", "1a2b3c4-dirty", "https://github.com/reckel-jm/dailybible-rs");
+            let (text, link) = message.rsplit_once('\n').unwrap();
+            assert_eq!(unescaped_reserved_characters(text), Vec::<char>::new());
+            assert!(text.contains("0\\.1\\.0") && text.contains("1a2b3c4\\-dirty"));
+            assert!(text.contains("Português"));
+            assert_eq!(link, "[https://github\\.com/reckel\\-jm/dailybible\\-rs](https://github.com/reckel-jm/dailybible-rs)");
+        }
+    }
+
+    #[test]
+    fn language_serde_round_trip() {
+        for lang in Language::ALL {
+            let json_string = serde_json::to_string(&lang).unwrap();
+            assert_eq!(serde_json::from_str::<Language>(&json_string).unwrap(), lang);
+        }
+        assert_eq!(serde_json::to_string(&Language::Spanish).unwrap(), "\"Spanish\"");
+        assert_eq!(serde_json::to_string(&Language::Portuguese).unwrap(), "\"Portuguese\"");
+        assert_eq!(serde_json::to_string(&Language::Italian).unwrap(), "\"Italian\"");
+        // The state files written before the new languages existed still contain the old names
+        assert_eq!(serde_json::from_str::<Language>("\"German\"").unwrap(), Language::German);
+    }
+
+    #[test]
+    fn user_text_is_escaped_and_truncated() {
+        assert_eq!(escape_truncated("Thanks (a lot)!", 100), "Thanks \\(a lot\\)\\!");
+
+        let truncated = escape_truncated(&"a.".repeat(100), 10);
+        assert_eq!(truncated, "a\\.a\\.a\\.…");
+        assert!(truncated.encode_utf16().count() <= 10);
+    }
+
+    #[test]
+    fn week_has_one_line_per_day() {
+        let days = get_biblereadings_for_week("", NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(), None);
+        let message = msg_week(&Language::English, &days, 4096);
+        assert_eq!(message.lines().count(), 8);
+        assert!(message.contains("09-01: OT Psalm 135,136 | NT 1Kor12"));
+        assert!(message.contains("09-07: OT Spr 1,2 | NT 1Kor 16"));
+
+        // Days without a reading get a placeholder instead of aborting the week
+        let days = get_biblereadings_for_week("", NaiveDate::from_ymd_opt(2012, 7, 3).unwrap(), None);
+        let message = msg_week(&Language::German, &days, 4096);
+        assert_eq!(message.lines().count(), 8);
+        assert!(message.contains("07-09: keine Bibellese eingeplant"));
+    }
+
+    #[test]
+    fn week_is_limited_to_max_length() {
+        let biblereading = BibleReading {
+            date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            old_testament_readings: vec!["Gen 1".repeat(100)],
+            new_testament_readings: vec![String::from("Mt 1")],
+            psalm: None,
+            wisdom: None,
+            audio: AudioLinks::default(),
+        };
+        let days: Vec<(NaiveDate, Option<BibleReading>)> = (1..=7)
+            .map(|day| (NaiveDate::from_ymd_opt(2024, 9, day).unwrap(), Some(biblereading.clone())))
+            .collect();
+
+        let message = msg_week(&Language::English, &days, 1200);
+        assert!(message.encode_utf16().count() <= 1200);
+        assert!(message.ends_with("\n…"));
+        assert!(message.contains("09-02:"));
+        assert!(!message.contains("09-07:"));
+    }
+}
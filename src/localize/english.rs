@@ -0,0 +1,414 @@
+//! In this unit, the English messages of the bot are implemented.
+
+use chrono::{Datelike, NaiveDate, NaiveTime};
+
+use crate::biblereading::BibleReading;
+
+use super::{format_reading, Localizer, PollText};
+
+/// The names of the weekdays, starting with Monday
+const WEEKDAYS: [&str; 7] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+
+/// The names of the months, starting with January
+const MONTHS: [&str; 12] = ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"];
+
+pub struct English;
+
+impl Localizer for English {
+    fn biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "*📖 This is a reminder to read the Bible today*:\n\nOT: {}\nNT: {}", 
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn verse_of_the_day(&self) -> String {
+        String::from("✨ Verse of the day")
+    }
+
+    fn next_biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "*📖 Tomorrow's reading*:\n\nOT: {}\nNT: {}", 
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn next_biblereading_not_found(&self) -> String {
+        "There is no Bible reading scheduled for tomorrow.".to_string()
+    }
+
+    fn biblereading_not_found(&self) -> String {
+        "This is a reminder to read your bible!".to_string()
+    }
+
+    fn button_mark_read(&self) -> String {
+        String::from("✅ Mark as read")
+    }
+
+    fn button_snooze(&self) -> String {
+        String::from("⏰ Remind me later")
+    }
+
+    fn marked_as_read(&self, streak: u32) -> String {
+        format!("Well done! You have read the Bible {} day(s) in a row.", streak)
+    }
+
+    fn poll_stale(&self) -> String {
+        String::from("This poll belongs to an earlier reminder, so your answer is not counted. Please answer the poll of today's reminder.")
+    }
+
+    fn snoozed(&self, time: &NaiveTime) -> String {
+        format!("I will remind you again at {}.", time.format("%H:%M"))
+    }
+
+    fn language_set(&self) -> String {
+        "Language set to English.".to_string()
+    }
+
+    fn poll(&self) -> PollText {
+        PollText {
+            question: String::from("Have you read the Bible today?"),
+            yes: String::from("Yes"),
+            no: String::from("No"),
+        }
+    }
+
+    fn not_implemented_yet(&self) -> String {
+        "This feature has not been implemented yet.".to_string()
+    }
+
+    fn select_language(&self) -> String {
+        String::from("Please choose which language you would like to set.")
+    }
+
+    fn timer_updated(&self, time: &NaiveTime) -> String {
+        format!("The daily timer has been updated to {}.", time.format("%H:%M"))
+    }
+
+    fn timer_unset(&self) -> String {
+        String::from("The daily timer has been unset")
+    }
+
+    fn error_timer_update(&self) -> String {
+        String::from("The format was not valid. Please use the function with a valid time (for example /settimer 08:00).")
+    }
+
+    fn biblereading_not_found_for_date(&self, date_string: &str) -> String {
+        format!("There is no Bible reading scheduled for {}.", date_string)
+    }
+
+    fn error_invalid_date(&self) -> String {
+        String::from("The date was not valid. Please use the function with a valid date (for example /readingfor 09-01).")
+    }
+
+    fn data_deleted(&self) -> String {
+        String::from("All data about you has been deleted.")
+    }
+
+    fn no_data_to_delete(&self) -> String {
+        String::from("There is no data saved about you, so nothing has been deleted.")
+    }
+
+    fn bible_version_set(&self, version: &str) -> String {
+        format!("The readings will be linked to the Bible version {}.", version)
+    }
+
+    fn button_marked_read(&self) -> String {
+        String::from("✅ Read")
+    }
+
+    fn read_button_enabled(&self) -> String {
+        String::from("The daily reminder is now confirmed with the \"Mark as read\" button instead of a poll.")
+    }
+
+    fn read_button_disabled(&self) -> String {
+        String::from("The daily reminder is followed by a poll again.")
+    }
+
+    fn error_read_button(&self) -> String {
+        String::from("Please use /readbutton on or /readbutton off.")
+    }
+
+    fn bible_version_unset(&self) -> String {
+        String::from("The links to the readings have been disabled.")
+    }
+
+    fn error_bible_version(&self) -> String {
+        String::from("The Bible version was not valid. Please use its abbreviation, for example /setversion ESV.")
+    }
+
+    fn paused(&self) -> String {
+        String::from("The daily reminders are paused. Your timer is kept, use /resume to continue.")
+    }
+
+    fn resumed(&self) -> String {
+        String::from("The daily reminders are active again.")
+    }
+
+    fn status(&self, timer: &Option<NaiveTime>, paused: bool) -> String {
+        let timer_string = match timer {
+            Some(time) => format!("The daily timer is set to {}.", time.format("%H:%M")),
+            None => String::from("No daily timer is set."),
+        };
+        let paused_string = if paused { "The reminders are paused." } else { "The reminders are active." };
+        format!("{}\n{}", timer_string, paused_string)
+    }
+
+    fn feedback_sent(&self) -> String {
+        String::from("Thank you for your feedback! It has been forwarded to the operator of the bot.")
+    }
+
+    fn feedback_unavailable(&self) -> String {
+        String::from("Sorry, feedback is not available for this bot.")
+    }
+
+    fn error_feedback_empty(&self) -> String {
+        String::from("Please write your feedback after the command, for example /feedback Thank you for this bot!")
+    }
+
+    fn group_enabled(&self) -> String {
+        String::from("The daily reminders are now enabled for this group.")
+    }
+
+    fn group_not_enabled(&self) -> String {
+        String::from("In groups the daily reminders are only sent after they have been enabled with /enable.")
+    }
+
+    fn enable_private_chat(&self) -> String {
+        String::from("In private chats the daily reminders are always enabled.")
+    }
+
+    fn plan_started(&self) -> String {
+        String::from("Your personal reading plan starts today. From now on you receive the readings of the schedule one after another, use /startplan off to follow the calendar again.")
+    }
+
+    fn plan_stopped(&self) -> String {
+        String::from("The readings follow the calendar again.")
+    }
+
+    fn week_heading(&self) -> String {
+        String::from("📅 The readings of the next seven days:")
+    }
+
+    fn week_day(&self, date_string: &str, biblereading: &BibleReading) -> String {
+        format!("{}: OT {} | NT {}", date_string, biblereading.old_testament_reading(), biblereading.new_testament_reading())
+    }
+
+    fn week_day_not_found(&self, date_string: &str) -> String {
+        format!("{}: no reading scheduled", date_string)
+    }
+
+    fn skipped_today(&self) -> String {
+        String::from("You will not receive a reminder today. Tomorrow the reminders continue as usual.")
+    }
+
+    fn psalm_label(&self) -> String {
+        String::from("Psalm")
+    }
+
+    fn wisdom_label(&self) -> String {
+        String::from("Wisdom")
+    }
+
+    fn no_data_to_export(&self) -> String {
+        String::from("There is no data saved about you, so there is nothing to export.")
+    }
+
+    fn data_imported(&self) -> String {
+        String::from("Your data has been imported.")
+    }
+
+    fn error_import_invalid(&self) -> String {
+        String::from("The file could not be imported. Please send a JSON file which has been created with /export.")
+    }
+
+    fn error_import_no_document(&self) -> String {
+        String::from("Please reply with /importdata to the JSON file which has been created with /export, or send the file with /importdata as caption.")
+    }
+
+    fn date(&self, date: &NaiveDate) -> String {
+        format!("{}, {} {}", WEEKDAYS[date.weekday().num_days_from_monday() as usize], date.day(), MONTHS[date.month0() as usize])
+    }
+
+    fn error_poll(&self) -> String {
+        String::from("Please use /poll on or /poll off.")
+    }
+
+    fn anonymous_poll_enabled(&self) -> String {
+        String::from("The poll after the daily reminder is now anonymous. Anonymous answers can not be counted for your streak.")
+    }
+
+    fn anonymous_poll_disabled(&self) -> String {
+        String::from("The poll after the daily reminder is no longer anonymous (polls in groups are always anonymous).")
+    }
+
+    fn error_anonymous_poll(&self) -> String {
+        String::from("Please use /pollanon on or /pollanon off.")
+    }
+
+    fn history_heading(&self, read_days: usize, days: usize) -> String {
+        format!("📊 Your readings of the last {} days ({} of {} read):", days, read_days, days)
+    }
+
+    fn verse_text_unavailable(&self) -> String {
+        String::from("The text of the passage could not be loaded, but you can read it here:")
+    }
+
+    fn error_verse_not_found(&self, reference: &str) -> String {
+        format!("The passage {} could not be found. Please check the reference.", reference)
+    }
+
+    fn error_verse_reference(&self) -> String {
+        String::from("Please add a Bible reference, for example /verse John 3,16.")
+    }
+
+    fn error_snooze_until(&self) -> String {
+        String::from("Please use the function with a later time of today (for example /snoozeuntil 20:00).")
+    }
+
+    fn reading_lines(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "OT: {}\nNT: {}",
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn custom_prefix_set(&self) -> String {
+        String::from("Your reminders now start with your own message. Use /setmessage without text to restore the default.")
+    }
+
+    fn custom_prefix_unset(&self) -> String {
+        String::from("Your reminders start with the default message again.")
+    }
+
+    fn error_custom_prefix(&self, max_length: usize) -> String {
+        format!("Your message is too long. Please use at most {} characters, for example /setmessage Good morning! Time to read:", max_length)
+    }
+
+    fn markdown_enabled(&self) -> String {
+        String::from("Your reminders are formatted again (bold text and links).")
+    }
+
+    fn markdown_disabled(&self) -> String {
+        String::from("Your reminders are now sent as plain text without formatting.")
+    }
+
+    fn error_markdown(&self) -> String {
+        String::from("Please use /markdown on or /markdown off.")
+    }
+
+    fn weekly_summary(&self, read_days: usize) -> String {
+        format!("📅 Your week: you have read the Bible on {} of 7 days. Keep it up!", read_days)
+    }
+
+    fn weekly_summary_enabled(&self) -> String {
+        String::from("Every Sunday you will receive a summary of your week together with the reminder.")
+    }
+
+    fn weekly_summary_disabled(&self) -> String {
+        String::from("You will no longer receive a weekly summary.")
+    }
+
+    fn error_weekly_summary(&self) -> String {
+        String::from("Please use /weeklysummary on or /weeklysummary off.")
+    }
+
+    fn confirm_delete_data(&self) -> String {
+        String::from("Do you really want to delete all data which is saved about this chat? This can not be undone.")
+    }
+
+    fn button_delete_confirm(&self) -> String {
+        String::from("🗑 Yes, delete")
+    }
+
+    fn button_delete_cancel(&self) -> String {
+        String::from("Cancel")
+    }
+
+    fn deletion_cancelled(&self) -> String {
+        String::from("Your data has not been deleted.")
+    }
+
+    fn deletion_expired(&self) -> String {
+        String::from("The confirmation has expired, your data has not been deleted. Please use /deletemydata again.")
+    }
+
+    fn next_reminder_today(&self, time: &NaiveTime) -> String {
+        format!("Your next reminder is today at {}.", time.format("%H:%M"))
+    }
+
+    fn next_reminder_tomorrow(&self, time: &NaiveTime) -> String {
+        format!("Your next reminder is tomorrow at {}.", time.format("%H:%M"))
+    }
+
+    fn next_reminder_on(&self, date: &str, time: &NaiveTime) -> String {
+        format!("Your next reminder is on {} at {}.", date, time.format("%H:%M"))
+    }
+
+    fn next_reminder_no_timer(&self) -> String {
+        String::from("No daily timer is set, so you will not receive reminders. Use /settimer to set one.")
+    }
+
+    fn next_reminder_paused(&self) -> String {
+        String::from("Your reminders are paused. Use /resume to receive them again.")
+    }
+
+    fn user_information_header(&self) -> String {
+        String::from("The following data about you is saved on the server:")
+    }
+
+    fn no_data(&self) -> String {
+        String::from("There is currently no data saved on the server concerning you.")
+    }
+
+    fn random_reading(&self) -> String {
+        String::from("Here's a random passage:")
+    }
+
+    fn button_listen_old_testament(&self) -> String {
+        String::from("🔊 Listen (OT)")
+    }
+
+    fn button_listen_new_testament(&self) -> String {
+        String::from("🔊 Listen (NT)")
+    }
+
+    fn about(&self, version: &str, commit: &str, languages: &str) -> String {
+        format!("DailyBible {} (commit {})\nLanguages: {}\nSource code:", version, commit, languages)
+    }
+
+    fn available_plans(&self, plans: &str, current: &str) -> String {
+        format!("The following reading plans are available: {}\nYour current plan is {}. To change it, use /setplan followed by the name of the plan.", plans, current)
+    }
+
+    fn plan_set(&self, plan: &str) -> String {
+        format!("Your readings are now taken from the reading plan {}.", plan)
+    }
+
+    fn error_unknown_plan(&self, plan: &str) -> String {
+        format!("There is no reading plan named {}.", plan)
+    }
+
+    fn member_since(&self, date: &str, days: i64) -> String {
+        format!("Member since {} ({} days).", date, days)
+    }
+
+    fn member_since_unknown(&self) -> String {
+        String::from("Member since an unknown date (registered before this was recorded).")
+    }
+
+    fn private_chat(&self) -> String {
+        String::from("This is a private chat.")
+    }
+
+    fn group_chat_enabled(&self) -> String {
+        String::from("This is a group chat, the reminders are enabled.")
+    }
+
+    fn group_chat_not_enabled(&self) -> String {
+        String::from("This is a group chat, the reminders are not enabled yet (use /enable).")
+    }
+}
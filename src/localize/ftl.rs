@@ -0,0 +1,658 @@
+//! In this unit, the messages of the bot are loaded from the Fluent files in the locales directory,
+//! so the wording can be changed and translated without recompiling the bot.
+//! If the files cannot be loaded, the built-in messages of each language are used instead.
+
+use std::{env, error::Error, fs, path::Path, sync::OnceLock};
+
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use fluent::{concurrent::FluentBundle, FluentArgs, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+use crate::biblereading::BibleReading;
+
+use super::{format_reading, Language, Localizer, PollText};
+
+/// The default directory which contains the `.ftl` files of all languages
+const DEFAULT_LOCALES_DIRECTORY: &str = "locales";
+
+/// The name of the environment variable where the directory of the `.ftl` files can be specified.
+/// If it is not set, `DEFAULT_LOCALES_DIRECTORY` is used.
+pub const LOCALES_DIR_ENV: &str = "DAILYBIBLE_LOCALES_DIR";
+
+/// The error type which is returned if a `.ftl` file cannot be loaded
+pub type LocaleError = Box<dyn Error + Send + Sync>;
+
+/// The localizers of all languages whose `.ftl` file has been loaded at startup
+static FLUENT_LOCALIZERS: OnceLock<Vec<FluentLocalizer>> = OnceLock::new();
+
+
+/// The messages of one language which are looked up in a Fluent bundle.
+/// Messages which are missing in the bundle are taken from the English bundle.
+pub struct FluentLocalizer {
+    language: Language,
+    bundle: FluentBundle<FluentResource>,
+    fallback: Option<FluentBundle<FluentResource>>,
+}
+
+impl FluentLocalizer {
+    /// Loads the `.ftl` file of the given language and of English as fallback from the given directory.
+    pub fn load(directory: &Path, language: Language) -> Result<Self, LocaleError> {
+        let source = read_ftl_file(directory, &language)?;
+        let fallback_source = match language {
+            Language::English => None,
+            _ => Some(read_ftl_file(directory, &Language::English)?),
+        };
+        FluentLocalizer::from_sources(language, source, fallback_source)
+    }
+
+    /// Creates the localizer from the content of the `.ftl` file of the language and the optional English fallback.
+    pub fn from_sources(language: Language, source: String, fallback_source: Option<String>) -> Result<Self, LocaleError> {
+        let bundle = create_bundle(&language, source)?;
+        let fallback = match fallback_source {
+            Some(fallback_source) => Some(create_bundle(&Language::English, fallback_source)?),
+            None => None,
+        };
+        Ok(FluentLocalizer { language, bundle, fallback })
+    }
+
+    /// Looks up the message with the given id and interpolates the arguments.
+    /// If the message is missing, the English message is used and a warning is logged.
+    fn message(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        if let Some(message) = format_message(&self.bundle, id, args) {
+            return message;
+        }
+
+        log::warn!("The message {} is missing for {:?}, falling back to English.", id, self.language);
+        match self.fallback.as_ref().and_then(|fallback| format_message(fallback, id, args)) {
+            Some(message) => message,
+            None => {
+                log::error!("The message {} is missing in the English locale.", id);
+                id.to_string()
+            }
+        }
+    }
+
+    fn reading_message(&self, id: &str, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        let mut args = FluentArgs::new();
+        args.set("old_testament", format_reading(&biblereading.old_testament_reading(), bible_version));
+        args.set("new_testament", format_reading(&biblereading.new_testament_reading(), bible_version));
+        self.message(id, Some(&args))
+    }
+
+    fn time_message(&self, id: &str, time: &NaiveTime) -> String {
+        let mut args = FluentArgs::new();
+        args.set("time", time.format("%H:%M").to_string());
+        self.message(id, Some(&args))
+    }
+}
+
+/// Reads the `.ftl` file of the language from the given directory.
+fn read_ftl_file(directory: &Path, language: &Language) -> Result<String, LocaleError> {
+    let file_path = directory.join(format!("{}.ftl", language.locale_id()));
+    fs::read_to_string(&file_path).map_err(|error| format!("Could not read {}: {}", file_path.display(), error).into())
+}
+
+/// Parses the content of a `.ftl` file and adds it to a new bundle for the language.
+fn create_bundle(language: &Language, source: String) -> Result<FluentBundle<FluentResource>, LocaleError> {
+    let locale_id: LanguageIdentifier = language.locale_id().parse()?;
+    let resource = FluentResource::try_new(source)
+        .map_err(|(_, errors)| format!("Could not parse the {} locale: {:?}", language.locale_id(), errors))?;
+
+    let mut bundle = FluentBundle::new_concurrent(vec![locale_id]);
+    // The isolation marks around the arguments would be visible in Telegram and break the Markdown links
+    bundle.set_use_isolating(false);
+    bundle.add_resource(resource)
+        .map_err(|errors| format!("Could not add the {} locale: {:?}", language.locale_id(), errors))?;
+    Ok(bundle)
+}
+
+/// Returns the formatted message with the given id, or `None` if the bundle does not contain it.
+fn format_message(bundle: &FluentBundle<FluentResource>, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+    let pattern = bundle.get_message(id)?.value()?;
+    let mut errors = Vec::new();
+    let message = bundle.format_pattern(pattern, args, &mut errors).to_string();
+    if !errors.is_empty() {
+        log::warn!("The message {} could not be formatted completely: {:?}", id, errors);
+    }
+    Some(message)
+}
+
+/// Loads the `.ftl` files of all languages from the directory in `LOCALES_DIR_ENV`.
+/// Languages whose file cannot be loaded keep their built-in messages. If the English file cannot be loaded,
+/// no `.ftl` file is used at all, because English is the fallback for missing messages.
+pub fn load_locales() {
+    let directory = env::var(LOCALES_DIR_ENV).unwrap_or(DEFAULT_LOCALES_DIRECTORY.to_string());
+
+    let mut localizers = Vec::new();
+    for language in Language::ALL {
+        match FluentLocalizer::load(Path::new(&directory), language.clone()) {
+            Ok(localizer) => localizers.push(localizer),
+            Err(error) if language == Language::English => {
+                log::warn!("The locales could not be loaded, using the built-in messages: {}", error.to_string());
+                return;
+            },
+            Err(error) => log::warn!("Using the built-in messages for {:?}: {}", language, error.to_string()),
+        }
+    }
+
+    log::info!("Loaded {} locale(s) from {}", localizers.len(), directory);
+    if FLUENT_LOCALIZERS.set(localizers).is_err() {
+        log::warn!("The locales have already been loaded.");
+    }
+}
+
+/// Returns the localizer of the language which has been loaded from its `.ftl` file,
+/// or `None` if the file has not been loaded.
+pub fn fluent_localizer(language: &Language) -> Option<&'static FluentLocalizer> {
+    FLUENT_LOCALIZERS.get()?.iter().find(|localizer| &localizer.language == language)
+}
+
+
+impl Localizer for FluentLocalizer {
+    fn biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        self.reading_message("biblereading", biblereading, bible_version)
+    }
+
+    fn verse_of_the_day(&self) -> String {
+        self.message("verse-of-the-day", None)
+    }
+
+    fn next_biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        self.reading_message("next-biblereading", biblereading, bible_version)
+    }
+
+    fn next_biblereading_not_found(&self) -> String {
+        self.message("next-biblereading-not-found", None)
+    }
+
+    fn biblereading_not_found(&self) -> String {
+        self.message("biblereading-not-found", None)
+    }
+
+    fn button_mark_read(&self) -> String {
+        self.message("button-mark-read", None)
+    }
+
+    fn button_snooze(&self) -> String {
+        self.message("button-snooze", None)
+    }
+
+    fn marked_as_read(&self, streak: u32) -> String {
+        let mut args = FluentArgs::new();
+        args.set("streak", streak);
+        self.message("marked-as-read", Some(&args))
+    }
+
+    fn poll_stale(&self) -> String {
+        self.message("poll-stale", None)
+    }
+
+    fn snoozed(&self, time: &NaiveTime) -> String {
+        self.time_message("snoozed", time)
+    }
+
+    fn language_set(&self) -> String {
+        self.message("language-set", None)
+    }
+
+    fn poll(&self) -> PollText {
+        PollText {
+            question: self.message("poll-question", None),
+            yes: self.message("poll-yes", None),
+            no: self.message("poll-no", None),
+        }
+    }
+
+    fn not_implemented_yet(&self) -> String {
+        self.message("not-implemented-yet", None)
+    }
+
+    fn select_language(&self) -> String {
+        self.message("select-language", None)
+    }
+
+    fn timer_updated(&self, time: &NaiveTime) -> String {
+        self.time_message("timer-updated", time)
+    }
+
+    fn timer_unset(&self) -> String {
+        self.message("timer-unset", None)
+    }
+
+    fn error_timer_update(&self) -> String {
+        self.message("error-timer-update", None)
+    }
+
+    fn biblereading_not_found_for_date(&self, date_string: &str) -> String {
+        let mut args = FluentArgs::new();
+        args.set("date", date_string.to_string());
+        self.message("biblereading-not-found-for-date", Some(&args))
+    }
+
+    fn error_invalid_date(&self) -> String {
+        self.message("error-invalid-date", None)
+    }
+
+    fn data_deleted(&self) -> String {
+        self.message("data-deleted", None)
+    }
+
+    fn no_data_to_delete(&self) -> String {
+        self.message("no-data-to-delete", None)
+    }
+
+    fn bible_version_set(&self, version: &str) -> String {
+        let mut args = FluentArgs::new();
+        args.set("version", version.to_string());
+        self.message("bible-version-set", Some(&args))
+    }
+
+    fn button_marked_read(&self) -> String {
+        self.message("button-marked-read", None)
+    }
+
+    fn read_button_enabled(&self) -> String {
+        self.message("read-button-enabled", None)
+    }
+
+    fn read_button_disabled(&self) -> String {
+        self.message("read-button-disabled", None)
+    }
+
+    fn error_read_button(&self) -> String {
+        self.message("error-read-button", None)
+    }
+
+    fn bible_version_unset(&self) -> String {
+        self.message("bible-version-unset", None)
+    }
+
+    fn error_bible_version(&self) -> String {
+        self.message("error-bible-version", None)
+    }
+
+    fn paused(&self) -> String {
+        self.message("paused", None)
+    }
+
+    fn resumed(&self) -> String {
+        self.message("resumed", None)
+    }
+
+    fn status(&self, timer: &Option<NaiveTime>, paused: bool) -> String {
+        let timer_string = match timer {
+            Some(time) => self.time_message("status-timer", time),
+            None => self.message("status-no-timer", None),
+        };
+        let paused_string = if paused { self.message("status-paused", None) } else { self.message("status-active", None) };
+        format!("{}\n{}", timer_string, paused_string)
+    }
+
+    fn feedback_sent(&self) -> String {
+        self.message("feedback-sent", None)
+    }
+
+    fn feedback_unavailable(&self) -> String {
+        self.message("feedback-unavailable", None)
+    }
+
+    fn error_feedback_empty(&self) -> String {
+        self.message("error-feedback-empty", None)
+    }
+
+    fn group_enabled(&self) -> String {
+        self.message("group-enabled", None)
+    }
+
+    fn group_not_enabled(&self) -> String {
+        self.message("group-not-enabled", None)
+    }
+
+    fn enable_private_chat(&self) -> String {
+        self.message("enable-private-chat", None)
+    }
+
+    fn plan_started(&self) -> String {
+        self.message("plan-started", None)
+    }
+
+    fn plan_stopped(&self) -> String {
+        self.message("plan-stopped", None)
+    }
+
+    fn week_heading(&self) -> String {
+        self.message("week-heading", None)
+    }
+
+    fn week_day(&self, date_string: &str, biblereading: &BibleReading) -> String {
+        let mut args = FluentArgs::new();
+        args.set("date", date_string.to_string());
+        args.set("old_testament", biblereading.old_testament_reading());
+        args.set("new_testament", biblereading.new_testament_reading());
+        self.message("week-day", Some(&args))
+    }
+
+    fn week_day_not_found(&self, date_string: &str) -> String {
+        let mut args = FluentArgs::new();
+        args.set("date", date_string.to_string());
+        self.message("week-day-not-found", Some(&args))
+    }
+
+    fn skipped_today(&self) -> String {
+        self.message("skipped-today", None)
+    }
+
+    fn psalm_label(&self) -> String {
+        self.message("psalm-label", None)
+    }
+
+    fn wisdom_label(&self) -> String {
+        self.message("wisdom-label", None)
+    }
+
+    fn no_data_to_export(&self) -> String {
+        self.message("no-data-to-export", None)
+    }
+
+    fn data_imported(&self) -> String {
+        self.message("data-imported", None)
+    }
+
+    fn error_import_invalid(&self) -> String {
+        self.message("error-import-invalid", None)
+    }
+
+    fn error_import_no_document(&self) -> String {
+        self.message("error-import-no-document", None)
+    }
+
+    fn date(&self, date: &NaiveDate) -> String {
+        let mut args = FluentArgs::new();
+        args.set("weekday", date.weekday().number_from_monday());
+        let weekday = self.message("weekday-name", Some(&args));
+
+        let mut args = FluentArgs::new();
+        args.set("month", date.month());
+        let month = self.message("month-name", Some(&args));
+
+        let mut args = FluentArgs::new();
+        args.set("weekday", weekday);
+        args.set("day", date.day().to_string());
+        args.set("month", month);
+        self.message("date", Some(&args))
+    }
+
+    fn error_poll(&self) -> String {
+        self.message("error-poll", None)
+    }
+
+    fn anonymous_poll_enabled(&self) -> String {
+        self.message("anonymous-poll-enabled", None)
+    }
+
+    fn anonymous_poll_disabled(&self) -> String {
+        self.message("anonymous-poll-disabled", None)
+    }
+
+    fn error_anonymous_poll(&self) -> String {
+        self.message("error-anonymous-poll", None)
+    }
+
+    fn history_heading(&self, read_days: usize, days: usize) -> String {
+        let mut args = FluentArgs::new();
+        args.set("read_days", read_days.to_string());
+        args.set("days", days.to_string());
+        self.message("history-heading", Some(&args))
+    }
+
+    fn verse_text_unavailable(&self) -> String {
+        self.message("verse-text-unavailable", None)
+    }
+
+    fn error_verse_not_found(&self, reference: &str) -> String {
+        let mut args = FluentArgs::new();
+        args.set("reference", reference.to_string());
+        self.message("error-verse-not-found", Some(&args))
+    }
+
+    fn error_verse_reference(&self) -> String {
+        self.message("error-verse-reference", None)
+    }
+
+    fn error_snooze_until(&self) -> String {
+        self.message("error-snooze-until", None)
+    }
+
+    fn reading_lines(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        self.reading_message("reading-lines", biblereading, bible_version)
+    }
+
+    fn custom_prefix_set(&self) -> String {
+        self.message("custom-prefix-set", None)
+    }
+
+    fn custom_prefix_unset(&self) -> String {
+        self.message("custom-prefix-unset", None)
+    }
+
+    fn error_custom_prefix(&self, max_length: usize) -> String {
+        let mut args = FluentArgs::new();
+        args.set("max_length", max_length);
+        self.message("error-custom-prefix", Some(&args))
+    }
+
+    fn markdown_enabled(&self) -> String {
+        self.message("markdown-enabled", None)
+    }
+
+    fn markdown_disabled(&self) -> String {
+        self.message("markdown-disabled", None)
+    }
+
+    fn error_markdown(&self) -> String {
+        self.message("error-markdown", None)
+    }
+
+    fn weekly_summary(&self, read_days: usize) -> String {
+        let mut args = FluentArgs::new();
+        args.set("read_days", read_days);
+        self.message("weekly-summary", Some(&args))
+    }
+
+    fn weekly_summary_enabled(&self) -> String {
+        self.message("weekly-summary-enabled", None)
+    }
+
+    fn weekly_summary_disabled(&self) -> String {
+        self.message("weekly-summary-disabled", None)
+    }
+
+    fn error_weekly_summary(&self) -> String {
+        self.message("error-weekly-summary", None)
+    }
+
+    fn confirm_delete_data(&self) -> String {
+        self.message("confirm-delete-data", None)
+    }
+
+    fn button_delete_confirm(&self) -> String {
+        self.message("button-delete-confirm", None)
+    }
+
+    fn button_delete_cancel(&self) -> String {
+        self.message("button-delete-cancel", None)
+    }
+
+    fn deletion_cancelled(&self) -> String {
+        self.message("deletion-cancelled", None)
+    }
+
+    fn deletion_expired(&self) -> String {
+        self.message("deletion-expired", None)
+    }
+
+    fn next_reminder_today(&self, time: &NaiveTime) -> String {
+        self.time_message("next-reminder-today", time)
+    }
+
+    fn next_reminder_tomorrow(&self, time: &NaiveTime) -> String {
+        self.time_message("next-reminder-tomorrow", time)
+    }
+
+    fn next_reminder_on(&self, date: &str, time: &NaiveTime) -> String {
+        let mut args = FluentArgs::new();
+        args.set("date", date.to_string());
+        args.set("time", time.format("%H:%M").to_string());
+        self.message("next-reminder-on", Some(&args))
+    }
+
+    fn next_reminder_no_timer(&self) -> String {
+        self.message("next-reminder-no-timer", None)
+    }
+
+    fn next_reminder_paused(&self) -> String {
+        self.message("next-reminder-paused", None)
+    }
+
+    fn user_information_header(&self) -> String {
+        self.message("user-information-header", None)
+    }
+
+    fn no_data(&self) -> String {
+        self.message("no-data", None)
+    }
+
+    fn random_reading(&self) -> String {
+        self.message("random-reading", None)
+    }
+
+    fn button_listen_old_testament(&self) -> String {
+        self.message("button-listen-old-testament", None)
+    }
+
+    fn button_listen_new_testament(&self) -> String {
+        self.message("button-listen-new-testament", None)
+    }
+
+    fn about(&self, version: &str, commit: &str, languages: &str) -> String {
+        let mut args = FluentArgs::new();
+        args.set("version", version.to_string());
+        args.set("commit", commit.to_string());
+        args.set("languages", languages.to_string());
+        self.message("about", Some(&args))
+    }
+
+    fn available_plans(&self, plans: &str, current: &str) -> String {
+        let mut args = FluentArgs::new();
+        args.set("plans", plans.to_string());
+        args.set("current", current.to_string());
+        self.message("available-plans", Some(&args))
+    }
+
+    fn plan_set(&self, plan: &str) -> String {
+        let mut args = FluentArgs::new();
+        args.set("plan", plan.to_string());
+        self.message("plan-set", Some(&args))
+    }
+
+    fn error_unknown_plan(&self, plan: &str) -> String {
+        let mut args = FluentArgs::new();
+        args.set("plan", plan.to_string());
+        self.message("error-unknown-plan", Some(&args))
+    }
+
+    fn member_since(&self, date: &str, days: i64) -> String {
+        let mut args = FluentArgs::new();
+        args.set("date", date.to_string());
+        args.set("days", days.to_string());
+        self.message("member-since", Some(&args))
+    }
+
+    fn member_since_unknown(&self) -> String {
+        self.message("member-since-unknown", None)
+    }
+
+    fn private_chat(&self) -> String {
+        self.message("private-chat", None)
+    }
+
+    fn group_chat_enabled(&self) -> String {
+        self.message("group-chat-enabled", None)
+    }
+
+    fn group_chat_not_enabled(&self) -> String {
+        self.message("group-chat-not-enabled", None)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::localize::{english::English, german::German, italian::Italian, portuguese::Portuguese, spanish::Spanish};
+    use crate::biblereading::AudioLinks;
+
+    /// Compares the messages of the `.ftl` file with the built-in messages of the language
+    fn assert_same_messages(fluent: &dyn Localizer, builtin: &dyn Localizer) {
+        let biblereading = BibleReading {
+            date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            old_testament_readings: vec![String::from("Psalm 135,136")],
+            new_testament_readings: vec![String::from("1Kor12")],
+            psalm: None,
+            wisdom: None,
+            audio: AudioLinks::default(),
+        };
+        let time = NaiveTime::from_hms_opt(7, 30, 0).unwrap();
+
+        assert_eq!(fluent.biblereading(&biblereading, Some("ESV")), builtin.biblereading(&biblereading, Some("ESV")));
+        assert_eq!(fluent.next_biblereading(&biblereading, None), builtin.next_biblereading(&biblereading, None));
+        assert_eq!(fluent.reading_lines(&biblereading, None), builtin.reading_lines(&biblereading, None));
+        assert_eq!(fluent.verse_of_the_day(), builtin.verse_of_the_day());
+        assert_eq!(fluent.marked_as_read(3), builtin.marked_as_read(3));
+        assert_eq!(fluent.snoozed(&time), builtin.snoozed(&time));
+        assert_eq!(fluent.poll(), builtin.poll());
+        assert_eq!(fluent.timer_updated(&time), builtin.timer_updated(&time));
+        assert_eq!(fluent.biblereading_not_found_for_date("09-01"), builtin.biblereading_not_found_for_date("09-01"));
+        assert_eq!(fluent.bible_version_set("ESV"), builtin.bible_version_set("ESV"));
+        assert_eq!(fluent.read_button_enabled(), builtin.read_button_enabled());
+        assert_eq!(fluent.status(&Some(time), true), builtin.status(&Some(time), true));
+        assert_eq!(fluent.status(&None, false), builtin.status(&None, false));
+        assert_eq!(fluent.next_reminder_on("Sunday, 1 September", &time), builtin.next_reminder_on("Sunday, 1 September", &time));
+        assert_eq!(fluent.about("0.1.0", "1a2b3c4", "English, Deutsch"), builtin.about("0.1.0", "1a2b3c4", "English, Deutsch"));
+        assert_eq!(fluent.available_plans("chronological, mcheyne", "mcheyne"), builtin.available_plans("chronological, mcheyne", "mcheyne"));
+        assert_eq!(fluent.member_since("2024-09-01", 42), builtin.member_since("2024-09-01", 42));
+        assert_eq!(fluent.member_since_unknown(), builtin.member_since_unknown());
+        assert_eq!(fluent.private_chat(), builtin.private_chat());
+        assert_eq!(fluent.group_chat_enabled(), builtin.group_chat_enabled());
+        assert_eq!(fluent.group_chat_not_enabled(), builtin.group_chat_not_enabled());
+        for day in 1..=7 {
+            let date = NaiveDate::from_ymd_opt(2024, 9, day).unwrap();
+            assert_eq!(fluent.date(&date), builtin.date(&date));
+        }
+        assert_eq!(fluent.date(&NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()), builtin.date(&NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()));
+    }
+
+    #[test]
+    fn test_locale_files_match_builtin_messages() {
+        let directory = Path::new(DEFAULT_LOCALES_DIRECTORY);
+        assert_same_messages(&FluentLocalizer::load(directory, Language::English).unwrap(), &English);
+        assert_same_messages(&FluentLocalizer::load(directory, Language::German).unwrap(), &German);
+        assert_same_messages(&FluentLocalizer::load(directory, Language::Spanish).unwrap(), &Spanish);
+        assert_same_messages(&FluentLocalizer::load(directory, Language::Portuguese).unwrap(), &Portuguese);
+        assert_same_messages(&FluentLocalizer::load(directory, Language::Italian).unwrap(), &Italian);
+    }
+
+    #[test]
+    fn test_missing_message_falls_back_to_english() {
+        let localizer = FluentLocalizer::from_sources(
+            Language::German,
+            String::from("paused = Pausiert"),
+            Some(String::from("paused = Paused\nresumed = Active again")),
+        ).unwrap();
+
+        assert_eq!(localizer.paused(), "Pausiert");
+        assert_eq!(localizer.resumed(), "Active again");
+        // A message which is missing in both languages is replaced by its id
+        assert_eq!(localizer.data_deleted(), "data-deleted");
+    }
+}
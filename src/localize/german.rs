@@ -0,0 +1,414 @@
+//! In this unit, the German messages of the bot are implemented.
+
+use chrono::{Datelike, NaiveDate, NaiveTime};
+
+use crate::biblereading::BibleReading;
+
+use super::{format_reading, Localizer, PollText};
+
+/// The names of the weekdays, starting with Monday
+const WEEKDAYS: [&str; 7] = ["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag"];
+
+/// The names of the months, starting with January
+const MONTHS: [&str; 12] = ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"];
+
+pub struct German;
+
+impl Localizer for German {
+    fn biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "*📖 Dies ist eine Erinnerung, heute in der Bibel zu lesen*:\n\nAT: {}\nNT: {}", 
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn verse_of_the_day(&self) -> String {
+        String::from("✨ Vers des Tages")
+    }
+
+    fn next_biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "*📖 Die Bibellese für morgen*:\n\nAT: {}\nNT: {}", 
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn next_biblereading_not_found(&self) -> String {
+        "Für morgen ist keine Bibellese eingeplant.".to_string()
+    }
+
+    fn biblereading_not_found(&self) -> String {
+        "Dies ist eine Erinnerung, heute in der Bibel zu lesen.".to_string()
+    }
+
+    fn button_mark_read(&self) -> String {
+        String::from("✅ Als gelesen markieren")
+    }
+
+    fn button_snooze(&self) -> String {
+        String::from("⏰ Später erinnern")
+    }
+
+    fn marked_as_read(&self, streak: u32) -> String {
+        format!("Gut gemacht! Du hast {} Tag(e) in Folge in der Bibel gelesen.", streak)
+    }
+
+    fn poll_stale(&self) -> String {
+        String::from("Diese Umfrage gehört zu einer früheren Erinnerung, daher wird deine Antwort nicht gezählt. Bitte beantworte die Umfrage der heutigen Erinnerung.")
+    }
+
+    fn snoozed(&self, time: &NaiveTime) -> String {
+        format!("Ich erinnere dich um {} noch einmal.", time.format("%H:%M"))
+    }
+
+    fn language_set(&self) -> String {
+        "Die Sprache wurde auf Deutsch umgestellt.".to_string()
+    }
+
+    fn poll(&self) -> PollText {
+        PollText {
+            question: String::from("Hast du heute in der Bibel gelesen?"),
+            yes: String::from("Ja"),
+            no: String::from("Nein"),
+        }
+    }
+
+    fn not_implemented_yet(&self) -> String {
+        "Diese Funktion wurde noch nicht implementiert.".to_string()
+    }
+
+    fn select_language(&self) -> String {
+        String::from("Bitte wählen Sie die Sprache aus, die sie einstellen möchten.")
+    }
+
+    fn timer_updated(&self, time: &NaiveTime) -> String {
+        format!("Die tägliche Erinnerung wurde auf {} Uhr gesetzt.", time.format("%H:%M"))
+    }
+
+    fn timer_unset(&self) -> String {
+        String::from("Die tägliche Erinnerung wurde deaktiviert")
+    }
+
+    fn error_timer_update(&self) -> String {
+        String::from("Ungültiges Format. Bitte benutze die Funktion mit einer gültigen Zeitangabe, zum Beispiel /settimer 08:00.")
+    }
+
+    fn biblereading_not_found_for_date(&self, date_string: &str) -> String {
+        format!("Für den {} ist keine Bibellese eingeplant.", date_string)
+    }
+
+    fn error_invalid_date(&self) -> String {
+        String::from("Ungültiges Datum. Bitte benutze die Funktion mit einem gültigen Datum, zum Beispiel /readingfor 09-01.")
+    }
+
+    fn data_deleted(&self) -> String {
+        String::from("Alle Daten über dich wurden gelöscht.")
+    }
+
+    fn no_data_to_delete(&self) -> String {
+        String::from("Es sind keine Daten über dich gespeichert, daher wurde nichts gelöscht.")
+    }
+
+    fn bible_version_set(&self, version: &str) -> String {
+        format!("Die Bibellese wird mit der Bibelübersetzung {} verlinkt.", version)
+    }
+
+    fn button_marked_read(&self) -> String {
+        String::from("✅ Gelesen")
+    }
+
+    fn read_button_enabled(&self) -> String {
+        String::from("Die tägliche Erinnerung wird jetzt mit dem Knopf \"Als gelesen markieren\" statt mit einer Umfrage bestätigt.")
+    }
+
+    fn read_button_disabled(&self) -> String {
+        String::from("Nach der täglichen Erinnerung wird wieder eine Umfrage gesendet.")
+    }
+
+    fn error_read_button(&self) -> String {
+        String::from("Bitte benutze /readbutton on oder /readbutton off.")
+    }
+
+    fn bible_version_unset(&self) -> String {
+        String::from("Die Links zur Bibellese wurden deaktiviert.")
+    }
+
+    fn error_bible_version(&self) -> String {
+        String::from("Ungültige Bibelübersetzung. Bitte benutze ihre Abkürzung, zum Beispiel /setversion LUT.")
+    }
+
+    fn paused(&self) -> String {
+        String::from("Die täglichen Erinnerungen sind pausiert. Deine Erinnerungszeit bleibt erhalten, mit /resume geht es weiter.")
+    }
+
+    fn resumed(&self) -> String {
+        String::from("Die täglichen Erinnerungen sind wieder aktiv.")
+    }
+
+    fn status(&self, timer: &Option<NaiveTime>, paused: bool) -> String {
+        let timer_string = match timer {
+            Some(time) => format!("Die tägliche Erinnerung ist auf {} gesetzt.", time.format("%H:%M")),
+            None => String::from("Es ist keine tägliche Erinnerung gesetzt."),
+        };
+        let paused_string = if paused { "Die Erinnerungen sind pausiert." } else { "Die Erinnerungen sind aktiv." };
+        format!("{}\n{}", timer_string, paused_string)
+    }
+
+    fn feedback_sent(&self) -> String {
+        String::from("Danke für dein Feedback! Es wurde an den Betreiber des Bots weitergeleitet.")
+    }
+
+    fn feedback_unavailable(&self) -> String {
+        String::from("Leider ist für diesen Bot kein Feedback möglich.")
+    }
+
+    fn error_feedback_empty(&self) -> String {
+        String::from("Bitte schreibe dein Feedback hinter den Befehl, zum Beispiel /feedback Danke für diesen Bot!")
+    }
+
+    fn group_enabled(&self) -> String {
+        String::from("Die täglichen Erinnerungen sind jetzt für diese Gruppe aktiviert.")
+    }
+
+    fn group_not_enabled(&self) -> String {
+        String::from("In Gruppen werden die täglichen Erinnerungen erst gesendet, nachdem sie mit /enable aktiviert wurden.")
+    }
+
+    fn enable_private_chat(&self) -> String {
+        String::from("In privaten Chats sind die täglichen Erinnerungen immer aktiviert.")
+    }
+
+    fn plan_started(&self) -> String {
+        String::from("Dein persönlicher Leseplan beginnt heute. Ab jetzt bekommst du die Bibellesen des Plans der Reihe nach, mit /startplan off folgst du wieder dem Kalender.")
+    }
+
+    fn plan_stopped(&self) -> String {
+        String::from("Die Bibellese folgt wieder dem Kalender.")
+    }
+
+    fn week_heading(&self) -> String {
+        String::from("📅 Die Bibellesen der nächsten sieben Tage:")
+    }
+
+    fn week_day(&self, date_string: &str, biblereading: &BibleReading) -> String {
+        format!("{}: AT {} | NT {}", date_string, biblereading.old_testament_reading(), biblereading.new_testament_reading())
+    }
+
+    fn week_day_not_found(&self, date_string: &str) -> String {
+        format!("{}: keine Bibellese eingeplant", date_string)
+    }
+
+    fn skipped_today(&self) -> String {
+        String::from("Heute bekommst du keine Erinnerung. Ab morgen geht es wie gewohnt weiter.")
+    }
+
+    fn psalm_label(&self) -> String {
+        String::from("Psalm")
+    }
+
+    fn wisdom_label(&self) -> String {
+        String::from("Weisheit")
+    }
+
+    fn no_data_to_export(&self) -> String {
+        String::from("Es sind keine Daten über dich gespeichert, daher gibt es nichts zu exportieren.")
+    }
+
+    fn data_imported(&self) -> String {
+        String::from("Deine Daten wurden importiert.")
+    }
+
+    fn error_import_invalid(&self) -> String {
+        String::from("Die Datei konnte nicht importiert werden. Bitte sende eine JSON-Datei, die mit /export erstellt wurde.")
+    }
+
+    fn error_import_no_document(&self) -> String {
+        String::from("Bitte antworte mit /importdata auf die JSON-Datei, die mit /export erstellt wurde, oder sende die Datei mit /importdata als Beschriftung.")
+    }
+
+    fn date(&self, date: &NaiveDate) -> String {
+        format!("{}, {}. {}", WEEKDAYS[date.weekday().num_days_from_monday() as usize], date.day(), MONTHS[date.month0() as usize])
+    }
+
+    fn error_poll(&self) -> String {
+        String::from("Bitte benutze /poll on oder /poll off.")
+    }
+
+    fn anonymous_poll_enabled(&self) -> String {
+        String::from("Die Umfrage nach der täglichen Erinnerung ist jetzt anonym. Anonyme Antworten können nicht für deine Serie gezählt werden.")
+    }
+
+    fn anonymous_poll_disabled(&self) -> String {
+        String::from("Die Umfrage nach der täglichen Erinnerung ist nicht mehr anonym (Umfragen in Gruppen sind immer anonym).")
+    }
+
+    fn error_anonymous_poll(&self) -> String {
+        String::from("Bitte benutze /pollanon on oder /pollanon off.")
+    }
+
+    fn history_heading(&self, read_days: usize, days: usize) -> String {
+        format!("📊 Deine Bibellesen der letzten {} Tage ({} von {} gelesen):", days, read_days, days)
+    }
+
+    fn verse_text_unavailable(&self) -> String {
+        String::from("Der Text der Bibelstelle konnte nicht geladen werden, aber du kannst ihn hier lesen:")
+    }
+
+    fn error_verse_not_found(&self, reference: &str) -> String {
+        format!("Die Bibelstelle {} wurde nicht gefunden. Bitte überprüfe die Angabe.", reference)
+    }
+
+    fn error_verse_reference(&self) -> String {
+        String::from("Bitte gib eine Bibelstelle an, zum Beispiel /verse Joh 3,16.")
+    }
+
+    fn error_snooze_until(&self) -> String {
+        String::from("Bitte benutze die Funktion mit einer späteren Uhrzeit von heute (zum Beispiel /snoozeuntil 20:00).")
+    }
+
+    fn reading_lines(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "AT: {}\nNT: {}",
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn custom_prefix_set(&self) -> String {
+        String::from("Deine Erinnerungen beginnen jetzt mit deiner eigenen Nachricht. Mit /setmessage ohne Text wird wieder die Standardnachricht verwendet.")
+    }
+
+    fn custom_prefix_unset(&self) -> String {
+        String::from("Deine Erinnerungen beginnen wieder mit der Standardnachricht.")
+    }
+
+    fn error_custom_prefix(&self, max_length: usize) -> String {
+        format!("Deine Nachricht ist zu lang. Bitte verwende höchstens {} Zeichen, zum Beispiel /setmessage Guten Morgen! Zeit zum Lesen:", max_length)
+    }
+
+    fn markdown_enabled(&self) -> String {
+        String::from("Deine Erinnerungen werden wieder formatiert (fetter Text und Links).")
+    }
+
+    fn markdown_disabled(&self) -> String {
+        String::from("Deine Erinnerungen werden jetzt als einfacher Text ohne Formatierung gesendet.")
+    }
+
+    fn error_markdown(&self) -> String {
+        String::from("Bitte benutze /markdown on oder /markdown off.")
+    }
+
+    fn weekly_summary(&self, read_days: usize) -> String {
+        format!("📅 Deine Woche: Du hast an {} von 7 Tagen in der Bibel gelesen. Weiter so!", read_days)
+    }
+
+    fn weekly_summary_enabled(&self) -> String {
+        String::from("Jeden Sonntag bekommst du zusammen mit der Erinnerung eine Zusammenfassung deiner Woche.")
+    }
+
+    fn weekly_summary_disabled(&self) -> String {
+        String::from("Du bekommst keine wöchentliche Zusammenfassung mehr.")
+    }
+
+    fn error_weekly_summary(&self) -> String {
+        String::from("Bitte benutze /weeklysummary on oder /weeklysummary off.")
+    }
+
+    fn confirm_delete_data(&self) -> String {
+        String::from("Möchtest du wirklich alle Daten löschen, die über diesen Chat gespeichert sind? Das kann nicht rückgängig gemacht werden.")
+    }
+
+    fn button_delete_confirm(&self) -> String {
+        String::from("🗑 Ja, löschen")
+    }
+
+    fn button_delete_cancel(&self) -> String {
+        String::from("Abbrechen")
+    }
+
+    fn deletion_cancelled(&self) -> String {
+        String::from("Deine Daten wurden nicht gelöscht.")
+    }
+
+    fn deletion_expired(&self) -> String {
+        String::from("Die Bestätigung ist abgelaufen, deine Daten wurden nicht gelöscht. Bitte benutze /deletemydata erneut.")
+    }
+
+    fn next_reminder_today(&self, time: &NaiveTime) -> String {
+        format!("Deine nächste Erinnerung kommt heute um {}.", time.format("%H:%M"))
+    }
+
+    fn next_reminder_tomorrow(&self, time: &NaiveTime) -> String {
+        format!("Deine nächste Erinnerung kommt morgen um {}.", time.format("%H:%M"))
+    }
+
+    fn next_reminder_on(&self, date: &str, time: &NaiveTime) -> String {
+        format!("Deine nächste Erinnerung kommt am {} um {}.", date, time.format("%H:%M"))
+    }
+
+    fn next_reminder_no_timer(&self) -> String {
+        String::from("Es ist kein täglicher Timer eingestellt, daher bekommst du keine Erinnerungen. Benutze /settimer, um einen einzustellen.")
+    }
+
+    fn next_reminder_paused(&self) -> String {
+        String::from("Deine Erinnerungen sind pausiert. Benutze /resume, um sie wieder zu bekommen.")
+    }
+
+    fn user_information_header(&self) -> String {
+        String::from("Die folgenden Daten über dich sind auf dem Server gespeichert:")
+    }
+
+    fn no_data(&self) -> String {
+        String::from("Auf dem Server sind derzeit keine Daten über dich gespeichert.")
+    }
+
+    fn random_reading(&self) -> String {
+        String::from("Hier ist ein zufälliger Abschnitt:")
+    }
+
+    fn button_listen_old_testament(&self) -> String {
+        String::from("🔊 Anhören (AT)")
+    }
+
+    fn button_listen_new_testament(&self) -> String {
+        String::from("🔊 Anhören (NT)")
+    }
+
+    fn about(&self, version: &str, commit: &str, languages: &str) -> String {
+        format!("DailyBible {} (Commit {})\nSprachen: {}\nQuellcode:", version, commit, languages)
+    }
+
+    fn available_plans(&self, plans: &str, current: &str) -> String {
+        format!("Die folgenden Lesepläne sind verfügbar: {}\nDein aktueller Plan ist {}. Um ihn zu ändern, nutze /setplan gefolgt vom Namen des Plans.", plans, current)
+    }
+
+    fn plan_set(&self, plan: &str) -> String {
+        format!("Deine Lesungen kommen jetzt aus dem Leseplan {}.", plan)
+    }
+
+    fn error_unknown_plan(&self, plan: &str) -> String {
+        format!("Es gibt keinen Leseplan mit dem Namen {}.", plan)
+    }
+
+    fn member_since(&self, date: &str, days: i64) -> String {
+        format!("Mitglied seit {} ({} Tage).", date, days)
+    }
+
+    fn member_since_unknown(&self) -> String {
+        String::from("Mitglied seit einem unbekannten Datum (registriert, bevor es gespeichert wurde).")
+    }
+
+    fn private_chat(&self) -> String {
+        String::from("Dies ist ein privater Chat.")
+    }
+
+    fn group_chat_enabled(&self) -> String {
+        String::from("Dies ist ein Gruppenchat, die Erinnerungen sind aktiviert.")
+    }
+
+    fn group_chat_not_enabled(&self) -> String {
+        String::from("Dies ist ein Gruppenchat, die Erinnerungen sind noch nicht aktiviert (verwende /enable).")
+    }
+}
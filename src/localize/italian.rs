@@ -0,0 +1,414 @@
+//! In this unit, the Italian messages of the bot are implemented.
+
+use chrono::{Datelike, NaiveDate, NaiveTime};
+
+use crate::biblereading::BibleReading;
+
+use super::{format_reading, Localizer, PollText};
+
+/// The names of the weekdays, starting with Monday
+const WEEKDAYS: [&str; 7] = ["lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato", "domenica"];
+
+/// The names of the months, starting with January
+const MONTHS: [&str; 12] = ["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"];
+
+pub struct Italian;
+
+impl Localizer for Italian {
+    fn biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "*📖 Questo è un promemoria per leggere la Bibbia oggi*:\n\nAT: {}\nNT: {}",
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn verse_of_the_day(&self) -> String {
+        String::from("✨ Versetto del giorno")
+    }
+
+    fn next_biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "*📖 La lettura di domani*:\n\nAT: {}\nNT: {}",
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn next_biblereading_not_found(&self) -> String {
+        "Non c'è nessuna lettura biblica prevista per domani.".to_string()
+    }
+
+    fn biblereading_not_found(&self) -> String {
+        "Questo è un promemoria per leggere la Bibbia oggi.".to_string()
+    }
+
+    fn button_mark_read(&self) -> String {
+        String::from("✅ Segna come letto")
+    }
+
+    fn button_snooze(&self) -> String {
+        String::from("⏰ Ricordamelo più tardi")
+    }
+
+    fn marked_as_read(&self, streak: u32) -> String {
+        format!("Ben fatto! Hai letto la Bibbia per {} giorno/i di fila.", streak)
+    }
+
+    fn poll_stale(&self) -> String {
+        String::from("Questo sondaggio appartiene a un promemoria precedente, quindi la tua risposta non viene contata. Per favore, rispondi al sondaggio del promemoria di oggi.")
+    }
+
+    fn snoozed(&self, time: &NaiveTime) -> String {
+        format!("Te lo ricorderò di nuovo alle {}.", time.format("%H:%M"))
+    }
+
+    fn language_set(&self) -> String {
+        "La lingua è stata impostata sull'italiano.".to_string()
+    }
+
+    fn poll(&self) -> PollText {
+        PollText {
+            question: String::from("Hai letto la Bibbia oggi?"),
+            yes: String::from("Sì"),
+            no: String::from("No"),
+        }
+    }
+
+    fn not_implemented_yet(&self) -> String {
+        "Questa funzione non è ancora stata implementata.".to_string()
+    }
+
+    fn select_language(&self) -> String {
+        String::from("Per favore, scegli la lingua che vuoi impostare.")
+    }
+
+    fn timer_updated(&self, time: &NaiveTime) -> String {
+        format!("Il promemoria giornaliero è stato impostato alle {}.", time.format("%H:%M"))
+    }
+
+    fn timer_unset(&self) -> String {
+        String::from("Il promemoria giornaliero è stato disattivato")
+    }
+
+    fn error_timer_update(&self) -> String {
+        String::from("Il formato non è valido. Per favore, usa la funzione con un orario valido (ad esempio /settimer 08:00).")
+    }
+
+    fn biblereading_not_found_for_date(&self, date_string: &str) -> String {
+        format!("Non c'è nessuna lettura biblica prevista per il {}.", date_string)
+    }
+
+    fn error_invalid_date(&self) -> String {
+        String::from("La data non è valida. Per favore, usa la funzione con una data valida (ad esempio /readingfor 09-01).")
+    }
+
+    fn data_deleted(&self) -> String {
+        String::from("Tutti i tuoi dati sono stati eliminati.")
+    }
+
+    fn no_data_to_delete(&self) -> String {
+        String::from("Non ci sono dati salvati su di te, quindi non è stato eliminato nulla.")
+    }
+
+    fn bible_version_set(&self, version: &str) -> String {
+        format!("Le letture verranno collegate alla versione della Bibbia {}.", version)
+    }
+
+    fn button_marked_read(&self) -> String {
+        String::from("✅ Letto")
+    }
+
+    fn read_button_enabled(&self) -> String {
+        String::from("Il promemoria giornaliero ora si conferma con il pulsante \"Segna come letto\" invece che con un sondaggio.")
+    }
+
+    fn read_button_disabled(&self) -> String {
+        String::from("Dopo il promemoria giornaliero viene di nuovo inviato un sondaggio.")
+    }
+
+    fn error_read_button(&self) -> String {
+        String::from("Per favore, usa /readbutton on o /readbutton off.")
+    }
+
+    fn bible_version_unset(&self) -> String {
+        String::from("I link alle letture sono stati disattivati.")
+    }
+
+    fn error_bible_version(&self) -> String {
+        String::from("La versione della Bibbia non è valida. Per favore, usa la sua abbreviazione, ad esempio /setversion CEI.")
+    }
+
+    fn paused(&self) -> String {
+        String::from("I promemoria giornalieri sono in pausa. Il tuo orario viene mantenuto, usa /resume per continuare.")
+    }
+
+    fn resumed(&self) -> String {
+        String::from("I promemoria giornalieri sono di nuovo attivi.")
+    }
+
+    fn status(&self, timer: &Option<NaiveTime>, paused: bool) -> String {
+        let timer_string = match timer {
+            Some(time) => format!("Il promemoria giornaliero è impostato alle {}.", time.format("%H:%M")),
+            None => String::from("Non è impostato nessun promemoria giornaliero."),
+        };
+        let paused_string = if paused { "I promemoria sono in pausa." } else { "I promemoria sono attivi." };
+        format!("{}\n{}", timer_string, paused_string)
+    }
+
+    fn feedback_sent(&self) -> String {
+        String::from("Grazie per il tuo feedback! È stato inviato al gestore del bot.")
+    }
+
+    fn feedback_unavailable(&self) -> String {
+        String::from("Spiacenti, questo bot non accetta feedback.")
+    }
+
+    fn error_feedback_empty(&self) -> String {
+        String::from("Per favore, scrivi il tuo feedback dopo il comando, ad esempio /feedback Grazie per questo bot!")
+    }
+
+    fn group_enabled(&self) -> String {
+        String::from("I promemoria giornalieri sono ora attivati per questo gruppo.")
+    }
+
+    fn group_not_enabled(&self) -> String {
+        String::from("Nei gruppi, i promemoria giornalieri vengono inviati solo dopo averli attivati con /enable.")
+    }
+
+    fn enable_private_chat(&self) -> String {
+        String::from("Nelle chat private, i promemoria giornalieri sono sempre attivati.")
+    }
+
+    fn plan_started(&self) -> String {
+        String::from("Il tuo piano di lettura personale inizia oggi. D'ora in poi riceverai le letture del piano una dopo l'altra, usa /startplan off per seguire di nuovo il calendario.")
+    }
+
+    fn plan_stopped(&self) -> String {
+        String::from("Le letture seguono di nuovo il calendario.")
+    }
+
+    fn week_heading(&self) -> String {
+        String::from("📅 Le letture dei prossimi sette giorni:")
+    }
+
+    fn week_day(&self, date_string: &str, biblereading: &BibleReading) -> String {
+        format!("{}: AT {} | NT {}", date_string, biblereading.old_testament_reading(), biblereading.new_testament_reading())
+    }
+
+    fn week_day_not_found(&self, date_string: &str) -> String {
+        format!("{}: nessuna lettura prevista", date_string)
+    }
+
+    fn skipped_today(&self) -> String {
+        String::from("Oggi non riceverai nessun promemoria. Domani i promemoria continuano come sempre.")
+    }
+
+    fn psalm_label(&self) -> String {
+        String::from("Salmo")
+    }
+
+    fn wisdom_label(&self) -> String {
+        String::from("Sapienza")
+    }
+
+    fn no_data_to_export(&self) -> String {
+        String::from("Non ci sono dati salvati su di te, quindi non c'è nulla da esportare.")
+    }
+
+    fn data_imported(&self) -> String {
+        String::from("I tuoi dati sono stati importati.")
+    }
+
+    fn error_import_invalid(&self) -> String {
+        String::from("Non è stato possibile importare il file. Per favore, invia un file JSON creato con /export.")
+    }
+
+    fn error_import_no_document(&self) -> String {
+        String::from("Per favore, rispondi con /importdata al file JSON creato con /export, oppure invia il file con /importdata come didascalia.")
+    }
+
+    fn date(&self, date: &NaiveDate) -> String {
+        format!("{} {} {}", WEEKDAYS[date.weekday().num_days_from_monday() as usize], date.day(), MONTHS[date.month0() as usize])
+    }
+
+    fn error_poll(&self) -> String {
+        String::from("Per favore, usa /poll on o /poll off.")
+    }
+
+    fn anonymous_poll_enabled(&self) -> String {
+        String::from("Il sondaggio dopo il promemoria giornaliero ora è anonimo. Le risposte anonime non contano per la tua serie.")
+    }
+
+    fn anonymous_poll_disabled(&self) -> String {
+        String::from("Il sondaggio dopo il promemoria giornaliero non è più anonimo (i sondaggi nei gruppi sono sempre anonimi).")
+    }
+
+    fn error_anonymous_poll(&self) -> String {
+        String::from("Per favore, usa /pollanon on o /pollanon off.")
+    }
+
+    fn history_heading(&self, read_days: usize, days: usize) -> String {
+        format!("📊 Le tue letture degli ultimi {} giorni ({} su {} lette):", days, read_days, days)
+    }
+
+    fn verse_text_unavailable(&self) -> String {
+        String::from("Non è stato possibile caricare il testo del brano, ma puoi leggerlo qui:")
+    }
+
+    fn error_verse_not_found(&self, reference: &str) -> String {
+        format!("Il brano {} non è stato trovato. Per favore, controlla il riferimento.", reference)
+    }
+
+    fn error_verse_reference(&self) -> String {
+        String::from("Per favore, indica un riferimento biblico, ad esempio /verse Giovanni 3,16.")
+    }
+
+    fn error_snooze_until(&self) -> String {
+        String::from("Per favore, usa la funzione con un orario successivo di oggi (ad esempio /snoozeuntil 20:00).")
+    }
+
+    fn reading_lines(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "AT: {}\nNT: {}",
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn custom_prefix_set(&self) -> String {
+        String::from("I tuoi promemoria ora iniziano con il tuo messaggio. Usa /setmessage senza testo per tornare al messaggio predefinito.")
+    }
+
+    fn custom_prefix_unset(&self) -> String {
+        String::from("I tuoi promemoria iniziano di nuovo con il messaggio predefinito.")
+    }
+
+    fn error_custom_prefix(&self, max_length: usize) -> String {
+        format!("Il tuo messaggio è troppo lungo. Per favore, usa al massimo {} caratteri, ad esempio /setmessage Buongiorno! È ora di leggere:", max_length)
+    }
+
+    fn markdown_enabled(&self) -> String {
+        String::from("I tuoi promemoria sono di nuovo formattati (testo in grassetto e link).")
+    }
+
+    fn markdown_disabled(&self) -> String {
+        String::from("I tuoi promemoria ora vengono inviati come testo semplice senza formattazione.")
+    }
+
+    fn error_markdown(&self) -> String {
+        String::from("Per favore, usa /markdown on o /markdown off.")
+    }
+
+    fn weekly_summary(&self, read_days: usize) -> String {
+        format!("📅 La tua settimana: hai letto la Bibbia in {} giorni su 7. Continua così!", read_days)
+    }
+
+    fn weekly_summary_enabled(&self) -> String {
+        String::from("Ogni domenica riceverai un riepilogo della tua settimana insieme al promemoria.")
+    }
+
+    fn weekly_summary_disabled(&self) -> String {
+        String::from("Non riceverai più un riepilogo settimanale.")
+    }
+
+    fn error_weekly_summary(&self) -> String {
+        String::from("Per favore, usa /weeklysummary on o /weeklysummary off.")
+    }
+
+    fn confirm_delete_data(&self) -> String {
+        String::from("Vuoi davvero eliminare tutti i dati salvati su questa chat? L'operazione non può essere annullata.")
+    }
+
+    fn button_delete_confirm(&self) -> String {
+        String::from("🗑 Sì, elimina")
+    }
+
+    fn button_delete_cancel(&self) -> String {
+        String::from("Annulla")
+    }
+
+    fn deletion_cancelled(&self) -> String {
+        String::from("I tuoi dati non sono stati eliminati.")
+    }
+
+    fn deletion_expired(&self) -> String {
+        String::from("La conferma è scaduta, i tuoi dati non sono stati eliminati. Per favore, usa di nuovo /deletemydata.")
+    }
+
+    fn next_reminder_today(&self, time: &NaiveTime) -> String {
+        format!("Il tuo prossimo promemoria è oggi alle {}.", time.format("%H:%M"))
+    }
+
+    fn next_reminder_tomorrow(&self, time: &NaiveTime) -> String {
+        format!("Il tuo prossimo promemoria è domani alle {}.", time.format("%H:%M"))
+    }
+
+    fn next_reminder_on(&self, date: &str, time: &NaiveTime) -> String {
+        format!("Il tuo prossimo promemoria è {} alle {}.", date, time.format("%H:%M"))
+    }
+
+    fn next_reminder_no_timer(&self) -> String {
+        String::from("Non c'è nessun orario giornaliero, quindi non riceverai promemoria. Usa /settimer per impostarne uno.")
+    }
+
+    fn next_reminder_paused(&self) -> String {
+        String::from("I tuoi promemoria sono in pausa. Usa /resume per riceverli di nuovo.")
+    }
+
+    fn user_information_header(&self) -> String {
+        String::from("I seguenti dati su di te sono salvati sul server:")
+    }
+
+    fn no_data(&self) -> String {
+        String::from("Al momento non ci sono dati salvati su di te sul server.")
+    }
+
+    fn random_reading(&self) -> String {
+        String::from("Ecco un brano a caso:")
+    }
+
+    fn button_listen_old_testament(&self) -> String {
+        String::from("🔊 Ascolta (AT)")
+    }
+
+    fn button_listen_new_testament(&self) -> String {
+        String::from("🔊 Ascolta (NT)")
+    }
+
+    fn about(&self, version: &str, commit: &str, languages: &str) -> String {
+        format!("DailyBible {} (commit {})\nLingue: {}\nCodice sorgente:", version, commit, languages)
+    }
+
+    fn available_plans(&self, plans: &str, current: &str) -> String {
+        format!("Sono disponibili i seguenti piani di lettura: {}\nIl tuo piano attuale è {}. Per cambiarlo, usa /setplan seguito dal nome del piano.", plans, current)
+    }
+
+    fn plan_set(&self, plan: &str) -> String {
+        format!("Le tue letture ora provengono dal piano di lettura {}.", plan)
+    }
+
+    fn error_unknown_plan(&self, plan: &str) -> String {
+        format!("Non esiste nessun piano di lettura chiamato {}.", plan)
+    }
+
+    fn member_since(&self, date: &str, days: i64) -> String {
+        format!("Membro dal {} ({} giorni).", date, days)
+    }
+
+    fn member_since_unknown(&self) -> String {
+        String::from("Membro da una data sconosciuta (registrato prima che venisse salvata).")
+    }
+
+    fn private_chat(&self) -> String {
+        String::from("Questa è una chat privata.")
+    }
+
+    fn group_chat_enabled(&self) -> String {
+        String::from("Questa è una chat di gruppo, i promemoria sono attivati.")
+    }
+
+    fn group_chat_not_enabled(&self) -> String {
+        String::from("Questa è una chat di gruppo, i promemoria non sono ancora attivati (usa /enable).")
+    }
+}
@@ -0,0 +1,414 @@
+//! In this unit, the Portuguese messages of the bot are implemented.
+
+use chrono::{Datelike, NaiveDate, NaiveTime};
+
+use crate::biblereading::BibleReading;
+
+use super::{format_reading, Localizer, PollText};
+
+/// The names of the weekdays, starting with Monday
+const WEEKDAYS: [&str; 7] = ["segunda-feira", "terça-feira", "quarta-feira", "quinta-feira", "sexta-feira", "sábado", "domingo"];
+
+/// The names of the months, starting with January
+const MONTHS: [&str; 12] = ["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"];
+
+pub struct Portuguese;
+
+impl Localizer for Portuguese {
+    fn biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "*📖 Este é um lembrete para ler a Bíblia hoje*:\n\nAT: {}\nNT: {}",
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn verse_of_the_day(&self) -> String {
+        String::from("✨ Versículo do dia")
+    }
+
+    fn next_biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "*📖 A leitura de amanhã*:\n\nAT: {}\nNT: {}",
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn next_biblereading_not_found(&self) -> String {
+        "Não há nenhuma leitura bíblica programada para amanhã.".to_string()
+    }
+
+    fn biblereading_not_found(&self) -> String {
+        "Este é um lembrete para ler a Bíblia hoje.".to_string()
+    }
+
+    fn button_mark_read(&self) -> String {
+        String::from("✅ Marcar como lido")
+    }
+
+    fn button_snooze(&self) -> String {
+        String::from("⏰ Lembre-me mais tarde")
+    }
+
+    fn marked_as_read(&self, streak: u32) -> String {
+        format!("Muito bem! Você leu a Bíblia {} dia(s) seguidos.", streak)
+    }
+
+    fn poll_stale(&self) -> String {
+        String::from("Esta enquete pertence a um lembrete anterior, por isso a sua resposta não é contada. Por favor, responda à enquete do lembrete de hoje.")
+    }
+
+    fn snoozed(&self, time: &NaiveTime) -> String {
+        format!("Vou lembrá-lo novamente às {}.", time.format("%H:%M"))
+    }
+
+    fn language_set(&self) -> String {
+        "O idioma foi alterado para português.".to_string()
+    }
+
+    fn poll(&self) -> PollText {
+        PollText {
+            question: String::from("Você leu a Bíblia hoje?"),
+            yes: String::from("Sim"),
+            no: String::from("Não"),
+        }
+    }
+
+    fn not_implemented_yet(&self) -> String {
+        "Esta função ainda não foi implementada.".to_string()
+    }
+
+    fn select_language(&self) -> String {
+        String::from("Por favor, escolha o idioma que deseja configurar.")
+    }
+
+    fn timer_updated(&self, time: &NaiveTime) -> String {
+        format!("O lembrete diário foi configurado para as {}.", time.format("%H:%M"))
+    }
+
+    fn timer_unset(&self) -> String {
+        String::from("O lembrete diário foi desativado")
+    }
+
+    fn error_timer_update(&self) -> String {
+        String::from("O formato não é válido. Por favor, use a função com um horário válido (por exemplo /settimer 08:00).")
+    }
+
+    fn biblereading_not_found_for_date(&self, date_string: &str) -> String {
+        format!("Não há nenhuma leitura bíblica programada para {}.", date_string)
+    }
+
+    fn error_invalid_date(&self) -> String {
+        String::from("A data não é válida. Por favor, use a função com uma data válida (por exemplo /readingfor 09-01).")
+    }
+
+    fn data_deleted(&self) -> String {
+        String::from("Todos os seus dados foram apagados.")
+    }
+
+    fn no_data_to_delete(&self) -> String {
+        String::from("Não há dados guardados sobre você, por isso nada foi apagado.")
+    }
+
+    fn bible_version_set(&self, version: &str) -> String {
+        format!("As leituras serão ligadas à versão da Bíblia {}.", version)
+    }
+
+    fn button_marked_read(&self) -> String {
+        String::from("✅ Lido")
+    }
+
+    fn read_button_enabled(&self) -> String {
+        String::from("O lembrete diário agora é confirmado com o botão \"Marcar como lido\" em vez de uma enquete.")
+    }
+
+    fn read_button_disabled(&self) -> String {
+        String::from("Depois do lembrete diário volta a ser enviada uma enquete.")
+    }
+
+    fn error_read_button(&self) -> String {
+        String::from("Por favor, use /readbutton on ou /readbutton off.")
+    }
+
+    fn bible_version_unset(&self) -> String {
+        String::from("Os links para as leituras foram desativados.")
+    }
+
+    fn error_bible_version(&self) -> String {
+        String::from("A versão da Bíblia não é válida. Por favor, use a sua abreviatura, por exemplo /setversion ARC.")
+    }
+
+    fn paused(&self) -> String {
+        String::from("Os lembretes diários estão em pausa. O seu horário é mantido, use /resume para continuar.")
+    }
+
+    fn resumed(&self) -> String {
+        String::from("Os lembretes diários estão ativos novamente.")
+    }
+
+    fn status(&self, timer: &Option<NaiveTime>, paused: bool) -> String {
+        let timer_string = match timer {
+            Some(time) => format!("O lembrete diário está configurado para as {}.", time.format("%H:%M")),
+            None => String::from("Não há nenhum lembrete diário configurado."),
+        };
+        let paused_string = if paused { "Os lembretes estão em pausa." } else { "Os lembretes estão ativos." };
+        format!("{}\n{}", timer_string, paused_string)
+    }
+
+    fn feedback_sent(&self) -> String {
+        String::from("Obrigado pelo seu comentário! Ele foi enviado ao operador do bot.")
+    }
+
+    fn feedback_unavailable(&self) -> String {
+        String::from("Lamentamos, este bot não aceita comentários.")
+    }
+
+    fn error_feedback_empty(&self) -> String {
+        String::from("Por favor, escreva o seu comentário depois do comando, por exemplo /feedback Obrigado por este bot!")
+    }
+
+    fn group_enabled(&self) -> String {
+        String::from("Os lembretes diários estão agora ativados para este grupo.")
+    }
+
+    fn group_not_enabled(&self) -> String {
+        String::from("Nos grupos, os lembretes diários só são enviados depois de ativá-los com /enable.")
+    }
+
+    fn enable_private_chat(&self) -> String {
+        String::from("Nos chats privados, os lembretes diários estão sempre ativados.")
+    }
+
+    fn plan_started(&self) -> String {
+        String::from("O seu plano de leitura pessoal começa hoje. A partir de agora você receberá as leituras do plano uma após a outra, use /startplan off para seguir o calendário novamente.")
+    }
+
+    fn plan_stopped(&self) -> String {
+        String::from("As leituras voltam a seguir o calendário.")
+    }
+
+    fn week_heading(&self) -> String {
+        String::from("📅 As leituras dos próximos sete dias:")
+    }
+
+    fn week_day(&self, date_string: &str, biblereading: &BibleReading) -> String {
+        format!("{}: AT {} | NT {}", date_string, biblereading.old_testament_reading(), biblereading.new_testament_reading())
+    }
+
+    fn week_day_not_found(&self, date_string: &str) -> String {
+        format!("{}: nenhuma leitura programada", date_string)
+    }
+
+    fn skipped_today(&self) -> String {
+        String::from("Hoje você não receberá nenhum lembrete. Amanhã os lembretes continuam como sempre.")
+    }
+
+    fn psalm_label(&self) -> String {
+        String::from("Salmo")
+    }
+
+    fn wisdom_label(&self) -> String {
+        String::from("Sabedoria")
+    }
+
+    fn no_data_to_export(&self) -> String {
+        String::from("Não há dados guardados sobre você, por isso não há nada para exportar.")
+    }
+
+    fn data_imported(&self) -> String {
+        String::from("Os seus dados foram importados.")
+    }
+
+    fn error_import_invalid(&self) -> String {
+        String::from("Não foi possível importar o arquivo. Por favor, envie um arquivo JSON criado com /export.")
+    }
+
+    fn error_import_no_document(&self) -> String {
+        String::from("Por favor, responda com /importdata ao arquivo JSON criado com /export, ou envie o arquivo com /importdata como legenda.")
+    }
+
+    fn date(&self, date: &NaiveDate) -> String {
+        format!("{}, {} de {}", WEEKDAYS[date.weekday().num_days_from_monday() as usize], date.day(), MONTHS[date.month0() as usize])
+    }
+
+    fn error_poll(&self) -> String {
+        String::from("Por favor, use /poll on ou /poll off.")
+    }
+
+    fn anonymous_poll_enabled(&self) -> String {
+        String::from("A enquete depois do lembrete diário agora é anônima. As respostas anônimas não contam para a sua sequência.")
+    }
+
+    fn anonymous_poll_disabled(&self) -> String {
+        String::from("A enquete depois do lembrete diário já não é anônima (as enquetes em grupos são sempre anônimas).")
+    }
+
+    fn error_anonymous_poll(&self) -> String {
+        String::from("Por favor, use /pollanon on ou /pollanon off.")
+    }
+
+    fn history_heading(&self, read_days: usize, days: usize) -> String {
+        format!("📊 As suas leituras dos últimos {} dias ({} de {} lidas):", days, read_days, days)
+    }
+
+    fn verse_text_unavailable(&self) -> String {
+        String::from("Não foi possível carregar o texto da passagem, mas você pode lê-lo aqui:")
+    }
+
+    fn error_verse_not_found(&self, reference: &str) -> String {
+        format!("A passagem {} não foi encontrada. Por favor, verifique a referência.", reference)
+    }
+
+    fn error_verse_reference(&self) -> String {
+        String::from("Por favor, indique uma referência bíblica, por exemplo /verse João 3,16.")
+    }
+
+    fn error_snooze_until(&self) -> String {
+        String::from("Por favor, use a função com um horário posterior de hoje (por exemplo /snoozeuntil 20:00).")
+    }
+
+    fn reading_lines(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "AT: {}\nNT: {}",
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn custom_prefix_set(&self) -> String {
+        String::from("Os seus lembretes agora começam com a sua própria mensagem. Use /setmessage sem texto para voltar à mensagem padrão.")
+    }
+
+    fn custom_prefix_unset(&self) -> String {
+        String::from("Os seus lembretes voltam a começar com a mensagem padrão.")
+    }
+
+    fn error_custom_prefix(&self, max_length: usize) -> String {
+        format!("A sua mensagem é demasiado longa. Por favor, use no máximo {} caracteres, por exemplo /setmessage Bom dia! Hora de ler:", max_length)
+    }
+
+    fn markdown_enabled(&self) -> String {
+        String::from("Os seus lembretes voltam a ter formatação (texto em negrito e links).")
+    }
+
+    fn markdown_disabled(&self) -> String {
+        String::from("Os seus lembretes agora são enviados como texto simples sem formatação.")
+    }
+
+    fn error_markdown(&self) -> String {
+        String::from("Por favor, use /markdown on ou /markdown off.")
+    }
+
+    fn weekly_summary(&self, read_days: usize) -> String {
+        format!("📅 A sua semana: você leu a Bíblia em {} de 7 dias. Continue assim!", read_days)
+    }
+
+    fn weekly_summary_enabled(&self) -> String {
+        String::from("Todos os domingos você receberá um resumo da sua semana junto com o lembrete.")
+    }
+
+    fn weekly_summary_disabled(&self) -> String {
+        String::from("Você já não receberá um resumo semanal.")
+    }
+
+    fn error_weekly_summary(&self) -> String {
+        String::from("Por favor, use /weeklysummary on ou /weeklysummary off.")
+    }
+
+    fn confirm_delete_data(&self) -> String {
+        String::from("Quer mesmo apagar todos os dados guardados sobre este chat? Não é possível desfazer.")
+    }
+
+    fn button_delete_confirm(&self) -> String {
+        String::from("🗑 Sim, apagar")
+    }
+
+    fn button_delete_cancel(&self) -> String {
+        String::from("Cancelar")
+    }
+
+    fn deletion_cancelled(&self) -> String {
+        String::from("Os seus dados não foram apagados.")
+    }
+
+    fn deletion_expired(&self) -> String {
+        String::from("A confirmação expirou, os seus dados não foram apagados. Por favor, use /deletemydata novamente.")
+    }
+
+    fn next_reminder_today(&self, time: &NaiveTime) -> String {
+        format!("O seu próximo lembrete é hoje às {}.", time.format("%H:%M"))
+    }
+
+    fn next_reminder_tomorrow(&self, time: &NaiveTime) -> String {
+        format!("O seu próximo lembrete é amanhã às {}.", time.format("%H:%M"))
+    }
+
+    fn next_reminder_on(&self, date: &str, time: &NaiveTime) -> String {
+        format!("O seu próximo lembrete é {} às {}.", date, time.format("%H:%M"))
+    }
+
+    fn next_reminder_no_timer(&self) -> String {
+        String::from("Não há nenhum horário diário, por isso você não receberá lembretes. Use /settimer para configurar um.")
+    }
+
+    fn next_reminder_paused(&self) -> String {
+        String::from("Os seus lembretes estão em pausa. Use /resume para recebê-los novamente.")
+    }
+
+    fn user_information_header(&self) -> String {
+        String::from("Os seguintes dados sobre você estão guardados no servidor:")
+    }
+
+    fn no_data(&self) -> String {
+        String::from("Atualmente não há dados guardados sobre você no servidor.")
+    }
+
+    fn random_reading(&self) -> String {
+        String::from("Aqui está uma passagem aleatória:")
+    }
+
+    fn button_listen_old_testament(&self) -> String {
+        String::from("🔊 Ouvir (AT)")
+    }
+
+    fn button_listen_new_testament(&self) -> String {
+        String::from("🔊 Ouvir (NT)")
+    }
+
+    fn about(&self, version: &str, commit: &str, languages: &str) -> String {
+        format!("DailyBible {} (commit {})\nIdiomas: {}\nCódigo-fonte:", version, commit, languages)
+    }
+
+    fn available_plans(&self, plans: &str, current: &str) -> String {
+        format!("Os seguintes planos de leitura estão disponíveis: {}\nO seu plano atual é {}. Para alterá-lo, use /setplan seguido do nome do plano.", plans, current)
+    }
+
+    fn plan_set(&self, plan: &str) -> String {
+        format!("As suas leituras agora vêm do plano de leitura {}.", plan)
+    }
+
+    fn error_unknown_plan(&self, plan: &str) -> String {
+        format!("Não existe nenhum plano de leitura chamado {}.", plan)
+    }
+
+    fn member_since(&self, date: &str, days: i64) -> String {
+        format!("Membro desde {} ({} dias).", date, days)
+    }
+
+    fn member_since_unknown(&self) -> String {
+        String::from("Membro desde uma data desconhecida (registrado antes de isso ser guardado).")
+    }
+
+    fn private_chat(&self) -> String {
+        String::from("Este é um chat privado.")
+    }
+
+    fn group_chat_enabled(&self) -> String {
+        String::from("Este é um chat de grupo, os lembretes estão ativados.")
+    }
+
+    fn group_chat_not_enabled(&self) -> String {
+        String::from("Este é um chat de grupo, os lembretes ainda não estão ativados (use /enable).")
+    }
+}
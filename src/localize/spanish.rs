@@ -0,0 +1,414 @@
+//! In this unit, the Spanish messages of the bot are implemented.
+
+use chrono::{Datelike, NaiveDate, NaiveTime};
+
+use crate::biblereading::BibleReading;
+
+use super::{format_reading, Localizer, PollText};
+
+/// The names of the weekdays, starting with Monday
+const WEEKDAYS: [&str; 7] = ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"];
+
+/// The names of the months, starting with January
+const MONTHS: [&str; 12] = ["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"];
+
+pub struct Spanish;
+
+impl Localizer for Spanish {
+    fn biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "*📖 Este es un recordatorio para leer la Biblia hoy*:\n\nAT: {}\nNT: {}", 
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn verse_of_the_day(&self) -> String {
+        String::from("✨ Versículo del día")
+    }
+
+    fn next_biblereading(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "*📖 La lectura de mañana*:\n\nAT: {}\nNT: {}", 
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn next_biblereading_not_found(&self) -> String {
+        "No hay ninguna lectura bíblica programada para mañana.".to_string()
+    }
+
+    fn biblereading_not_found(&self) -> String {
+        "Este es un recordatorio para leer la Biblia hoy.".to_string()
+    }
+
+    fn button_mark_read(&self) -> String {
+        String::from("✅ Marcar como leído")
+    }
+
+    fn button_snooze(&self) -> String {
+        String::from("⏰ Recuérdame más tarde")
+    }
+
+    fn marked_as_read(&self, streak: u32) -> String {
+        format!("¡Bien hecho! Has leído la Biblia {} día(s) seguidos.", streak)
+    }
+
+    fn poll_stale(&self) -> String {
+        String::from("Esta encuesta pertenece a un recordatorio anterior, por lo que tu respuesta no se cuenta. Por favor, responde la encuesta del recordatorio de hoy.")
+    }
+
+    fn snoozed(&self, time: &NaiveTime) -> String {
+        format!("Te lo recordaré de nuevo a las {}.", time.format("%H:%M"))
+    }
+
+    fn language_set(&self) -> String {
+        "El idioma se ha cambiado a español.".to_string()
+    }
+
+    fn poll(&self) -> PollText {
+        PollText {
+            question: String::from("¿Has leído la Biblia hoy?"),
+            yes: String::from("Sí"),
+            no: String::from("No"),
+        }
+    }
+
+    fn not_implemented_yet(&self) -> String {
+        "Esta función aún no se ha implementado.".to_string()
+    }
+
+    fn select_language(&self) -> String {
+        String::from("Por favor, elige el idioma que deseas configurar.")
+    }
+
+    fn timer_updated(&self, time: &NaiveTime) -> String {
+        format!("El recordatorio diario se ha configurado a las {}.", time.format("%H:%M"))
+    }
+
+    fn timer_unset(&self) -> String {
+        String::from("El recordatorio diario se ha desactivado")
+    }
+
+    fn error_timer_update(&self) -> String {
+        String::from("El formato no es válido. Por favor, usa la función con una hora válida (por ejemplo /settimer 08:00).")
+    }
+
+    fn biblereading_not_found_for_date(&self, date_string: &str) -> String {
+        format!("No hay ninguna lectura bíblica programada para el {}.", date_string)
+    }
+
+    fn error_invalid_date(&self) -> String {
+        String::from("La fecha no es válida. Por favor, usa la función con una fecha válida (por ejemplo /readingfor 09-01).")
+    }
+
+    fn data_deleted(&self) -> String {
+        String::from("Se han eliminado todos tus datos.")
+    }
+
+    fn no_data_to_delete(&self) -> String {
+        String::from("No hay datos guardados sobre ti, así que no se ha eliminado nada.")
+    }
+
+    fn bible_version_set(&self, version: &str) -> String {
+        format!("Las lecturas se enlazarán con la versión de la Biblia {}.", version)
+    }
+
+    fn button_marked_read(&self) -> String {
+        String::from("✅ Leído")
+    }
+
+    fn read_button_enabled(&self) -> String {
+        String::from("El recordatorio diario ahora se confirma con el botón \"Marcar como leído\" en lugar de una encuesta.")
+    }
+
+    fn read_button_disabled(&self) -> String {
+        String::from("Después del recordatorio diario se vuelve a enviar una encuesta.")
+    }
+
+    fn error_read_button(&self) -> String {
+        String::from("Por favor, usa /readbutton on o /readbutton off.")
+    }
+
+    fn bible_version_unset(&self) -> String {
+        String::from("Los enlaces a las lecturas se han desactivado.")
+    }
+
+    fn error_bible_version(&self) -> String {
+        String::from("La versión de la Biblia no es válida. Por favor, usa su abreviatura, por ejemplo /setversion RVR1960.")
+    }
+
+    fn paused(&self) -> String {
+        String::from("Los recordatorios diarios están en pausa. Tu hora se conserva, usa /resume para continuar.")
+    }
+
+    fn resumed(&self) -> String {
+        String::from("Los recordatorios diarios vuelven a estar activos.")
+    }
+
+    fn status(&self, timer: &Option<NaiveTime>, paused: bool) -> String {
+        let timer_string = match timer {
+            Some(time) => format!("El recordatorio diario está configurado a las {}.", time.format("%H:%M")),
+            None => String::from("No hay ningún recordatorio diario configurado."),
+        };
+        let paused_string = if paused { "Los recordatorios están en pausa." } else { "Los recordatorios están activos." };
+        format!("{}\n{}", timer_string, paused_string)
+    }
+
+    fn feedback_sent(&self) -> String {
+        String::from("¡Gracias por tus comentarios! Se han enviado al operador del bot.")
+    }
+
+    fn feedback_unavailable(&self) -> String {
+        String::from("Lo sentimos, este bot no admite comentarios.")
+    }
+
+    fn error_feedback_empty(&self) -> String {
+        String::from("Por favor, escribe tus comentarios después del comando, por ejemplo /feedback ¡Gracias por este bot!")
+    }
+
+    fn group_enabled(&self) -> String {
+        String::from("Los recordatorios diarios ya están activados para este grupo.")
+    }
+
+    fn group_not_enabled(&self) -> String {
+        String::from("En los grupos, los recordatorios diarios solo se envían después de activarlos con /enable.")
+    }
+
+    fn enable_private_chat(&self) -> String {
+        String::from("En los chats privados, los recordatorios diarios siempre están activados.")
+    }
+
+    fn plan_started(&self) -> String {
+        String::from("Tu plan de lectura personal empieza hoy. A partir de ahora recibirás las lecturas del plan una tras otra, usa /startplan off para seguir el calendario de nuevo.")
+    }
+
+    fn plan_stopped(&self) -> String {
+        String::from("Las lecturas vuelven a seguir el calendario.")
+    }
+
+    fn week_heading(&self) -> String {
+        String::from("📅 Las lecturas de los próximos siete días:")
+    }
+
+    fn week_day(&self, date_string: &str, biblereading: &BibleReading) -> String {
+        format!("{}: AT {} | NT {}", date_string, biblereading.old_testament_reading(), biblereading.new_testament_reading())
+    }
+
+    fn week_day_not_found(&self, date_string: &str) -> String {
+        format!("{}: ninguna lectura programada", date_string)
+    }
+
+    fn skipped_today(&self) -> String {
+        String::from("Hoy no recibirás ningún recordatorio. Mañana los recordatorios continúan como siempre.")
+    }
+
+    fn psalm_label(&self) -> String {
+        String::from("Salmo")
+    }
+
+    fn wisdom_label(&self) -> String {
+        String::from("Sabiduría")
+    }
+
+    fn no_data_to_export(&self) -> String {
+        String::from("No hay datos guardados sobre ti, así que no hay nada que exportar.")
+    }
+
+    fn data_imported(&self) -> String {
+        String::from("Tus datos han sido importados.")
+    }
+
+    fn error_import_invalid(&self) -> String {
+        String::from("No se pudo importar el archivo. Por favor, envía un archivo JSON creado con /export.")
+    }
+
+    fn error_import_no_document(&self) -> String {
+        String::from("Por favor, responde con /importdata al archivo JSON creado con /export, o envía el archivo con /importdata como descripción.")
+    }
+
+    fn date(&self, date: &NaiveDate) -> String {
+        format!("{}, {} de {}", WEEKDAYS[date.weekday().num_days_from_monday() as usize], date.day(), MONTHS[date.month0() as usize])
+    }
+
+    fn error_poll(&self) -> String {
+        String::from("Por favor, usa /poll on o /poll off.")
+    }
+
+    fn anonymous_poll_enabled(&self) -> String {
+        String::from("La encuesta después del recordatorio diario ahora es anónima. Las respuestas anónimas no se cuentan para tu racha.")
+    }
+
+    fn anonymous_poll_disabled(&self) -> String {
+        String::from("La encuesta después del recordatorio diario ya no es anónima (las encuestas en grupos siempre son anónimas).")
+    }
+
+    fn error_anonymous_poll(&self) -> String {
+        String::from("Por favor, usa /pollanon on o /pollanon off.")
+    }
+
+    fn history_heading(&self, read_days: usize, days: usize) -> String {
+        format!("📊 Tus lecturas de los últimos {} días ({} de {} leídas):", days, read_days, days)
+    }
+
+    fn verse_text_unavailable(&self) -> String {
+        String::from("No se pudo cargar el texto del pasaje, pero puedes leerlo aquí:")
+    }
+
+    fn error_verse_not_found(&self, reference: &str) -> String {
+        format!("No se encontró el pasaje {}. Por favor, revisa la referencia.", reference)
+    }
+
+    fn error_verse_reference(&self) -> String {
+        String::from("Por favor, indica una referencia bíblica, por ejemplo /verse Juan 3,16.")
+    }
+
+    fn error_snooze_until(&self) -> String {
+        String::from("Por favor, usa la función con una hora posterior de hoy (por ejemplo /snoozeuntil 20:00).")
+    }
+
+    fn reading_lines(&self, biblereading: &BibleReading, bible_version: Option<&str>) -> String {
+        format!(
+            "AT: {}\nNT: {}",
+            format_reading(&biblereading.old_testament_reading(), bible_version),
+            format_reading(&biblereading.new_testament_reading(), bible_version)
+        )
+    }
+
+    fn custom_prefix_set(&self) -> String {
+        String::from("Tus recordatorios ahora empiezan con tu propio mensaje. Usa /setmessage sin texto para volver al mensaje predeterminado.")
+    }
+
+    fn custom_prefix_unset(&self) -> String {
+        String::from("Tus recordatorios vuelven a empezar con el mensaje predeterminado.")
+    }
+
+    fn error_custom_prefix(&self, max_length: usize) -> String {
+        format!("Tu mensaje es demasiado largo. Por favor, usa como máximo {} caracteres, por ejemplo /setmessage ¡Buenos días! Hora de leer:", max_length)
+    }
+
+    fn markdown_enabled(&self) -> String {
+        String::from("Tus recordatorios vuelven a tener formato (texto en negrita y enlaces).")
+    }
+
+    fn markdown_disabled(&self) -> String {
+        String::from("Tus recordatorios ahora se envían como texto simple sin formato.")
+    }
+
+    fn error_markdown(&self) -> String {
+        String::from("Por favor, usa /markdown on o /markdown off.")
+    }
+
+    fn weekly_summary(&self, read_days: usize) -> String {
+        format!("📅 Tu semana: has leído la Biblia {} de 7 días. ¡Sigue así!", read_days)
+    }
+
+    fn weekly_summary_enabled(&self) -> String {
+        String::from("Cada domingo recibirás un resumen de tu semana junto con el recordatorio.")
+    }
+
+    fn weekly_summary_disabled(&self) -> String {
+        String::from("Ya no recibirás un resumen semanal.")
+    }
+
+    fn error_weekly_summary(&self) -> String {
+        String::from("Por favor, usa /weeklysummary on o /weeklysummary off.")
+    }
+
+    fn confirm_delete_data(&self) -> String {
+        String::from("¿De verdad quieres borrar todos los datos guardados sobre este chat? No se puede deshacer.")
+    }
+
+    fn button_delete_confirm(&self) -> String {
+        String::from("🗑 Sí, borrar")
+    }
+
+    fn button_delete_cancel(&self) -> String {
+        String::from("Cancelar")
+    }
+
+    fn deletion_cancelled(&self) -> String {
+        String::from("Tus datos no han sido borrados.")
+    }
+
+    fn deletion_expired(&self) -> String {
+        String::from("La confirmación ha caducado, tus datos no han sido borrados. Por favor, usa /deletemydata de nuevo.")
+    }
+
+    fn next_reminder_today(&self, time: &NaiveTime) -> String {
+        format!("Tu próximo recordatorio es hoy a las {}.", time.format("%H:%M"))
+    }
+
+    fn next_reminder_tomorrow(&self, time: &NaiveTime) -> String {
+        format!("Tu próximo recordatorio es mañana a las {}.", time.format("%H:%M"))
+    }
+
+    fn next_reminder_on(&self, date: &str, time: &NaiveTime) -> String {
+        format!("Tu próximo recordatorio es el {} a las {}.", date, time.format("%H:%M"))
+    }
+
+    fn next_reminder_no_timer(&self) -> String {
+        String::from("No hay ningún temporizador diario, así que no recibirás recordatorios. Usa /settimer para configurar uno.")
+    }
+
+    fn next_reminder_paused(&self) -> String {
+        String::from("Tus recordatorios están en pausa. Usa /resume para recibirlos de nuevo.")
+    }
+
+    fn user_information_header(&self) -> String {
+        String::from("Los siguientes datos sobre ti están guardados en el servidor:")
+    }
+
+    fn no_data(&self) -> String {
+        String::from("Actualmente no hay datos guardados sobre ti en el servidor.")
+    }
+
+    fn random_reading(&self) -> String {
+        String::from("Aquí tienes un pasaje al azar:")
+    }
+
+    fn button_listen_old_testament(&self) -> String {
+        String::from("🔊 Escuchar (AT)")
+    }
+
+    fn button_listen_new_testament(&self) -> String {
+        String::from("🔊 Escuchar (NT)")
+    }
+
+    fn about(&self, version: &str, commit: &str, languages: &str) -> String {
+        format!("DailyBible {} (commit {})\nIdiomas: {}\nCódigo fuente:", version, commit, languages)
+    }
+
+    fn available_plans(&self, plans: &str, current: &str) -> String {
+        format!("Están disponibles los siguientes planes de lectura: {}\nTu plan actual es {}. Para cambiarlo, usa /setplan seguido del nombre del plan.", plans, current)
+    }
+
+    fn plan_set(&self, plan: &str) -> String {
+        format!("Tus lecturas ahora se toman del plan de lectura {}.", plan)
+    }
+
+    fn error_unknown_plan(&self, plan: &str) -> String {
+        format!("No existe ningún plan de lectura llamado {}.", plan)
+    }
+
+    fn member_since(&self, date: &str, days: i64) -> String {
+        format!("Miembro desde {} ({} días).", date, days)
+    }
+
+    fn member_since_unknown(&self) -> String {
+        String::from("Miembro desde una fecha desconocida (registrado antes de que se guardara).")
+    }
+
+    fn private_chat(&self) -> String {
+        String::from("Este es un chat privado.")
+    }
+
+    fn group_chat_enabled(&self) -> String {
+        String::from("Este es un chat de grupo, los recordatorios están activados.")
+    }
+
+    fn group_chat_not_enabled(&self) -> String {
+        String::from("Este es un chat de grupo, los recordatorios aún no están activados (usa /enable).")
+    }
+}
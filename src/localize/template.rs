@@ -0,0 +1,135 @@
+//! In this unit, the optional reminder templates are loaded, with which operators can change the wording of the
+//! daily reminder per language without recompiling the bot (for example `REMINDER_TEMPLATE_EN`).
+//! The placeholders `{ot}`, `{nt}` and `{date}` are replaced with the readings and the date of the reminder.
+
+use std::{env, sync::OnceLock};
+
+use teloxide::utils::markdown::escape;
+
+use super::Language;
+
+/// The prefix of the environment variables with the reminder templates, followed by the upper case locale id
+/// (`REMINDER_TEMPLATE_EN`, `REMINDER_TEMPLATE_DE`, `REMINDER_TEMPLATE_ES`, `REMINDER_TEMPLATE_PT`, `REMINDER_TEMPLATE_IT`)
+pub const REMINDER_TEMPLATE_ENV_PREFIX: &str = "REMINDER_TEMPLATE_";
+
+/// The placeholder of the Old Testament reading
+const OLD_TESTAMENT_PLACEHOLDER: &str = "{ot}";
+
+/// The placeholder of the New Testament reading
+const NEW_TESTAMENT_PLACEHOLDER: &str = "{nt}";
+
+/// The placeholder of the date of the reading
+const DATE_PLACEHOLDER: &str = "{date}";
+
+/// The placeholders which every template has to contain, as the reminder would be useless without the readings
+const REQUIRED_PLACEHOLDERS: [&str; 2] = [OLD_TESTAMENT_PLACEHOLDER, NEW_TESTAMENT_PLACEHOLDER];
+
+/// The valid reminder templates of all languages which have been loaded at startup
+static REMINDER_TEMPLATES: OnceLock<Vec<(Language, String)>> = OnceLock::new();
+
+
+/// Returns the name of the environment variable with the reminder template of the given language.
+fn template_env_name(language: &Language) -> String {
+    format!("{}{}", REMINDER_TEMPLATE_ENV_PREFIX, language.locale_id().to_uppercase())
+}
+
+/// Returns the required placeholders which are missing in the template.
+fn missing_placeholders(template: &str) -> Vec<&'static str> {
+    REQUIRED_PLACEHOLDERS.iter()
+        .filter(|placeholder| !template.contains(*placeholder))
+        .copied()
+        .collect()
+}
+
+/// Loads the reminder templates of all languages from the environment variables.
+/// A template without all required placeholders is ignored with a warning, so the built-in reminder is used instead.
+pub fn load_reminder_templates() {
+    let mut templates = Vec::new();
+    for language in Language::ALL {
+        let env_name = template_env_name(&language);
+        let template = match env::var(&env_name) {
+            Ok(template) if !template.trim().is_empty() => template,
+            _ => continue,
+        };
+
+        let missing_placeholders = missing_placeholders(&template);
+        if missing_placeholders.is_empty() {
+            templates.push((language, template));
+        } else {
+            log::warn!("The template in {} does not contain {}, using the built-in reminder instead.", env_name, missing_placeholders.join(" and "));
+        }
+    }
+
+    if !templates.is_empty() {
+        log::info!("Loaded {} reminder template(s)", templates.len());
+    }
+    if REMINDER_TEMPLATES.set(templates).is_err() {
+        log::warn!("The reminder templates have already been loaded.");
+    }
+}
+
+/// Returns the reminder template of the language, or `None` if the built-in reminder is used.
+pub fn reminder_template(language: &Language) -> Option<&'static str> {
+    REMINDER_TEMPLATES.get()?.iter()
+        .find(|(template_language, _)| template_language == language)
+        .map(|(_, template)| template.as_str())
+}
+
+/// Renders a reminder template for MarkdownV2. The text of the template is escaped, while the placeholders are
+/// replaced with the readings and the date, which have to be formatted (and escaped) already.
+///
+/// # Params
+/// - `template`: The template with the placeholders `{ot}`, `{nt}` and `{date}`
+/// - `old_testament`: The formatted Old Testament reading
+/// - `new_testament`: The formatted New Testament reading
+/// - `date`: The formatted date of the reading
+///
+/// # Returns
+/// The reminder which can be sent with MarkdownV2.
+pub fn render_reminder_template(template: &str, old_testament: &str, new_testament: &str, date: &str) -> String {
+    let placeholders = [
+        (OLD_TESTAMENT_PLACEHOLDER, old_testament),
+        (NEW_TESTAMENT_PLACEHOLDER, new_testament),
+        (DATE_PLACEHOLDER, date),
+    ];
+
+    let mut message = String::new();
+    let mut rest = template;
+    // The text between the placeholders is escaped piece by piece, so that the substituted values are not escaped twice
+    while let Some((index, placeholder, value)) = placeholders.iter()
+        .filter_map(|(placeholder, value)| rest.find(placeholder).map(|index| (index, *placeholder, *value)))
+        .min_by_key(|(index, _, _)| *index)
+    {
+        message.push_str(&escape(&rest[..index]));
+        message.push_str(value);
+        rest = &rest[index + placeholder.len()..];
+    }
+    message.push_str(&escape(rest));
+    message
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_placeholders() {
+        assert!(missing_placeholders("Read {ot} and {nt} on {date}!").is_empty());
+        assert!(missing_placeholders("Read {ot} and {nt}").is_empty());
+        assert_eq!(missing_placeholders("Read {ot} today"), vec!["{nt}"]);
+        assert_eq!(missing_placeholders("Read the Bible"), vec!["{ot}", "{nt}"]);
+        assert_eq!(template_env_name(&Language::German), "REMINDER_TEMPLATE_DE");
+    }
+
+    #[test]
+    fn test_template_is_rendered() {
+        let message = render_reminder_template(
+            "Today ({date}): {ot} & {nt}. {ot} again!",
+            "[Gen 1](https://example.com)",
+            "[Mt 1](https://example.com)",
+            "Monday, 1 September"
+        );
+        assert_eq!(message, "Today \\(Monday, 1 September\\): [Gen 1](https://example.com) & [Mt 1](https://example.com)\\. [Gen 1](https://example.com) again\\!");
+    }
+}
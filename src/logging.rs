@@ -0,0 +1,125 @@
+//! In this unit, the logger is set up. By default the human-readable output of `pretty_env_logger` is used,
+//! with `LOG_FORMAT=json` every event is written as a single JSON object (for log aggregators), which contains
+//! the structured fields of the event (like `chat_id` or `status`) as separate keys.
+
+use std::{env, str::FromStr};
+
+use chrono::Utc;
+use log::{kv, LevelFilter, Log, Metadata, Record};
+use serde_json::{Map, Number, Value};
+
+/// The name of the environment variable which selects the log format (`json`, otherwise the human-readable format)
+pub const LOG_FORMAT_ENV: &str = "LOG_FORMAT";
+
+/// The name of the environment variable with the log level, which is shared with `pretty_env_logger`
+const LOG_LEVEL_ENV: &str = "RUST_LOG";
+
+
+/// Initializes the logger with the format which is selected with `LOG_FORMAT_ENV`.
+pub fn init_logging() {
+    if !env::var(LOG_FORMAT_ENV).is_ok_and(|format| format.eq_ignore_ascii_case("json")) {
+        pretty_env_logger::init();
+        return;
+    }
+
+    // In the JSON format only a single level like `info` is supported, filters per module are not
+    let level = env::var(LOG_LEVEL_ENV).ok()
+        .and_then(|level| LevelFilter::from_str(level.trim()).ok())
+        .unwrap_or(LevelFilter::Info);
+    if log::set_boxed_logger(Box::new(JsonLogger { level })).is_ok() {
+        log::set_max_level(level);
+    }
+}
+
+
+/// Writes every event as a single line of JSON to stderr
+struct JsonLogger {
+    level: LevelFilter,
+}
+
+impl Log for JsonLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("{}", format_record(record));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Formats an event as JSON object with the timestamp, the level, the target, the message and all structured fields.
+fn format_record(record: &Record) -> String {
+    let mut fields = FieldCollector(Map::new());
+    // Collecting the fields can not fail, as the collector never returns an error
+    let _ = record.key_values().visit(&mut fields);
+
+    let mut entry = Map::new();
+    entry.insert(String::from("timestamp"), Value::String(Utc::now().to_rfc3339()));
+    entry.insert(String::from("level"), Value::String(record.level().to_string()));
+    entry.insert(String::from("target"), Value::String(record.target().to_string()));
+    entry.insert(String::from("message"), Value::String(record.args().to_string()));
+    entry.extend(fields.0);
+    Value::Object(entry).to_string()
+}
+
+/// Collects the structured fields of an event as JSON values
+struct FieldCollector(Map<String, Value>);
+
+impl<'kvs> kv::VisitSource<'kvs> for FieldCollector {
+    fn visit_pair(&mut self, key: kv::Key<'kvs>, value: kv::Value<'kvs>) -> Result<(), kv::Error> {
+        self.0.insert(key.to_string(), to_json_value(&value));
+        Ok(())
+    }
+}
+
+/// Converts a field into a JSON value. Booleans and numbers keep their type, everything else is a string.
+fn to_json_value(value: &kv::Value) -> Value {
+    if let Some(boolean) = value.to_bool() {
+        Value::Bool(boolean)
+    } else if let Some(number) = value.to_i64() {
+        Value::Number(number.into())
+    } else if let Some(number) = value.to_u64() {
+        Value::Number(number.into())
+    } else if let Some(number) = value.to_f64().and_then(Number::from_f64) {
+        Value::Number(number)
+    } else {
+        Value::String(value.to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use log::Level;
+
+    use super::*;
+
+    #[test]
+    fn test_record_contains_structured_fields() {
+        let fields: [(&str, kv::Value); 3] = [
+            ("chat_id", kv::Value::from(-100123_i64)),
+            ("language", kv::Value::from("German")),
+            ("status", kv::Value::from("sent")),
+        ];
+        let line = format_record(
+            &Record::builder()
+                .args(format_args!("Reminder sent to {}", -100123))
+                .level(Level::Info)
+                .target("dailybible_rs")
+                .key_values(&fields)
+                .build()
+        );
+
+        let entry: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(entry["level"], "INFO");
+        assert_eq!(entry["message"], "Reminder sent to -100123");
+        assert_eq!(entry["chat_id"], -100123);
+        assert_eq!(entry["language"], "German");
+        assert_eq!(entry["status"], "sent");
+        assert!(entry["timestamp"].is_string());
+    }
+}
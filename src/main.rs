@@ -1,16 +1,36 @@
-use std::{ops::Deref, sync::Arc, time, env};
+use std::{env, path::Path, sync::Arc, time};
 
-use chrono::{NaiveTime, Timelike};
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime, Timelike, Utc};
 use localize::msg_biblereading_not_found;
-use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
-use teloxide::{ prelude::*, types::ParseMode::*, utils::command::BotCommands, RequestError };
+use rand::seq::SliceRandom;
+use teloxide::types::{Chat, InlineKeyboardButton, InlineKeyboardButtonKind, InputFile, InlineKeyboardMarkup, MaybeInaccessibleMessage, PollAnswer, ReplyParameters};
+use teloxide::{ net::Download, prelude::*, types::ParseMode::*, utils::{command::BotCommands, markdown::escape}, ApiError, RequestError };
 use tokio::signal;
+use tokio_util::sync::CancellationToken;
 
 mod biblereading;
+mod error;
 mod userstate;
 mod localize;
+mod userstore;
+mod retry;
+mod metrics;
+mod health;
+mod verses;
+mod notifier;
+mod bibletext;
+mod logging;
+mod throttle;
 use crate::localize::*;
 use crate::userstate::*;
+use crate::userstore::*;
+use crate::retry::send_with_retry;
+use crate::metrics::{Metrics, ReminderMetrics, TickMetrics};
+use crate::notifier::{LoggingNotifier, Notifier, TelegramNotifier};
+use crate::bibletext::{BibleTextClient, BibleTextError};
+use crate::throttle::SendLimiter;
+use crate::error::DailyBibleError;
+use crate::biblereading::AudioLinks;
 
 
 
@@ -20,6 +40,81 @@ const DEFAULT_USER_STATE_FILE_PATH: &str = "userstates.json";
 /// The name of the environment variable where the path of the user_state_file_path can be specified
 const USER_STATE_ENV: &str = "TELOXIDE_USERSTATEFILE";
 
+/// The default interval in seconds in which the user states are saved
+const DEFAULT_SAVE_INTERVAL_SECONDS: u64 = 30;
+
+/// The name of the environment variable where the interval (in seconds) for saving the user states can be specified
+const SAVE_INTERVAL_ENV: &str = "TELOXIDE_SAVEINTERVAL";
+
+/// The name of the environment variable where the ChatIds of the administrators can be specified (comma-separated)
+const ADMIN_CHAT_IDS_ENV: &str = "ADMIN_CHAT_IDS";
+
+/// The name of the environment variable where the ChatId of a single administrator can be specified
+const ADMIN_ID_ENV: &str = "DAILYBIBLE_ADMIN_ID";
+
+/// The name of the environment variable where the ChatId for the feedback of the users can be specified.
+/// If it is not set, the feedback is forwarded to the administrator in `DAILYBIBLE_ADMIN_ID`.
+const FEEDBACK_CHAT_ID_ENV: &str = "FEEDBACK_CHAT_ID";
+
+/// The name of the environment variable which enables the dry run (`DRY_RUN=1`), where the daily reminders
+/// are only logged instead of sent to Telegram
+const DRY_RUN_ENV: &str = "DRY_RUN";
+
+/// An alternative name of the environment variable which enables the dry run
+const DAILYBIBLE_DRY_RUN_ENV: &str = "DAILYBIBLE_DRY_RUN";
+
+/// The version of the bot which is shown by `/about`
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The git commit the bot has been built from, which is set by the build script
+const GIT_COMMIT: &str = env!("DAILYBIBLE_GIT_COMMIT");
+
+/// The URL of the source code which is linked by `/about`
+const REPOSITORY_URL: &str = env!("CARGO_PKG_REPOSITORY");
+
+/// The number of days which are shown by `/history`
+const HISTORY_DAYS: usize = 30;
+
+/// The start of the name of the JSON file which is sent by `/export`, followed by the chat id and the date
+const EXPORT_FILE_PREFIX: &str = "dailybible_data";
+
+/// The command which imports a file of `/export` when it is sent as caption of the file
+const IMPORT_COMMAND: &str = "/importdata";
+
+/// The maximum size of a file which is accepted by `/importdata` (an export is far smaller)
+const IMPORT_MAX_FILE_SIZE: u32 = 64 * 1024;
+
+/// The maximum length of a message which Telegram accepts (in UTF-16 code units)
+const TELEGRAM_MESSAGE_LIMIT: usize = 4096;
+
+/// The delay between two messages of a broadcast to avoid hitting the flood limits of Telegram
+const BROADCAST_DELAY: time::Duration = time::Duration::from_millis(300);
+
+/// The name of the environment variable where the port of the health check server can be specified.
+/// The server is only started if the port is set.
+const HEALTHCHECK_PORT_ENV: &str = "HEALTHCHECK_PORT";
+
+/// An alternative name of the environment variable where the port of the health check server can be specified
+const HEALTH_PORT_ENV: &str = "DAILYBIBLE_HEALTH_PORT";
+
+/// The number of minutes after which a snoozed reminder is sent again
+const SNOOZE_MINUTES: i64 = 30;
+
+/// The name of the file (next to the user state file) where the reminder counters per language are saved
+const REMINDER_METRICS_FILE_NAME: &str = "reminder_metrics.json";
+
+/// The interval in which the schedule file is checked for changes
+const SCHEDULE_CHECK_INTERVAL: time::Duration = time::Duration::from_secs(60);
+
+/// The name of the environment variable where the storage backend for the user states can be selected (`json` or `sqlite`)
+const USER_STORE_ENV: &str = "TELOXIDE_USERSTORE";
+
+/// The default url of the SQLite database where the user states will be saved if the SQLite backend is selected
+const DEFAULT_SQLITE_DATABASE_URL: &str = "sqlite://userstates.db";
+
+/// The name of the environment variable where the url of the SQLite database can be specified
+const SQLITE_DATABASE_ENV: &str = "TELOXIDE_SQLITEDATABASE";
+
 
 /// Here are all commands which the bot understands 
 #[derive(BotCommands, Clone)]
@@ -29,34 +124,120 @@ enum Command {
     Start,
     #[command(description="Send the daily reminder with the verses once")]
     SendDailyReminder,
-    #[command(description="Setup a daily timer for a given time (hh:mm)", parse_with="split")]
+    #[command(description="Show today's Bible reading")]
+    Today,
+    #[command(description="Show tomorrow's Bible reading")]
+    NextReading,
+    #[command(description="Show the text of a Bible passage, e.g. /verse John 3,16")]
+    Verse { reference: String },
+    #[command(description="Show the Bible readings of the next seven days")]
+    Week,
+    #[command(description="Show the Bible readings of the next seven days (same as /week)", hide)]
+    Schedule,
+    #[command(description="Show on which of the last 30 days you have read the Bible")]
+    History,
+    #[command(description="Receive a summary of your week every Sunday (on/off)", parse_with="split")]
+    WeeklySummary { setting: String },
+    #[command(description="Show the Bible reading of a random day of the schedule")]
+    Random,
+    #[command(description="Show the Bible reading for a given date (mm-dd)", parse_with="split")]
+    ReadingFor { date_string: String },
+    #[command(description="Show tomorrow's Bible reading (same as /nextreading)", hide)]
+    Tomorrow,
+    #[command(description="Show the Bible reading for a given date (same as /readingfor)", parse_with="split", hide)]
+    OnDate { date_string: String },
+    #[command(description="Setup a daily timer for a given time (for example 08:00, 8am or 20.00)")]
     SetTimer { timer_string: String },
+    #[command(description="Send the reminder once more at a later time today without changing the timer (for example 20:00)")]
+    SnoozeUntil { time_string: String },
     #[command(description="Unsets any set timer")]
     UnsetTimer,
+    #[command(description="Pause the daily reminders without unsetting the timer")]
+    Pause,
+    #[command(description="Resume the paused daily reminders")]
+    Resume,
+    #[command(description="Skip only today's reminder")]
+    SkipToday,
+    #[command(description="Enable the daily reminders in a group chat")]
+    Enable,
+    #[command(description="Show your timer and whether the reminders are paused")]
+    Status,
+    #[command(description="Show when you receive your next reminder")]
+    NextReminder,
     #[command(description="Show help message")]
     Help,
+    #[command(description="Show the version of the bot and a link to its source code")]
+    About,
     #[command(description="Send user/chat information (for debugging purposes)")]
     UserInformation,
     #[command(description="Setup the language", parse_with="split")]
-    SetLang { lang_string: String }
+    SetLang { lang_string: String },
+    #[command(description="Choose the reading plan, e.g. /setplan chronological (empty to list the plans)")]
+    SetPlan { plan: String },
+    #[command(description="Setup the Bible version for links to the readings, e.g. ESV (empty to disable)")]
+    SetVersion { version: String },
+    #[command(description="Start your reminders with your own message, e.g. /setmessage Good morning! (empty for the default)")]
+    SetMessage { text: String },
+    #[command(description="Confirm the daily reminder with a button instead of a poll (on/off)", parse_with="split")]
+    ReadButton { setting: String },
+    #[command(description="Send a poll after the daily reminder (on/off)", parse_with="split")]
+    Poll { setting: String },
+    #[command(description="Make the poll after the daily reminder anonymous (on/off)", parse_with="split")]
+    PollAnon { setting: String },
+    #[command(description="Format the reminders with bold text and links, or send them as plain text (on/off)", parse_with="split")]
+    Markdown { setting: String },
+    #[command(description="Start a personal reading plan from the first reading of the schedule today (off to follow the calendar)")]
+    StartPlan { setting: String },
+    #[command(description="Download all data which is saved about you as JSON file")]
+    Export,
+    #[command(description="Download all data which is saved about you (same as /export)", hide)]
+    ExportData,
+    #[command(description="Restore your data from a JSON file of /export (reply to the file)")]
+    ImportData,
+    #[command(description="Delete all data which is saved about you")]
+    DeleteMyData,
+    #[command(description="Delete all data which is saved about you (same as /deletemydata)", hide)]
+    DeleteData,
+    #[command(description="Send feedback to the operator of the bot")]
+    Feedback { text: String },
+    #[command(description="Show usage statistics (only for administrators)", hide)]
+    Stats,
+    #[command(description="Send a message to all users (only for administrators)", hide)]
+    Broadcast { text: String },
+    #[command(description="Show the number of sent reminders per language (only for administrators)", hide)]
+    Metrics,
+    #[command(description="Reload the schedule files after they have been changed (only for administrators)", hide)]
+    ReloadSchedule,
 }
 
 
 
 #[tokio::main]
 async fn main() {
-    pretty_env_logger::init();
+    logging::init_logging();
     log::info!("Starting DailyBible Bot...");
 
-    let user_state_wrapper: UserStateWrapper = UserStateWrapper::new();
+    // The messages are loaded from the .ftl files if they exist, otherwise the built-in messages are used
+    load_locales();
+    // Optional templates from the environment replace the wording of the daily reminder
+    load_reminder_templates();
 
-    // Check whether we can load the latest user_states from a file
-    let user_state_file = env::var(USER_STATE_ENV).unwrap_or(DEFAULT_USER_STATE_FILE_PATH.to_string());
-    match user_state_wrapper.load_states_from_file(&user_state_file).await {
+    // The JSON file has to be rewritten periodically, while the SQLite database persists every update directly
+    let user_store = create_user_store().await;
+    let save_periodically = !user_store.persists_single_updates();
+    let user_state_wrapper: UserStateWrapper = UserStateWrapper::new(user_store);
+
+    // Check whether we can load the latest user_states from the store
+    match user_state_wrapper.load_states().await {
         Ok(_) => log::info!("Previous user states successfully loaded."),
         Err(error) => log::warn!("Could not load previous user states: {}", error.to_string()),
     }
 
+    // The schedules of all reading plans are read once, the readings are looked up in memory afterwards
+    biblereading::load_plans();
+    // Check the whole schedule once, so that problems are not only discovered when a user requests a reading
+    biblereading::validate_schedule();
+
     let bot: Bot = Bot::from_env();
 
     let bot_commands = Command::bot_commands();
@@ -71,31 +252,150 @@ async fn main() {
     let callback_handler = Update::filter_callback_query()
             .endpoint(answer_button);
 
+    // Documents which are sent with `/importdata` as caption are not recognized as commands
+    let import_handler = Update::filter_message()
+            .filter(|msg: Message| msg.document().is_some() && msg.caption().is_some_and(|caption| caption.trim().starts_with(IMPORT_COMMAND)))
+            .endpoint(answer_import_document);
+
+    let poll_answer_handler = Update::filter_poll_answer()
+            .endpoint(answer_poll);
+
     let handler = dptree::entry()
         .branch(message_handler)
-        .branch(callback_handler);
+        .branch(import_handler)
+        .branch(callback_handler)
+        .branch(poll_answer_handler);
 
-    let bot_arc = Arc::new(bot.clone());
+    let notifier_arc = create_notifier(&bot);
+    let bible_text_client_arc = Arc::new(BibleTextClient::from_env());
     let user_state_wrapper_arc = Arc::new(user_state_wrapper);
 
-    let bot_arc_thread = bot_arc.clone();
+    let metrics_arc = Arc::new(Metrics::new());
+    let reminder_metrics_arc = Arc::new(ReminderMetrics::load(&reminder_metrics_file_path()).await);
+
+    // A single token is cancelled on Ctrl-C or SIGTERM and stops the dispatcher and all background loops
+    let shutdown = CancellationToken::new();
+
+    let notifier_arc_thread = notifier_arc.clone();
     let user_state_wrapper_arc_thread = user_state_wrapper_arc.clone();
-    tokio::spawn(async move { run_timer_thread_loop(bot_arc_thread.clone(), user_state_wrapper_arc_thread.clone()).await } );
+    let metrics_arc_thread = metrics_arc.clone();
+    let reminder_metrics_arc_thread = reminder_metrics_arc.clone();
+    let shutdown_thread = shutdown.clone();
+    let timer_thread = tokio::spawn(async move { run_timer_thread_loop(notifier_arc_thread, user_state_wrapper_arc_thread.clone(), metrics_arc_thread, reminder_metrics_arc_thread, shutdown_thread).await } );
+
+    let shutdown_thread = shutdown.clone();
+    tokio::spawn(async move { run_schedule_watch_loop(shutdown_thread).await });
 
+    match env::var(HEALTHCHECK_PORT_ENV).or_else(|_| env::var(HEALTH_PORT_ENV)).map(|port_string| port_string.parse::<u16>()) {
+        Ok(Ok(port)) => {
+            let user_state_wrapper_arc_thread = user_state_wrapper_arc.clone();
+            let metrics_arc_thread = metrics_arc.clone();
+            let shutdown_thread = shutdown.clone();
+            tokio::spawn(async move {
+                if let Err(error) = health::run_health_server(port, user_state_wrapper_arc_thread, metrics_arc_thread, shutdown_thread.cancelled_owned()).await {
+                    log::error!("The health check server failed: {}", error.to_string());
+                }
+            });
+        },
+        Ok(Err(_)) => log::error!("The value of {} or {} is not a valid port, the health check server is not started.", HEALTHCHECK_PORT_ENV, HEALTH_PORT_ENV),
+        Err(_) => {}
+    }
+
+    // The reminder metrics are always saved in the loop, the user states only if the store does not persist them directly
     let user_state_wrapper_arc_thread = user_state_wrapper_arc.clone();
-    tokio::spawn(async move { run_save_userstate_loop(user_state_wrapper_arc_thread.clone()).await } );
+    let reminder_metrics_arc_thread = reminder_metrics_arc.clone();
+    let shutdown_thread = shutdown.clone();
+    let save_thread = tokio::spawn(async move { run_save_userstate_loop(user_state_wrapper_arc_thread.clone(), reminder_metrics_arc_thread, save_periodically, shutdown_thread).await } );
+
+    let services_arc = Arc::new(Services {
+        reminder_metrics: reminder_metrics_arc.clone(),
+        notifier: notifier_arc.clone(),
+        bible_text_client: bible_text_client_arc.clone(),
+    });
 
-    Dispatcher::builder(bot, handler)
-        .dependencies(dptree::deps![user_state_wrapper_arc.clone()])
-        .enable_ctrlc_handler()
-        .build()
-        .dispatch()
-        .await;
+    let mut dispatcher = Dispatcher::builder(bot, handler)
+        .dependencies(dptree::deps![user_state_wrapper_arc.clone(), services_arc])
+        .build();
 
+    let dispatcher_shutdown_token = dispatcher.shutdown_token();
+    let shutdown_thread = shutdown.clone();
+    tokio::spawn(async move {
+        wait_for_shutdown_signal().await;
+        log::info!("Shutdown signal received, stopping the bot...");
+        shutdown_thread.cancel();
+        match dispatcher_shutdown_token.shutdown() {
+            Ok(dispatcher_stopped) => dispatcher_stopped.await,
+            Err(_) => log::warn!("The dispatcher was not running while shutting down."),
+        }
+    });
+
+    dispatcher.dispatch().await;
+
+    // The loops are stopped as well if the dispatcher returned for another reason. The final save is only performed
+    // after they have finished, so that no update which is made during the shutdown gets lost.
+    shutdown.cancel();
+    let _ = timer_thread.await;
+    let _ = save_thread.await;
+    handle_final_save(user_state_wrapper_arc.clone(), reminder_metrics_arc.clone()).await;
 }   
 
 
 
+/// The services which are shared by the command handlers, so that they are injected by the dispatcher together.
+struct Services {
+    /// The durable reminder counters per language
+    reminder_metrics: Arc<ReminderMetrics>,
+    /// The notifier with which the daily reminders are sent
+    notifier: Arc<dyn Notifier>,
+    /// The client for the texts of Bible passages
+    bible_text_client: Arc<BibleTextClient>,
+}
+
+/// Creates the `Notifier` for the daily reminders. With `DRY_RUN=1` (or `DAILYBIBLE_DRY_RUN=1`) the reminders
+/// are only logged, otherwise they are sent to Telegram with the given bot.
+fn create_notifier(bot: &Bot) -> Arc<dyn Notifier> {
+    let dry_run = env::var(DRY_RUN_ENV).or_else(|_| env::var(DAILYBIBLE_DRY_RUN_ENV));
+    if dry_run.is_ok_and(|value| value == "1" || value.eq_ignore_ascii_case("true")) {
+        log::warn!("Dry run: the reminders are only logged and not sent to Telegram.");
+        return Arc::new(LoggingNotifier);
+    }
+    Arc::new(TelegramNotifier::new(bot.clone()))
+}
+
+/// Creates the `UserStore` which is selected by the environment variable `TELOXIDE_USERSTORE`.
+/// If nothing is selected, the JSON file store is used. If the SQLite database can not be opened,
+/// the JSON file store is used as fallback.
+async fn create_user_store() -> Arc<dyn UserStore> {
+    let user_state_file = env::var(USER_STATE_ENV).unwrap_or(DEFAULT_USER_STATE_FILE_PATH.to_string());
+
+    if env::var(USER_STORE_ENV).unwrap_or_default().to_lowercase() == "sqlite" {
+        let database_url = env::var(SQLITE_DATABASE_ENV).unwrap_or(DEFAULT_SQLITE_DATABASE_URL.to_string());
+        match SqliteStore::connect(&database_url).await {
+            Ok(store) => {
+                log::info!("Using the SQLite database {} for the user states.", database_url);
+                return Arc::new(store);
+            },
+            Err(error) => log::error!("Could not open the SQLite database {}: {}. Falling back to {}.", database_url, error.to_string(), user_state_file)
+        }
+    }
+
+    log::info!("Using the file {} for the user states.", user_state_file);
+    Arc::new(JsonFileStore::new(&user_state_file))
+}
+
+
+/// Returns the path of the file where the reminder counters per language are saved.
+/// The file is placed in the same directory as the user state file.
+fn reminder_metrics_file_path() -> String {
+    let user_state_file = env::var(USER_STATE_ENV).unwrap_or(DEFAULT_USER_STATE_FILE_PATH.to_string());
+    Path::new(&user_state_file)
+        .with_file_name(REMINDER_METRICS_FILE_NAME)
+        .to_string_lossy()
+        .to_string()
+}
+
+
+
 /// This function handles the answers which the bot can give depending on the command issued by the user.
 /// It is automatically called by the dispatcher.
 /// 
@@ -103,21 +403,83 @@ async fn main() {
 /// - bot: The telegram bot (it can be cloned)
 /// - cmd: The Command which has been issued
 /// - user_state_wrapper: An Arc of the UserStateWrapper
+/// - services: An Arc of the `Services` (metrics, notifier and Bible text client)
 /// 
 /// # Return
 /// A ResponseResult (just await this function)
 /// 
 /// # Note
 /// The Arc of the UserStateWrapper should be cloned every time passing it to a function to make sure that always enough references of that live.
-async fn answer(bot: Bot, msg: Message, cmd: Command, user_state_wrapper: Arc<UserStateWrapper>) -> ResponseResult<()> {
+async fn answer(bot: Bot, msg: Message, cmd: Command, user_state_wrapper: Arc<UserStateWrapper>, services: Arc<Services>) -> ResponseResult<()> {
     match cmd {
         Command::Help => bot.send_message(msg.chat.id, Command::descriptions().to_string()).await?,
-        Command::SendDailyReminder => send_daily_reminder(bot, msg.chat.id, user_state_wrapper.clone()).await?,
-        Command::Start => bot.send_message(msg.chat.id, "This bot helps you to read your Bible daily. Type /help for more information").await?,
+        Command::About => send_about(bot, msg, user_state_wrapper.clone()).await?,
+        Command::SendDailyReminder => {
+            // A requested reminder is not counted in the metrics of the timer reminders
+            send_daily_reminder(services.notifier.clone(), msg.chat.id, user_state_wrapper.clone(), None).await?;
+            return Ok(());
+        },
+        Command::Today => {
+            send_todays_biblereading(services.notifier.as_ref(), msg.chat.id, &user_state_wrapper.find_userstate(msg.chat.id).await, None).await?;
+            return Ok(());
+        },
+        Command::Start => bot.send_message(msg.chat.id, "This bot helps you to read your Bible daily. Type /help for more information. \
+            If you are on vacation, use /pause to stop the reminders without losing your timer and /resume to continue.").await?,
+        Command::NextReading | Command::Tomorrow => send_next_biblereading(bot, msg, user_state_wrapper.clone()).await?,
+        Command::Verse { reference } => send_verse(bot, msg, user_state_wrapper.clone(), services.bible_text_client.clone(), reference).await?,
+        Command::Week | Command::Schedule => send_week(bot, msg, user_state_wrapper.clone()).await?,
+        Command::History => send_history(bot, msg, user_state_wrapper.clone()).await?,
+        Command::WeeklySummary { setting } => set_weekly_summary(bot, msg, user_state_wrapper.clone(), setting).await?,
+        Command::Random => send_random_biblereading(bot, msg, user_state_wrapper.clone()).await?,
+        Command::ReadingFor { date_string } | Command::OnDate { date_string } => send_biblereading_for_date(bot, msg, user_state_wrapper.clone(), date_string).await?,
         Command::SetTimer { timer_string } => bot_set_timer(bot, msg, user_state_wrapper.clone(), timer_string).await?,
+        Command::SnoozeUntil { time_string } => snooze_reminder_until(bot, msg, user_state_wrapper.clone(), time_string).await?,
         Command::UnsetTimer => bot_unset_timer(bot, msg, user_state_wrapper.clone()).await?,
+        Command::Pause => bot_set_paused(bot, msg, user_state_wrapper.clone(), true).await?,
+        Command::Resume => bot_set_paused(bot, msg, user_state_wrapper.clone(), false).await?,
+        Command::SkipToday => bot_skip_today(bot, msg, user_state_wrapper.clone()).await?,
+        Command::Enable => bot_enable_group(bot, msg, user_state_wrapper.clone()).await?,
+        Command::Status => send_status(bot, msg, user_state_wrapper.clone()).await?,
+        Command::NextReminder => send_next_reminder(bot, msg, user_state_wrapper.clone()).await?,
         Command::UserInformation => send_user_information(bot, msg, user_state_wrapper.clone()).await?,
         Command::SetLang { lang_string } => set_language(bot, msg.chat.id, user_state_wrapper.clone(), lang_string).await?,
+        Command::SetPlan { plan } => set_plan(bot, msg, user_state_wrapper.clone(), plan).await?,
+        Command::SetVersion { version } => set_bible_version(bot, msg, user_state_wrapper.clone(), version).await?,
+        Command::SetMessage { text } => set_custom_prefix(bot, msg, user_state_wrapper.clone(), text).await?,
+        Command::ReadButton { setting } => set_read_button(bot, msg, user_state_wrapper.clone(), setting).await?,
+        Command::Poll { setting } => set_poll(bot, msg, user_state_wrapper.clone(), setting).await?,
+        Command::PollAnon { setting } => set_anonymous_poll(bot, msg, user_state_wrapper.clone(), setting).await?,
+        Command::Markdown { setting } => set_markdown(bot, msg, user_state_wrapper.clone(), setting).await?,
+        Command::StartPlan { setting } => set_reading_plan(bot, msg, user_state_wrapper.clone(), setting).await?,
+        Command::Export | Command::ExportData => export_user_data(bot, msg, user_state_wrapper.clone()).await?,
+        Command::ImportData => import_user_data(bot, msg, user_state_wrapper.clone()).await?,
+        Command::DeleteMyData | Command::DeleteData => delete_user_data(bot, msg, user_state_wrapper.clone()).await?,
+        Command::Feedback { text } => send_feedback(bot, msg, user_state_wrapper.clone(), text).await?,
+        Command::Stats => {
+            // For all other users the command behaves as if it did not exist
+            if is_admin(msg.chat.id) {
+                send_stats(bot, msg, user_state_wrapper.clone()).await?;
+            }
+            return Ok(());
+        },
+        Command::Broadcast { text } => {
+            if is_admin(msg.chat.id) {
+                broadcast(bot, msg, user_state_wrapper.clone(), text).await?;
+            }
+            return Ok(());
+        },
+        Command::Metrics => {
+            if is_admin(msg.chat.id) {
+                send_reminder_metrics(bot, msg, services.reminder_metrics.clone()).await?;
+            }
+            return Ok(());
+        },
+        Command::ReloadSchedule => {
+            if is_admin(msg.chat.id) {
+                reload_schedule(bot, msg).await?;
+            }
+            return Ok(());
+        },
     };  
     Ok(())
 }
@@ -126,7 +488,7 @@ async fn answer(bot: Bot, msg: Message, cmd: Command, user_state_wrapper: Arc<Us
 /// This function handles callbacks which come in from clicked inline buttons. 
 /// A callback consists of an `Option<callback_string>` which contains a kind of "message" 
 /// which is sent by the button when clicked.
-/// The ChatId can be gained by `callback.from.into()
+/// The ChatId is the chat of the message with the button (see `callback_chat_id`).
 /// 
 /// # Params (provided by the Dispatcher)
 /// - `bot`: the Teloxide Bot
@@ -134,78 +496,516 @@ async fn answer(bot: Bot, msg: Message, cmd: Command, user_state_wrapper: Arc<Us
 /// - `user_state_wrapper`: The UserStateWrapper which allows to access the User State
 /// 
 /// # Returns
-/// A ResponseResult. 
+/// A ResponseResult. Errors which occur while handling the callback are logged.
 /// 
 /// # Note
 /// As this function is async, it should be called with `await`.
 async fn answer_button(bot: Bot, callback: CallbackQuery, user_state_wrapper: Arc<UserStateWrapper>)  -> ResponseResult<()> {
-    match callback.data {
+    // Without an answer the Telegram client keeps showing the loading spinner on the button
+    if let Err(error) = bot.answer_callback_query(callback.id.clone()).await {
+        log::warn!("Could not answer the callback query {}: {}", callback.id, error.to_string());
+    }
+
+    let chat_id = callback_chat_id(&callback);
+    let result = match callback.data {
         Some(callback_string) => {
             match callback_string.as_str() {
-                "German" => { let _ = set_language(bot, callback.from.id.into(), user_state_wrapper, "de".to_string()).await; },
-                "English" => { let _ = set_language(bot, callback.from.id.into(), user_state_wrapper, "en".to_string()).await; },
-                _ => { log::warn!("Received callback {} which isn't implemented.", callback_string); }
+                "German" => set_language(bot, chat_id, user_state_wrapper, "de".to_string()).await,
+                "English" => set_language(bot, chat_id, user_state_wrapper, "en".to_string()).await,
+                "Spanish" => set_language(bot, chat_id, user_state_wrapper, "es".to_string()).await,
+                "Portuguese" => set_language(bot, chat_id, user_state_wrapper, "pt".to_string()).await,
+                "Italian" => set_language(bot, chat_id, user_state_wrapper, "it".to_string()).await,
+                "mark_read" => mark_as_read(bot, chat_id, user_state_wrapper, callback.message).await,
+                "snooze_30" => snooze_reminder(bot, chat_id, user_state_wrapper).await,
+                "delete_confirm" => confirm_deletion(bot, chat_id, user_state_wrapper, callback.message).await,
+                "delete_cancel" => cancel_deletion(bot, chat_id, user_state_wrapper, callback.message).await,
+                _ => {
+                    log::warn!("Received callback {} which isn't implemented.", callback_string);
+                    return Ok(());
+                }
             }
         }
-        None => {}
+        None => {
+            log::warn!("Received a callback without data from {}.", chat_id.to_string());
+            return Ok(());
+        }
+    };
+
+    if let Err(error) = result {
+        log::error!("Could not handle the callback of {}: {}", chat_id.to_string(), error.to_string());
+    }
+    Ok(())
+}
+
+/// Returns the chat whose data a button concerns: the chat of the message with the button, which is a group chat
+/// if the message has been sent there. Only if the message is not available, the chat of the user who clicked is used.
+fn callback_chat_id(callback: &CallbackQuery) -> ChatId {
+    callback.message.as_ref().map(|message| message.chat().id).unwrap_or(callback.from.id.into())
+}
+
+/// This function handles the answers to the polls of the daily reminders, which are only received for polls
+/// which are not anonymous (so not in group chats). If the user answered "Yes" to the poll of today's reminder,
+/// the reading is marked as read and a congratulation is sent as reply to the poll.
+/// Answers to polls of previous days are not counted.
+///
+/// # Params (provided by the Dispatcher)
+/// - `bot`: the Teloxide Bot
+/// - `poll_answer`: the answer of the user
+/// - `user_state_wrapper`: The UserStateWrapper which allows to access the User State
+async fn answer_poll(bot: Bot, poll_answer: PollAnswer, user_state_wrapper: Arc<UserStateWrapper>) -> ResponseResult<()> {
+    // Retracted votes and votes of anonymous voters are ignored
+    let user = match poll_answer.voter.user() {
+        Some(user) => user,
+        None => return Ok(()),
     };
+    let chat_id: ChatId = user.id.into();
+    if poll_answer.option_ids.is_empty() || !user_state_wrapper.user_state_exists(chat_id).await {
+        return Ok(());
+    }
+
+    let mut user_state = user_state_wrapper.find_userstate(chat_id).await;
+    let today = Local::now().date_naive();
+
+    if !user_state.is_current_poll(&poll_answer.poll_id, today) {
+        bot.send_message(chat_id, msg_poll_stale(&user_state.language)).await?;
+        return Ok(());
+    }
+
+    // The first option of the poll is "Yes"
+    if poll_answer.option_ids.contains(&0) {
+        user_state.mark_as_read(today);
+        user_state_wrapper.update_and_persist(user_state.clone()).await;
+
+        let mut request = bot.send_message(chat_id, msg_marked_as_read(&user_state.language, user_state.streak));
+        if let Some(last_poll) = &user_state.last_poll {
+            request = request.reply_parameters(ReplyParameters::new(last_poll.message_id));
+        }
+        request.await?;
+    }
     Ok(())
 }
 
-/// This function is used to send the daily reminder to the user
+/// This function is used to send the daily reminder to the user.
+/// The Bible reading is sent with `send_todays_biblereading` (which is shared with the `/today` command),
+/// followed by the poll which is only part of the daily reminder.
 /// 
 /// # Arguments
-/// - bot: The telegram bot (it can be cloned)
+/// - notifier: The Notifier which sends the reminder (or only logs it in a dry run)
 /// - chat_id: the ChatId of the user (where to send the message to)
 /// - user_state_wrapper_arc: An Arc of the UserStateWrapper
+/// - metrics: Arcs of the TickMetrics which count the sent reminders, polls and failures and of the ReminderMetrics
+///   which count the sent reminders per language. It is `None` for a reminder which has been requested with
+///   `/senddailyreminder`, which is not counted.
 /// 
 /// # Return
 /// A ResponseResult (just await this function)
 /// 
 /// # Note
 /// The Arc of the UserStateWrapper should be cloned every time passing it to a function to make sure that always enough references of that live.
-async fn send_daily_reminder(bot: Bot, chat_id: ChatId, user_state_wrapper_arc: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+async fn send_daily_reminder(notifier: Arc<dyn Notifier>, chat_id: ChatId, user_state_wrapper_arc: Arc<UserStateWrapper>, metrics: Option<(Arc<TickMetrics>, Arc<ReminderMetrics>)>) -> Result<(), RequestError> {
     let userstate = user_state_wrapper_arc.find_userstate(chat_id).await;
 
-    match biblereading::get_todays_biblereading() {
+    let keyboard = InlineKeyboardMarkup::new(vec!{
+        vec![
+            InlineKeyboardButton::callback(msg_button_mark_read(&userstate.language), "mark_read"),
+            InlineKeyboardButton::callback(msg_button_snooze(&userstate.language), "snooze_30")
+        ]
+    });
+
+    let reading_result = send_todays_biblereading(notifier.as_ref(), chat_id, &userstate, Some(keyboard)).await;
+    match &reading_result {
+        Ok(_) => {
+            log::info!(chat_id = chat_id.0, language:? = userstate.language, status = "sent"; "Reminder sent to {}", chat_id.to_string());
+            if let Some((tick_metrics, reminder_metrics)) = &metrics {
+                tick_metrics.record_reminder_sent(Local::now().date_naive());
+                reminder_metrics.record_reminder_sent(&userstate.language).await;
+            }
+        },
+        Err(error) => {
+            log::error!(chat_id = chat_id.0, language:? = userstate.language, status = "failed", error = error.to_string(); "An error occurred while sending the request to {}: {}", chat_id.to_string(), error.to_string());
+            if let Some((tick_metrics, _)) = &metrics {
+                tick_metrics.record_send_failure();
+            }
+        }
+    };
+
+    // The reading can already be confirmed with the "Mark as read" button, so the poll is optional
+    if userstate.use_button_instead_of_poll {
+        return reading_result;
+    }
+
+    let poll_text = msg_poll(&userstate.language);
+    let poll_result = notifier.send_poll(
+        chat_id,
+        poll_text.question,
+        vec![poll_text.yes, poll_text.no],
+        userstate.sends_anonymous_poll(),
+    ).await;
+
+    match &poll_result {
+        // The poll is remembered, so that the answer of the user can be assigned to today's reminder
+        Ok(Some(poll_message)) => {
+            log::info!(chat_id = chat_id.0, language:? = userstate.language, status = "poll_sent"; "Poll sent to {}", chat_id.to_string());
+            if let Some((tick_metrics, _)) = &metrics {
+                tick_metrics.record_poll_sent();
+            }
+            let mut user_state = user_state_wrapper_arc.find_userstate(chat_id).await;
+            user_state.last_poll = Some(SentPoll {
+                message_id: poll_message.message_id,
+                poll_id: poll_message.poll_id.clone(),
+                date: Local::now().date_naive(),
+            });
+            user_state_wrapper_arc.update_and_persist(user_state).await;
+        },
+        Ok(None) => {},
+        Err(error) => {
+            log::error!(chat_id = chat_id.0, language:? = userstate.language, status = "poll_failed", error = error.to_string(); "Could not send the poll to {}: {}", chat_id.to_string(), error.to_string());
+            if let Some((tick_metrics, _)) = &metrics {
+                tick_metrics.record_send_failure();
+            }
+        },
+    }
+    poll_result.map(|_| ())
+}       
+
+
+/// This function sends today's Bible reading to the user without the poll which follows the daily reminder.
+/// If the user has started a personal reading plan, the reading of the current day of the plan is sent instead.
+/// If no Bible reading can be found for today, the fallback reminder message is sent instead.
+/// Transient errors while sending are retried by the `TelegramNotifier`.
+/// 
+/// # Arguments
+/// - notifier: The Notifier which sends the message (or only logs it in a dry run)
+/// - chat_id: the ChatId of the user (where to send the message to)
+/// - user_state: The UserState of the user, which contains the language and the preferred Bible version
+/// - keyboard: An optional inline keyboard which is attached to the message
+/// 
+/// # Return
+/// A ResponseResult (just await this function)
+async fn send_todays_biblereading(notifier: &dyn Notifier, chat_id: ChatId, user_state: &UserState, keyboard: Option<InlineKeyboardMarkup>) -> Result<(), RequestError> {
+    let language = &user_state.language;
+
+    let today = Local::now().date_naive();
+    let biblereading_result = match user_state.plan_day(today) {
+        Some(plan_day) => biblereading::get_biblereading_for_plan_day(&user_state.plan, plan_day, today),
+        None => biblereading::get_todays_biblereading(&user_state.plan),
+    };
+
+    match biblereading_result {
         Ok(todays_biblereading) => {
             log::info!("Send todays Biblereading to {}", chat_id.to_string());
-            match bot.send_message(
-                chat_id,
-                msg_biblereading(&userstate.language, todays_biblereading)
-            )
-            .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-            .await {
-                Ok(_) => log::info!("Sending completed!"),
-                Err(error) => log::error!("An error occurred while sending the request to {}: {}", chat_id.to_string(), error.to_string())
+            let bible_version = user_state.preferred_bible_version.as_deref();
+            let verse = verses::get_todays_verse();
+            let custom_prefix = user_state.custom_prefix.as_deref();
+            let keyboard = add_audio_buttons(keyboard, language, &todays_biblereading.audio);
+
+            if !user_state.use_markdown {
+                let message = msg_biblereading_plain(language, todays_biblereading, bible_version, verse.as_ref(), custom_prefix);
+                return notifier.send_text(chat_id, message, None, keyboard).await;
+            }
+
+            let message = msg_biblereading(language, todays_biblereading.clone(), bible_version, verse.as_ref(), custom_prefix);
+            match notifier.send_text(chat_id, message, Some(MarkdownV2), keyboard.clone()).await {
+                // A reading which Telegram cannot parse (for example because of an unusual schedule entry) is still sent
+                Err(RequestError::Api(ApiError::CantParseEntities(error))) => {
+                    log::warn!("Could not send the reading to {} with MarkdownV2 ({}), retrying as plain text.", chat_id.to_string(), error);
+                    let message = msg_biblereading_plain(language, todays_biblereading, bible_version, verse.as_ref(), custom_prefix);
+                    notifier.send_text(chat_id, message, None, keyboard).await
+                },
+                result => result,
+            }
+        },
+        Err(error) => {
+            // A missing or broken schedule is an operational problem, while a missing date only needs the fallback message
+            match error {
+                DailyBibleError::DateDoesNotExist => log::warn!("{}", error.to_string()),
+                _ => log::error!("{}", error.to_string()),
             }
-            
+            log::warn!("Today's Bible reading not found. Send fallback message to {}.", chat_id.to_string());
+            notifier.send_text(chat_id, msg_biblereading_not_found(language), None, keyboard).await
+        }
+    }
+}
+
+
+/// Adds a row with the links to the audio recordings of the reading (see `AudioLinks`) to the keyboard.
+/// Without audio links, the keyboard is returned unchanged.
+fn add_audio_buttons(keyboard: Option<InlineKeyboardMarkup>, language: &Language, audio: &AudioLinks) -> Option<InlineKeyboardMarkup> {
+    let buttons: Vec<InlineKeyboardButton> = [
+        (&audio.old_testament, msg_button_listen_old_testament(language)),
+        (&audio.new_testament, msg_button_listen_new_testament(language)),
+    ].into_iter()
+        .filter_map(|(url, text)| url.as_ref().and_then(|url| url.parse().ok()).map(|url| InlineKeyboardButton::url(text, url)))
+        .collect();
+
+    if buttons.is_empty() {
+        return keyboard;
+    }
+    Some(keyboard.unwrap_or_default().append_row(buttons))
+}
+
+
+/// Records that the user has read today's Bible reading (triggered by the "Mark as read" button)
+/// and answers with the current streak. The buttons of the reminder are replaced by a checkmark, except for the audio links.
+/// 
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `chat_id`: the ChatId of the user (where to send the message to)
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `message`: The reminder which contained the clicked button (if it is still accessible)
+async fn mark_as_read(bot: Bot, chat_id: ChatId, user_state_wrapper: Arc<UserStateWrapper>, message: Option<MaybeInaccessibleMessage>) -> Result<Message, RequestError> {
+    let mut user_state = user_state_wrapper.find_userstate(chat_id).await;
+
+    user_state.mark_as_read(Local::now().date_naive());
+    user_state_wrapper.update_and_persist(user_state.clone()).await;
+
+    if let Some(message) = message {
+        // The links to the audio recordings of the reading are kept
+        let audio_rows: Vec<Vec<InlineKeyboardButton>> = message.regular_message()
+            .and_then(|message| message.reply_markup())
+            .map(|markup| markup.inline_keyboard.iter()
+                .filter(|row| row.iter().all(|button| matches!(button.kind, InlineKeyboardButtonKind::Url(_))))
+                .cloned()
+                .collect())
+            .unwrap_or_default();
+        let mut rows = vec![vec![InlineKeyboardButton::callback(msg_button_marked_read(&user_state.language), "mark_read")]];
+        rows.extend(audio_rows);
+        let keyboard = InlineKeyboardMarkup::new(rows);
+        if let Err(error) = bot.edit_message_reply_markup(message.chat().id, message.id()).reply_markup(keyboard).await {
+            log::warn!("Could not mark the reminder {} as read: {}", message.id().0, error.to_string());
+        }
+    }
+
+    bot.send_message(chat_id, msg_marked_as_read(&user_state.language, user_state.streak)).await
+}
+
+
+/// Schedules a one-off reminder in `SNOOZE_MINUTES` minutes (triggered by the "Remind me later" button).
+/// The reminder is sent by the timer loop.
+/// 
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `chat_id`: the ChatId of the user (where to send the message to)
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn snooze_reminder(bot: Bot, chat_id: ChatId, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let language = user_state_wrapper.find_userstate(chat_id).await.language;
+    let snooze_time = Local::now().naive_local() + chrono::Duration::minutes(SNOOZE_MINUTES);
+
+    user_state_wrapper.add_snooze(chat_id, snooze_time).await;
+    log::info!("Snoozed the reminder of {} until {}", chat_id.to_string(), snooze_time.to_string());
+
+    bot.send_message(chat_id, msg_snoozed(&language, &snooze_time.time())).await
+}
+
+
+/// Schedules a single additional reminder at the given time of today (with `/snoozeuntil`).
+/// The time is parsed like the timer, the recurring timer itself is not changed.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `time_string`: The time of the reminder (for example `20:00` or `8pm`)
+async fn snooze_reminder_until(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, time_string: String) -> Result<Message, RequestError> {
+    let language = user_state_wrapper.find_userstate(msg.chat.id).await.language;
+    let now = Local::now().naive_local();
+
+    match parse_user_time(&time_string).map(|time| now.date().and_time(time)) {
+        Some(snooze_time) if snooze_time > now => {
+            user_state_wrapper.add_snooze(msg.chat.id, snooze_time).await;
+            log::info!("Snoozed the reminder of {} until {}", msg.chat.id.to_string(), snooze_time.to_string());
+            bot.send_message(msg.chat.id, msg_snoozed(&language, &snooze_time.time())).await
         },
-        Err(error) => {     
-            log::error!("{}", error.to_string());
-
-            match bot.send_message(
-                chat_id,
-                msg_biblereading_not_found(&userstate.language)
-            ).await {
-                Ok(_) => log::warn!("Today's Bible reading not found. Sent message to {}.", chat_id.to_string()),
-                Err(error) => log::error!("An error occurred while sending message to {}: {}", chat_id.to_string(), error.to_string())
+        _ => bot.send_message(msg.chat.id, msg_error_snooze_until(&language)).await
+    }
+}
+
+
+/// Sends the text of a Bible passage which is fetched from the configured Bible text API.
+/// If the API is not configured or not reachable, the reference (as link to the preferred Bible version) is sent instead.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `bible_text_client`: An Arc of the BibleTextClient which fetches and caches the passages
+/// - `reference`: The Bible reference which has been typed by the user
+async fn send_verse(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, bible_text_client: Arc<BibleTextClient>, reference: String) -> Result<Message, RequestError> {
+    let user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+    let language = &user_state.language;
+    let bible_version = user_state.preferred_bible_version.as_deref();
+    let reference = reference.trim();
+
+    if reference.is_empty() {
+        return bot.send_message(msg.chat.id, msg_error_verse_reference(language)).await;
+    }
+
+    match bible_text_client.get_passage(reference, language).await {
+        Ok(text) => bot.send_message(msg.chat.id, msg_verse(reference, &text, bible_version)).parse_mode(MarkdownV2).await,
+        Err(BibleTextError::PassageNotFound) => bot.send_message(msg.chat.id, msg_error_verse_not_found(language, reference)).await,
+        Err(error) => {
+            if error != BibleTextError::NotConfigured {
+                log::warn!("Could not fetch the passage {}: {}", reference, error.to_string());
             }
+            bot.send_message(msg.chat.id, msg_verse_fallback(language, reference, bible_version)).parse_mode(MarkdownV2).await
         }
+    }
+}
+
+
+/// Sends the Bible readings of today and the next six days in one message (one line per day).
+/// Days without a reading are shown with a localized placeholder.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn send_week(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+    let today = Local::now().date_naive();
+    let days = biblereading::get_biblereadings_for_week(&user_state.plan, today, user_state.plan_day(today));
+    bot.send_message(msg.chat.id, msg_week(&user_state.language, &days, TELEGRAM_MESSAGE_LIMIT)).await
+}
+
+
+/// Sends the days of the last `HISTORY_DAYS` days on which the user has marked the reading as read as a small calendar.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn send_history(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+    let history = msg_history(&user_state.language, &user_state.completed_dates, Local::now().date_naive(), HISTORY_DAYS);
+    bot.send_message(msg.chat.id, history).await
+}
+
+
+/// Sets whether the user receives a summary of the week on `WEEKLY_SUMMARY_WEEKDAY` (`on` or `off`).
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `setting`: Either `on` or `off`
+async fn set_weekly_summary(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, setting: String) -> Result<Message, RequestError> {
+    let mut user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+
+    match setting.trim().to_lowercase().as_str() {
+        "on" => {
+            user_state.weekly_summary = true;
+            user_state_wrapper.update_and_persist(user_state.clone()).await;
+            bot.send_message(msg.chat.id, msg_weekly_summary_enabled(&user_state.language)).await
+        },
+        "off" => {
+            user_state.weekly_summary = false;
+            user_state_wrapper.update_and_persist(user_state.clone()).await;
+            bot.send_message(msg.chat.id, msg_weekly_summary_disabled(&user_state.language)).await
+        },
+        _ => bot.send_message(msg.chat.id, msg_error_weekly_summary(&user_state.language)).await
+    }
+}
+
+/// Sends the number of days of the current week on which the user has marked the reading as read.
+///
+/// # Params
+/// - `notifier`: The Notifier which sends the summary (or only logs it in a dry run)
+/// - `user_state`: The UserState of the user, which contains the completed dates
+/// - `today`: The last day of the week which is summarized
+async fn send_weekly_summary(notifier: &dyn Notifier, user_state: &UserState, today: NaiveDate) -> Result<(), RequestError> {
+    let summary = msg_weekly_summary(&user_state.language, user_state.read_days_in_week(today));
+    notifier.send_text(user_state.chat_id, summary, None, None).await?;
+    log::info!(chat_id = user_state.chat_id.0, status = "summary_sent"; "Sent the weekly summary to {}", user_state.chat_id.to_string());
+    Ok(())
+}
+
+
+/// Sends tomorrow's Bible reading, so that the user can prepare. At the end of the year the reading
+/// of January 1 of the next year is sent. If no reading is scheduled for tomorrow, a localized note is sent.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn send_next_biblereading(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+    let language = user_state.language.clone();
+
+    let next_biblereading = match Local::now().date_naive().succ_opt() {
+        Some(tomorrow) => match user_state.plan_day(tomorrow) {
+            Some(plan_day) => biblereading::get_biblereading_for_plan_day(&user_state.plan, plan_day, tomorrow).ok(),
+            None => biblereading::get_biblereading_for_date(&user_state.plan, tomorrow).ok(),
+        },
+        None => None,
     };
 
-    let question_strings = msg_poll_text(&userstate.language);
-    bot.send_poll(
-        chat_id, 
-        question_strings.first().unwrap(), 
-        vec![
-            question_strings.get(1).unwrap().clone(), 
-            question_strings.get(2).unwrap().clone()
-        ],
-    )
-    .is_anonymous(false)
-    .await
-}       
+    match next_biblereading {
+        Some(biblereading) => {
+            bot.send_message(msg.chat.id, msg_next_biblereading(&language, biblereading, user_state.preferred_bible_version.as_deref()))
+                .parse_mode(MarkdownV2)
+                .await
+        },
+        None => bot.send_message(msg.chat.id, msg_next_biblereading_not_found(&language)).await
+    }
+}
+
+
+/// Sends the Bible reading for a date given by the user in the format `mm-dd`. The date is resolved
+/// within the current year. If the date is invalid or no reading is scheduled for it, a localized note is sent.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `date_string`: The string given by the user which should be parsed as date
+async fn send_biblereading_for_date(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, date_string: String) -> Result<Message, RequestError> {
+    let user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+    let language = user_state.language.clone();
+
+    match biblereading::parse_month_day(&date_string) {
+        Some((month, day)) => {
+            match biblereading::get_biblereading_for_month_day(&user_state.plan, month, day, Local::now().year()) {
+                Ok(biblereading) => {
+                    bot.send_message(msg.chat.id, msg_biblereading(&language, biblereading, user_state.preferred_bible_version.as_deref(), None, None))
+                        .parse_mode(MarkdownV2)
+                        .await
+                },
+                Err(error) => {
+                    log::info!("No Bible reading for {}: {}", date_string, error.to_string());
+                    bot.send_message(msg.chat.id, msg_biblereading_not_found_for_date(&language, &date_string)).await
+                }
+            }
+        },
+        None => bot.send_message(msg.chat.id, msg_error_invalid_date(&language)).await
+    }
+}
+
+
+/// Sends the bible reading of a random date of the schedule (with `/random`).
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn send_random_biblereading(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+    let language = user_state.language.clone();
+
+    let random_biblereading = biblereading::get_random_biblereading(&user_state.plan, Local::now().date_naive(), &mut rand::thread_rng());
+    match random_biblereading {
+        Ok(biblereading) => {
+            let reading = msg_biblereading(&language, biblereading, user_state.preferred_bible_version.as_deref(), None, None);
+            bot.send_message(msg.chat.id, format!("{}\n\n{}", escape(&msg_random_reading(&language)), reading))
+                .parse_mode(MarkdownV2)
+                .await
+        },
+        Err(DailyBibleError::DateDoesNotExist) => bot.send_message(msg.chat.id, msg_biblereading_not_found(&language)).await,
+        Err(error) => {
+            log::warn!("The random Bible reading can not be found: {}", error.to_string());
+            bot.send_message(msg.chat.id, msg_biblereading_not_found(&language)).await
+        },
+    }
+}
 
 
 /// This function can be used for future features which haven't been implemented yet.
@@ -229,7 +1029,7 @@ async fn send_not_implemented(bot: Bot, msg: Message, user_state_wrapper: Arc<Us
 /// # Behavior
 /// The behavior is depending on the `lang_str` parameter.
 /// If no `lang_str` is specified or the `lang_str` value is unknown, buttons with language selections will be send.
-/// If `lang_str` is `en` or `de`, the languages will be set accordingly.
+/// If `lang_str` is `en`, `de`, `es`, `pt` or `it`, the languages will be set accordingly.
 /// 
 /// # Returns
 /// A ResponseResult. 
@@ -241,11 +1041,16 @@ async fn set_language(bot: Bot, chat_id: ChatId, user_state_wrapper: Arc<UserSta
     match lang_str.to_lowercase().as_str() {
         "de" => { user_state.language = Language::German; },
         "en" => { user_state.language = Language::English; },
+        "es" => { user_state.language = Language::Spanish; },
+        "pt" => { user_state.language = Language::Portuguese; },
+        "it" => { user_state.language = Language::Italian; },
         _ => {
-                let keyboard = InlineKeyboardMarkup::new(vec!{
-                    vec![InlineKeyboardButton::callback("English", "English")],
-                    vec![InlineKeyboardButton::callback("Deutsch", "German")]
-                });
+                // Two languages per row keep the keyboard compact as more languages are added
+                let keyboard = InlineKeyboardMarkup::new(Language::ALL.chunks(2).map(|languages| {
+                    languages.iter()
+                        .map(|language| InlineKeyboardButton::callback(language.native_name(), format!("{:?}", language)))
+                        .collect::<Vec<_>>()
+                }));
 
                 return bot.send_message(
                     chat_id, 
@@ -255,51 +1060,369 @@ async fn set_language(bot: Bot, chat_id: ChatId, user_state_wrapper: Arc<UserSta
                 .await;
         }
     };
-    user_state_wrapper.update_userstate(user_state.clone()).await;
+    user_state_wrapper.update_and_persist(user_state.clone()).await;
     bot.send_message(chat_id, msg_language_set(&user_state.language)).await
 }
 
 
-/// Set the timer to a specific time which is parsed from `timer_tring` in the format `hh:mm`. If
-/// no string is provided, an error message will be generated.
+/// Sets the reading plan whose schedule is used for the readings of the user (see `biblereading::load_plans`).
+/// Without a name or with an unknown name, the available plans are listed instead.
 ///
 /// # Params
 /// - `bot`: The telegram bot (it can be cloned)
-/// - `chat_id`: the ChatId of the user (where to send the message to)
-/// - `user_state_wrapper_arc`: An Arc of the UserStateWrapper
-/// - `timer_string`: The string to be parsed to set the timer
-async fn bot_set_timer(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, timer_string: String) -> Result<Message, RequestError> {
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `plan`: The name of the plan given by the user
+async fn set_plan(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, plan: String) -> Result<Message, RequestError> {
     let mut user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+    let plan = plan.trim();
+    let plan_names = biblereading::plan_names();
 
-    match chrono::NaiveTime::parse_from_str(&timer_string, "%H:%M") {
-        Ok(time) => { 
-            user_state.timer = Some(time);
-            user_state_wrapper.update_userstate(user_state.clone()).await;
-            bot.send_message(msg.chat.id, msg_timer_updated(&user_state.language, &time)).await
-        }
-        Err(_) => {
-            bot.send_message(msg.chat.id, msg_error_timer_update(&user_state.language)).await
-        }
+    if let Some(plan_name) = plan_names.iter().find(|plan_name| plan_name.eq_ignore_ascii_case(plan)) {
+        user_state.plan = plan_name.clone();
+        user_state_wrapper.update_and_persist(user_state.clone()).await;
+        return bot.send_message(msg.chat.id, msg_plan_set(&user_state.language, plan_name)).await;
+    }
+
+    let available_plans = msg_available_plans(&user_state.language, &plan_names.join(", "), &biblereading::plan_name(&user_state.plan));
+    if plan.is_empty() {
+        bot.send_message(msg.chat.id, available_plans).await
+    } else {
+        bot.send_message(msg.chat.id, format!("{}\n\n{}", msg_error_unknown_plan(&user_state.language, plan), available_plans)).await
     }
 }
 
 
-/// Unsets any set timer and responses with a message
+/// Sets the Bible version (like `ESV` or `LUT`) which is used for the links to the readings.
+/// If no version is given, the links are disabled. Versions which are not alphanumeric are rejected.
+///
 /// # Params
 /// - `bot`: The telegram bot (it can be cloned)
-/// - `chat_id`: the ChatId of the user (where to send the message to)
-/// - `user_state_wrapper_arc`: An Arc of the UserStateWrapper
-async fn bot_unset_timer(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `version`: The abbreviation of the Bible version given by the user
+async fn set_bible_version(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, version: String) -> Result<Message, RequestError> {
     let mut user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+    let version = version.trim().to_uppercase();
 
-    user_state.timer = None;
+    if version.is_empty() {
+        user_state.preferred_bible_version = None;
+        user_state_wrapper.update_and_persist(user_state.clone()).await;
+        bot.send_message(msg.chat.id, msg_bible_version_unset(&user_state.language)).await
+    } else if version.len() <= 12 && version.chars().all(|c| c.is_ascii_alphanumeric()) {
+        user_state.preferred_bible_version = Some(version.clone());
+        user_state_wrapper.update_and_persist(user_state.clone()).await;
+        bot.send_message(msg.chat.id, msg_bible_version_set(&user_state.language, &version)).await
+    } else {
+        bot.send_message(msg.chat.id, msg_error_bible_version(&user_state.language)).await
+    }
+}
 
-    user_state_wrapper.update_userstate(user_state.clone()).await;
+
+/// Sets the custom prefix which replaces the header of the daily reminder. Control characters are removed
+/// and the prefix is limited to `CUSTOM_PREFIX_MAX_LENGTH` characters. Without a text, the default header is used again.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `text`: The prefix given by the user
+async fn set_custom_prefix(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, text: String) -> Result<Message, RequestError> {
+    let mut user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+
+    match sanitize_custom_prefix(&text) {
+        Some(prefix) if prefix.is_empty() => {
+            user_state.custom_prefix = None;
+            user_state_wrapper.update_and_persist(user_state.clone()).await;
+            bot.send_message(msg.chat.id, msg_custom_prefix_unset(&user_state.language)).await
+        },
+        Some(prefix) => {
+            user_state.custom_prefix = Some(prefix);
+            user_state_wrapper.update_and_persist(user_state.clone()).await;
+            bot.send_message(msg.chat.id, msg_custom_prefix_set(&user_state.language)).await
+        },
+        None => bot.send_message(msg.chat.id, msg_error_custom_prefix(&user_state.language, CUSTOM_PREFIX_MAX_LENGTH)).await,
+    }
+}
+
+
+/// Sets whether the daily reminder is confirmed with the "Mark as read" button (`on`) or followed by a poll (`off`).
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `setting`: Either `on` or `off`
+async fn set_read_button(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, setting: String) -> Result<Message, RequestError> {
+    match setting.trim().to_lowercase().as_str() {
+        "on" => update_poll_setting(bot, msg.chat.id, user_state_wrapper, false).await,
+        "off" => update_poll_setting(bot, msg.chat.id, user_state_wrapper, true).await,
+        _ => {
+            let language = user_state_wrapper.find_userstate(msg.chat.id).await.language;
+            bot.send_message(msg.chat.id, msg_error_read_button(&language)).await
+        }
+    }
+}
+
+/// Sets whether the daily reminder is followed by a poll (`on`) or only confirmed with the "Mark as read" button (`off`).
+/// This is the same setting as `/readbutton`, only inverted.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `setting`: Either `on` or `off`
+async fn set_poll(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, setting: String) -> Result<Message, RequestError> {
+    match setting.trim().to_lowercase().as_str() {
+        "on" => update_poll_setting(bot, msg.chat.id, user_state_wrapper, true).await,
+        "off" => update_poll_setting(bot, msg.chat.id, user_state_wrapper, false).await,
+        _ => {
+            let language = user_state_wrapper.find_userstate(msg.chat.id).await.language;
+            bot.send_message(msg.chat.id, msg_error_poll(&language)).await
+        }
+    }
+}
+
+/// Saves whether the daily reminder is followed by a poll and confirms the change.
+async fn update_poll_setting(bot: Bot, chat_id: ChatId, user_state_wrapper: Arc<UserStateWrapper>, send_poll: bool) -> Result<Message, RequestError> {
+    let mut user_state = user_state_wrapper.find_userstate(chat_id).await;
+    user_state.use_button_instead_of_poll = !send_poll;
+    user_state_wrapper.update_and_persist(user_state.clone()).await;
+
+    if send_poll {
+        bot.send_message(chat_id, msg_read_button_disabled(&user_state.language)).await
+    } else {
+        bot.send_message(chat_id, msg_read_button_enabled(&user_state.language)).await
+    }
+}
+
+/// Sets whether the poll after the daily reminder is anonymous in private chats. Polls in groups are always anonymous.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `setting`: Either `on` or `off`
+async fn set_anonymous_poll(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, setting: String) -> Result<Message, RequestError> {
+    let mut user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+
+    match setting.trim().to_lowercase().as_str() {
+        "on" => {
+            user_state.anonymous_poll = true;
+            user_state_wrapper.update_and_persist(user_state.clone()).await;
+            bot.send_message(msg.chat.id, msg_anonymous_poll_enabled(&user_state.language)).await
+        },
+        "off" => {
+            user_state.anonymous_poll = false;
+            user_state_wrapper.update_and_persist(user_state.clone()).await;
+            bot.send_message(msg.chat.id, msg_anonymous_poll_disabled(&user_state.language)).await
+        },
+        _ => bot.send_message(msg.chat.id, msg_error_anonymous_poll(&user_state.language)).await
+    }
+}
+
+
+/// Sets whether the reminders are formatted with MarkdownV2 (`on`) or sent as plain text (`off`).
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `setting`: Either `on` or `off`
+async fn set_markdown(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, setting: String) -> Result<Message, RequestError> {
+    let mut user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+
+    match setting.trim().to_lowercase().as_str() {
+        "on" => {
+            user_state.use_markdown = true;
+            user_state_wrapper.update_and_persist(user_state.clone()).await;
+            bot.send_message(msg.chat.id, msg_markdown_enabled(&user_state.language)).await
+        },
+        "off" => {
+            user_state.use_markdown = false;
+            user_state_wrapper.update_and_persist(user_state.clone()).await;
+            bot.send_message(msg.chat.id, msg_markdown_disabled(&user_state.language)).await
+        },
+        _ => bot.send_message(msg.chat.id, msg_error_markdown(&user_state.language)).await
+    }
+}
+
+
+/// Starts a personal reading plan today (which reads the rows of the schedule one after another),
+/// or returns to the readings of the calendar with `off`. Starting the plan again restarts it from the first row.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `setting`: `off` to follow the calendar again, everything else (usually empty) starts the plan
+async fn set_reading_plan(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, setting: String) -> Result<Message, RequestError> {
+    let mut user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+
+    if setting.trim().eq_ignore_ascii_case("off") {
+        user_state.plan_start_date = None;
+        user_state_wrapper.update_and_persist(user_state.clone()).await;
+        bot.send_message(msg.chat.id, msg_plan_stopped(&user_state.language)).await
+    } else {
+        user_state.plan_start_date = Some(Local::now().date_naive());
+        user_state_wrapper.update_and_persist(user_state.clone()).await;
+        bot.send_message(msg.chat.id, msg_plan_started(&user_state.language)).await
+    }
+}
+
+
+/// Set the timer to a specific time which is parsed from `timer_tring` with `parse_user_time`
+/// (for example `08:00`, `8`, `8am` or `20.00`). If the string can not be parsed, an error message will be generated.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `chat_id`: the ChatId of the user (where to send the message to)
+/// - `user_state_wrapper_arc`: An Arc of the UserStateWrapper
+/// - `timer_string`: The string to be parsed to set the timer
+async fn bot_set_timer(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, timer_string: String) -> Result<Message, RequestError> {
+    let mut user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+
+    match parse_user_time(&timer_string) {
+        Some(time) => { 
+            // A new timer may send another reminder today
+            if user_state.timer != Some(time) {
+                user_state.last_reminder_sent = None;
+            }
+            user_state.timer = Some(time);
+            user_state.is_group = is_group_chat(&msg.chat);
+            user_state_wrapper.update_and_persist(user_state.clone()).await;
+
+            let mut message = msg_timer_updated(&user_state.language, &time);
+            if !user_state.receives_reminders() && !user_state.paused {
+                message = format!("{}\n\n{}", message, msg_group_not_enabled(&user_state.language));
+            }
+            bot.send_message(msg.chat.id, message).await
+        }
+        None => {
+            bot.send_message(msg.chat.id, msg_error_timer_update(&user_state.language)).await
+        }
+    }
+}
+
+
+/// Unsets any set timer and responses with a message
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `chat_id`: the ChatId of the user (where to send the message to)
+/// - `user_state_wrapper_arc`: An Arc of the UserStateWrapper
+async fn bot_unset_timer(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let mut user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+
+    user_state.timer = None;
+
+    user_state_wrapper.update_and_persist(user_state.clone()).await;
     
     bot.send_message(msg.chat.id, msg_timer_unset(&user_state.language)).await
 }
 
-/// This function sends all user information **in English language** about the chat to the chat
+/// Pauses or resumes the daily reminders and responses with a message. The timer itself is kept.
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `paused`: `true` to pause the reminders, `false` to resume them
+async fn bot_set_paused(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, paused: bool) -> Result<Message, RequestError> {
+    let mut user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+
+    user_state.paused = paused;
+    user_state_wrapper.update_and_persist(user_state.clone()).await;
+
+    if paused {
+        bot.send_message(msg.chat.id, msg_paused(&user_state.language)).await
+    } else {
+        bot.send_message(msg.chat.id, msg_resumed(&user_state.language)).await
+    }
+}
+
+/// Skips the reminder of today. The timer loop clears the date when it skips the reminder,
+/// and as only the current date is compared, the reminders continue after midnight in any case.
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn bot_skip_today(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let mut user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+
+    user_state.skip_date = Some(Local::now().date_naive());
+    user_state_wrapper.update_and_persist(user_state.clone()).await;
+    bot.send_message(msg.chat.id, msg_skipped_today(&user_state.language)).await
+}
+
+/// Enables the daily reminders in a group chat, which only receives reminders after this command
+/// (see `UserState::receives_reminders`). In private chats the reminders are always enabled.
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn bot_enable_group(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let mut user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+
+    if !is_group_chat(&msg.chat) {
+        return bot.send_message(msg.chat.id, msg_enable_private_chat(&user_state.language)).await;
+    }
+
+    user_state.is_group = true;
+    user_state.group_enabled = true;
+    user_state_wrapper.update_and_persist(user_state.clone()).await;
+    bot.send_message(msg.chat.id, msg_group_enabled(&user_state.language)).await
+}
+
+/// Checks whether the chat is a group or a supergroup
+fn is_group_chat(chat: &Chat) -> bool {
+    chat.is_group() || chat.is_supergroup()
+}
+
+/// Sends the configured timer and whether the reminders are paused
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn send_status(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+    bot.send_message(msg.chat.id, msg_status(&user_state.language, &user_state.timer, user_state.paused)).await
+}
+
+/// Sends when the timer of the chat sends the next reminder, or why no reminder is sent.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn send_next_reminder(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+    let now = Local::now().naive_local();
+
+    let text = match user_state.next_reminder(now) {
+        Some(next_reminder) => msg_next_reminder(&user_state.language, &next_reminder, &now.date()),
+        None if user_state.timer.is_none() => msg_next_reminder_no_timer(&user_state.language),
+        None if user_state.is_group && !user_state.group_enabled => msg_group_not_enabled(&user_state.language),
+        None => msg_next_reminder_paused(&user_state.language),
+    };
+    bot.send_message(msg.chat.id, text).await
+}
+
+/// Sends the version and the git commit of the running bot, the supported languages and a link to the source code,
+/// so that users and maintainers know which build is running.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn send_about(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let language = user_state_wrapper.find_userstate(msg.chat.id).await.language;
+    bot.send_message(msg.chat.id, msg_about(&language, VERSION, GIT_COMMIT, REPOSITORY_URL))
+        .parse_mode(MarkdownV2)
+        .await
+}
+
+/// This function sends all user information about the chat to the chat in the language of the chat.
+/// In a group chat only the settings of the group itself are sent (which are keyed by the ChatId of the group),
+/// never the data of single members.
 ///
 /// # Params
 /// - `bot`: The telegram bot (it can be cloned)
@@ -307,98 +1430,646 @@ async fn bot_unset_timer(bot: Bot, msg: Message, user_state_wrapper: Arc<UserSta
 /// - `user_state_wrapper_arc`: An Arc of the UserStateWrapper
 async fn send_user_information(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
     if user_state_wrapper.user_state_exists(msg.chat.id).await {
+        let user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+        let description = format!("{}\n{}", chat_type_description(&msg.chat, &user_state), member_since_description(&user_state));
         bot.send_message(
-                msg.chat.id, 
-                format!("The following data about you is saved on the server: \n\
-                \n\
-                ```\
-                {}\
-                ```\
-                ", serde_json::to_string_pretty(&user_state_wrapper.find_userstate(msg.chat.id).await).unwrap()
+                msg.chat.id,
+                msg_user_information(&user_state.language, &description, &serde_json::to_string_pretty(&user_state).unwrap())
             )
-        )
-        .parse_mode(MarkdownV2).await
+            .parse_mode(MarkdownV2).await
     } else {
-        bot.send_message(msg.chat.id, "There is currently no data saved on the server concerning you.").await
+        let user_state = UserState::new(msg.chat.id);
+        bot.send_message(msg.chat.id, format!("{}\n\n{}", chat_type_description(&msg.chat, &user_state), msg_no_data(&user_state.language))).await
     }
 }
 
+/// Describes in the language of the chat since when the user is registered (if this is known).
+fn member_since_description(user_state: &UserState) -> String {
+    match user_state.created_at {
+        Some(created_at) => msg_member_since(
+            &user_state.language,
+            &created_at.format("%Y-%m-%d").to_string(),
+            (Utc::now() - created_at).num_days()
+        ),
+        None => msg_member_since_unknown(&user_state.language),
+    }
+}
 
-async fn run_timer_thread_loop(bot_arc: Arc<Bot>, user_state_wrapper_arc: Arc<UserStateWrapper>) {
-    let mut last_run: Option<NaiveTime> = None;
-    log::info!("Start Timer thread");
-    
-    let control_c_pressed = tokio::spawn(
-        async {
-            let _ = signal::ctrl_c().await;
-            log::info!("Shutdown the timer");
+/// Describes in the language of the chat whether the chat is a private or a group chat.
+/// In groups the description also says whether the reminders have been enabled with `/enable`.
+fn chat_type_description(chat: &Chat, user_state: &UserState) -> String {
+    if !is_group_chat(chat) {
+        return msg_private_chat(&user_state.language);
+    }
+    if user_state.group_enabled {
+        msg_group_chat_enabled(&user_state.language)
+    } else {
+        msg_group_chat_not_enabled(&user_state.language)
+    }
+}
+
+
+/// Sends all data which is saved about the chat as a JSON document. Unlike `/userinformation`, the data is not
+/// sent as text, so it is not affected by the length limit and the escaping of MarkdownV2.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn export_user_data(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let user_state = user_state_wrapper.find_userstate(msg.chat.id).await;
+
+    if !user_state_wrapper.user_state_exists(msg.chat.id).await {
+        return bot.send_message(msg.chat.id, msg_no_data_to_export(&user_state.language)).await;
+    }
+
+    let json_bytes = serde_json::to_vec_pretty(&user_state).unwrap();
+    let file_name = format!("{}_{}_{}.json", EXPORT_FILE_PREFIX, msg.chat.id.0, Local::now().format("%Y-%m-%d"));
+    bot.send_document(msg.chat.id, InputFile::memory(json_bytes).file_name(file_name)).await
+}
+
+
+/// This function handles documents which are sent with `/importdata` as caption.
+///
+/// # Params (provided by the Dispatcher)
+/// - `bot`: the Teloxide Bot
+/// - `msg`: the message which contains the document
+/// - `user_state_wrapper`: The UserStateWrapper which allows to access the User State
+async fn answer_import_document(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> ResponseResult<()> {
+    import_user_data(bot, msg, user_state_wrapper).await?;
+    Ok(())
+}
+
+/// Restores the data of the chat from a JSON file which has been created with `/export`. The file is either
+/// attached to the message itself or to the message which the command replies to.
+/// Only the settings of the file are imported for the current chat (see `UserState::from_export`).
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn import_user_data(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let language = user_state_wrapper.find_userstate(msg.chat.id).await.language;
+
+    let document = match msg.document().or_else(|| msg.reply_to_message().and_then(|reply| reply.document())) {
+        Some(document) => document,
+        None => return bot.send_message(msg.chat.id, msg_error_import_no_document(&language)).await,
+    };
+    if document.file.size > IMPORT_MAX_FILE_SIZE {
+        return bot.send_message(msg.chat.id, msg_error_import_invalid(&language)).await;
+    }
+
+    let file = bot.get_file(document.file.id.clone()).await?;
+    let mut json_bytes: Vec<u8> = Vec::new();
+    bot.download_file(&file.path, &mut json_bytes).await?;
+
+    match UserState::from_export(&json_bytes, msg.chat.id) {
+        Ok(mut user_state) => {
+            user_state.is_group = is_group_chat(&msg.chat);
+            let language = user_state.language.clone();
+            user_state_wrapper.update_and_persist(user_state).await;
+            log::info!("Imported the data of {}", msg.chat.id.to_string());
+            bot.send_message(msg.chat.id, msg_data_imported(&language)).await
+        },
+        Err(error) => {
+            log::warn!("Could not import the data of {}: {}", msg.chat.id.to_string(), error.to_string());
+            bot.send_message(msg.chat.id, msg_error_import_invalid(&language)).await
+        }
+    }
+}
+
+
+/// Asks whether all data which is saved about the chat should really be deleted (with `/deletemydata`).
+/// The data is only deleted if the "Yes, delete" button is clicked within `DELETION_CONFIRMATION_MINUTES` minutes
+/// (see `confirm_deletion`), so a mistyped command does not lead to the loss of the data.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn delete_user_data(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let language = user_state_wrapper.find_userstate(msg.chat.id).await.language;
+
+    user_state_wrapper.request_deletion(msg.chat.id, Local::now().naive_local()).await;
+    let keyboard = InlineKeyboardMarkup::new(vec![vec![
+        InlineKeyboardButton::callback(msg_button_delete_confirm(&language), "delete_confirm"),
+        InlineKeyboardButton::callback(msg_button_delete_cancel(&language), "delete_cancel"),
+    ]]);
+    bot.send_message(msg.chat.id, msg_confirm_delete_data(&language)).reply_markup(keyboard).await
+}
+
+
+/// Deletes all data which is saved about the chat (including pending reminders) after the deletion has been
+/// confirmed with the "Yes, delete" button. If the deletion has not been requested within the last
+/// `DELETION_CONFIRMATION_MINUTES` minutes, nothing is deleted.
+/// As the UserState is deleted, the message is sent in the language which has been set before.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `chat_id`: The ChatId of the chat whose data should be deleted
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `message`: The message with the confirmation, whose buttons are removed
+async fn confirm_deletion(bot: Bot, chat_id: ChatId, user_state_wrapper: Arc<UserStateWrapper>, message: Option<MaybeInaccessibleMessage>) -> Result<Message, RequestError> {
+    remove_inline_keyboard(&bot, message).await;
+    let language = user_state_wrapper.find_userstate(chat_id).await.language;
+
+    if !user_state_wrapper.take_deletion_request(chat_id, Local::now().naive_local()).await {
+        return bot.send_message(chat_id, msg_deletion_expired(&language)).await;
+    }
+
+    if user_state_wrapper.remove_userstate(chat_id).await {
+        log::info!("Deleted all data of {}", chat_id.to_string());
+        bot.send_message(chat_id, msg_data_deleted(&language)).await
+    } else {
+        bot.send_message(chat_id, msg_no_data_to_delete(&language)).await
+    }
+}
+
+
+/// Aborts the requested deletion of the data (triggered by the "Cancel" button).
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `chat_id`: The ChatId of the chat which has requested the deletion
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `message`: The message with the confirmation, whose buttons are removed
+async fn cancel_deletion(bot: Bot, chat_id: ChatId, user_state_wrapper: Arc<UserStateWrapper>, message: Option<MaybeInaccessibleMessage>) -> Result<Message, RequestError> {
+    remove_inline_keyboard(&bot, message).await;
+    user_state_wrapper.take_deletion_request(chat_id, Local::now().naive_local()).await;
+
+    let language = user_state_wrapper.find_userstate(chat_id).await.language;
+    bot.send_message(chat_id, msg_deletion_cancelled(&language)).await
+}
+
+
+/// Removes the inline keyboard of a message, so that its buttons can not be clicked again.
+async fn remove_inline_keyboard(bot: &Bot, message: Option<MaybeInaccessibleMessage>) {
+    if let Some(message) = message {
+        if let Err(error) = bot.edit_message_reply_markup(message.chat().id, message.id()).await {
+            log::warn!("Could not remove the buttons of the message {}: {}", message.id().0, error.to_string());
+        }
+    }
+}
+
+
+/// Forwards the feedback of a user together with the ChatId and the username to the operator chat
+/// (see `feedback_chat_id`). The text of the user is escaped for MarkdownV2 and truncated,
+/// so that the forwarded message does not exceed the length limit of Telegram.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `text`: The feedback of the user
+async fn send_feedback(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, text: String) -> Result<Message, RequestError> {
+    let language = user_state_wrapper.find_userstate(msg.chat.id).await.language;
+
+    let feedback_chat_id = match feedback_chat_id() {
+        Some(chat_id) => chat_id,
+        None => return bot.send_message(msg.chat.id, msg_feedback_unavailable(&language)).await,
+    };
+    if text.trim().is_empty() {
+        return bot.send_message(msg.chat.id, msg_error_feedback_empty(&language)).await;
+    }
+
+    let sender = match &msg.from {
+        Some(user) => match &user.username {
+            Some(username) => format!("@{}", username),
+            None => user.full_name(),
+        },
+        None => String::from("unknown"),
+    };
+    let header = format!("*📬 Feedback* from {} \\(chat id {}\\)\n\n", escape(&sender), escape(&msg.chat.id.to_string()));
+    let feedback = format!("{}{}", header, escape_truncated(text.trim(), TELEGRAM_MESSAGE_LIMIT - header.encode_utf16().count()));
+
+    send_with_retry(bot.send_message(feedback_chat_id, feedback).parse_mode(MarkdownV2)).await?;
+    log::info!("Forwarded the feedback of {}", msg.chat.id.to_string());
+    bot.send_message(msg.chat.id, msg_feedback_sent(&language)).await
+}
+
+
+/// Returns the ChatId where the feedback of the users is forwarded to. This is the chat in `FEEDBACK_CHAT_ID`
+/// or, if it is not set, the administrator in `DAILYBIBLE_ADMIN_ID`.
+fn feedback_chat_id() -> Option<ChatId> {
+    let chat_id_string = env::var(FEEDBACK_CHAT_ID_ENV).or_else(|_| env::var(ADMIN_ID_ENV)).ok()?;
+    chat_id_string.trim().parse::<i64>().ok().map(ChatId)
+}
+
+
+/// Checks whether the given ChatId is one of the administrators configured in `ADMIN_CHAT_IDS` or `DAILYBIBLE_ADMIN_ID`.
+fn is_admin(chat_id: ChatId) -> bool {
+    admin_chat_ids().contains(&chat_id)
+}
+
+
+/// Returns the ChatIds of all administrators configured in `ADMIN_CHAT_IDS` and `DAILYBIBLE_ADMIN_ID`.
+fn admin_chat_ids() -> Vec<ChatId> {
+    let admin_chat_ids_string = format!(
+        "{},{}",
+        env::var(ADMIN_CHAT_IDS_ENV).unwrap_or_default(),
+        env::var(ADMIN_ID_ENV).unwrap_or_default()
+    );
+
+    admin_chat_ids_string
+        .split(',')
+        .filter_map(|admin_chat_id| admin_chat_id.trim().parse::<i64>().ok())
+        .map(ChatId)
+        .collect()
+}
+
+
+/// Sends the usage statistics **in English language** to an administrator.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+async fn send_stats(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>) -> Result<Message, RequestError> {
+    let stats = user_state_wrapper.stats().await;
+
+    let mut stats_string = format!(
+        "Users: {}\nUsers with timer: {}\nActive users: {}\n",
+        stats.total_users,
+        stats.users_with_timer,
+        stats.active_users
+    );
+    for (language, count) in stats.users_per_language.iter() {
+        stats_string.push_str(&format!("{:?}: {}\n", language, count));
+    }
+    if !stats.timers_per_hour.is_empty() {
+        stats_string.push_str("\nTimers per hour:\n");
+        for (hour, count) in stats.timers_per_hour.iter() {
+            stats_string.push_str(&format!("{:02}:00: {}\n", hour, count));
         }
+    }
+
+    bot.send_message(msg.chat.id, stats_string).await
+}
+
+
+/// Sends the number of reminders which have been sent per language **in English language** to an administrator.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `reminder_metrics`: An Arc of the ReminderMetrics
+async fn send_reminder_metrics(bot: Bot, msg: Message, reminder_metrics: Arc<ReminderMetrics>) -> Result<Message, RequestError> {
+    let reminders_per_language = reminder_metrics.reminders_per_language().await;
+
+    let mut metrics_string = format!(
+        "Reminders sent: {}\n",
+        reminders_per_language.iter().map(|(_, count)| count).sum::<u64>()
     );
+    for (language, count) in reminders_per_language.iter() {
+        metrics_string.push_str(&format!("{:?}: {}\n", language, count));
+    }
+
+    bot.send_message(msg.chat.id, metrics_string).await
+}
+
+
+/// Reloads the schedules of the reading plans (see `biblereading::reload_plans`) and reports **in English language**
+/// to an administrator whether they have been replaced. A schedule with problems is not used, the previous one is kept.
+/// This is the manual alternative to `run_schedule_watch_loop` for file systems where the modification time is not reliable.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+async fn reload_schedule(bot: Bot, msg: Message) -> Result<Message, RequestError> {
+    if biblereading::reload_plans() {
+        let file_path = biblereading::plan_file_paths().join(", ");
+        bot.send_message(msg.chat.id, format!("The schedule {} has been reloaded and is used now.", file_path)).await
+    } else {
+        bot.send_message(msg.chat.id, "The schedule contains problems and has not been replaced, see the log for details.").await
+    }
+}
+
+
+/// Sends a message to all users and reports the number of successful and failed sends to the administrator.
+/// Users who blocked the bot or deactivated their account are removed. Between two messages the function
+/// waits `BROADCAST_DELAY` to avoid hitting the flood limits of Telegram.
+///
+/// # Params
+/// - `bot`: The telegram bot (it can be cloned)
+/// - `msg`: The message which contained the command
+/// - `user_state_wrapper`: An Arc of the UserStateWrapper
+/// - `text`: The text which should be sent to all users
+async fn broadcast(bot: Bot, msg: Message, user_state_wrapper: Arc<UserStateWrapper>, text: String) -> Result<Message, RequestError> {
+    // The ChatIds are copied, so that the lock is not held while sending (unreachable users are removed in between)
+    let chat_ids: Vec<ChatId> = user_state_wrapper.user_states.read().await.keys().copied().collect();
+    let mut successes = 0;
+    let mut failures = 0;
+
+    for chat_id in chat_ids {
+        match bot.send_message(chat_id, text.clone()).await {
+            Ok(_) => successes += 1,
+            Err(error) => {
+                failures += 1;
+                log::warn!("Could not send the broadcast to {}: {}", chat_id.to_string(), error.to_string());
+                if is_chat_unreachable(&error) {
+                    user_state_wrapper.remove_userstate(chat_id).await;
+                    log::info!("Removed {} because the chat is not reachable anymore", chat_id.to_string());
+                }
+            }
+        }
+        tokio::time::sleep(BROADCAST_DELAY).await;
+    }
+
+    bot.send_message(msg.chat.id, format!("The broadcast has been sent to {} chat(s), {} failed.", successes, failures)).await
+}
+
+
+/// Checks whether the error means that the bot can never reach the chat again
+/// (because the user blocked the bot, deactivated the account or removed the bot from the group).
+fn is_chat_unreachable(error: &RequestError) -> bool {
+    matches!(
+        error,
+        RequestError::Api(ApiError::BotBlocked | ApiError::UserDeactivated | ApiError::BotKicked | ApiError::BotKickedFromSupergroup)
+    )
+}
+
+
+/// Returns the start of the minute of the given time (with zero seconds).
+fn start_of_minute(date_time: NaiveDateTime) -> NaiveDateTime {
+    date_time.with_second(0).and_then(|date_time| date_time.with_nanosecond(0)).unwrap_or(date_time)
+}
+
+/// Returns the time until the deadline, or zero if the deadline has already passed.
+fn duration_until(deadline: NaiveDateTime, now: NaiveDateTime) -> time::Duration {
+    (deadline - now).to_std().unwrap_or(time::Duration::ZERO)
+}
+
+async fn run_timer_thread_loop(notifier_arc: Arc<dyn Notifier>, user_state_wrapper_arc: Arc<UserStateWrapper>, metrics_arc: Arc<Metrics>, reminder_metrics_arc: Arc<ReminderMetrics>, shutdown: CancellationToken) {
+    log::info!("Start Timer thread");
+    let send_limiter = Arc::new(SendLimiter::from_env());
+    log::info!("Sending at most {} reminder(s) at the same time, {} ms apart", send_limiter.concurrency(), send_limiter.delay().as_millis());
+    
     log::info!("Start the Loop");
-    while !control_c_pressed.is_finished() {
-        let now = chrono::offset::Local::now().naive_local().time();
+    // Every tick processes the minute after the previous one, so that no minute is skipped if sending takes longer
+    let mut now_date_time = start_of_minute(chrono::offset::Local::now().naive_local());
+    while !shutdown.is_cancelled() {
+        // The date and the time are taken from the same instant, so that they match around midnight
+        let now = now_date_time.time();
+        let today = now_date_time.date();
         log::info!(
             "Start timer for {}", now.to_string()
         );
 
-        // We make sure that the real timer task is only runned once per minute.
-        if last_run.is_none() || last_run.unwrap().hour() != now.hour() || last_run.unwrap().minute() != now.minute() {
-            let unlocked_user_state_wrapper = user_state_wrapper_arc.clone();
-            
-            for u in unlocked_user_state_wrapper.user_states.read().await.iter() {
-                if u.timer.is_some() && u.timer.unwrap().hour() == now.hour() && u.timer.unwrap().minute() == now.minute() {
-                    log::info!("Send Reminder");
-
-                    // We have to clone all the variables which are needed for the `send_daily-reminder`-function because they will be consumed 
-                    // by the spawned task.
-                    let bot_arc_clone = bot_arc.clone();
-                    let user_state_wrapper_arc_clone = user_state_wrapper_arc.clone();
-                    let u_clone = u.clone();
-                    tokio::spawn(
-                        async move { 
-                            match send_daily_reminder(bot_arc_clone.deref().clone(), u_clone.chat_id, user_state_wrapper_arc_clone).await {
-                                Ok(_) => log::info!("Sending completed"),
-                                Err(_) => log::info!("There was an error"),
-                            } 
-                        } 
-                    );
-                }   
+        let tick_metrics = Arc::new(TickMetrics::new(metrics_arc.clone()));
+        let (due_user_states, inactive_user_states): (Vec<UserState>, Vec<UserState>) = user_state_wrapper_arc.user_states.read().await.values()
+            .filter(|u| u.timer.is_some() && u.timer.unwrap().hour() == now.hour() && u.timer.unwrap().minute() == now.minute())
+            .cloned()
+            .partition(|u| u.receives_reminders());
+        let mut users_skipped = inactive_user_states.len() as u64;
+
+        let mut due_chat_ids: Vec<ChatId> = Vec::new();
+        let mut summary_chat_ids: Vec<ChatId> = Vec::new();
+        for user_state in due_user_states {
+            // The weekly summary is sent even if today's reminder is skipped
+            if user_state.is_weekly_summary_due(today) {
+                summary_chat_ids.push(user_state.chat_id);
+            }
+
+            // After a restart within the same minute the reminder has already been sent
+            if user_state.received_reminder_on(today) {
+                log::info!(chat_id = user_state.chat_id.0, status = "already_sent"; "The reminder of {} has already been sent today", user_state.chat_id.to_string());
+                continue;
+            }
+
+            if user_state.skips_reminder(today) {
+                log::info!(chat_id = user_state.chat_id.0, language:? = user_state.language, status = "skipped"; "Skip today's reminder of {}", user_state.chat_id.to_string());
+                users_skipped += 1;
+                user_state_wrapper_arc.update_with(user_state.chat_id, |user_state| user_state.skip_date = None).await;
+            } else {
+                due_chat_ids.push(user_state.chat_id);
             }
         }
-        last_run = Some(now);
-        tokio::time::sleep(time::Duration::from_secs(5)).await;
+        // The reminders are marked before they are sent, so that they are not sent twice if the bot stops while sending
+        user_state_wrapper_arc.mark_reminders_sent(&due_chat_ids, today).await;
+        due_chat_ids.append(&mut user_state_wrapper_arc.take_due_snoozes(now_date_time).await);
+        tick_metrics.record_users_skipped(users_skipped);
+
+        // A chat whose timer and snooze are due at the same time only receives one reminder
+        let mut unique_chat_ids: Vec<ChatId> = Vec::new();
+        for chat_id in due_chat_ids {
+            if user_state_wrapper_arc.claim_reminder(chat_id, now_date_time).await {
+                unique_chat_ids.push(chat_id);
+            }
+        }
+
+        // The order is shuffled, so that the same users are not always the last ones of a large burst
+        unique_chat_ids.shuffle(&mut rand::thread_rng());
+
+        let mut reminder_tasks = Vec::new();
+        for (index, chat_id) in unique_chat_ids.into_iter().enumerate() {
+            log::info!(chat_id = chat_id.0, status = "due"; "Send Reminder to {}", chat_id.to_string());
+
+            // We have to clone all the variables which are needed for the `send_daily-reminder`-function because they will be consumed 
+            // by the spawned task.
+            let notifier_arc_clone = notifier_arc.clone();
+            let user_state_wrapper_arc_clone = user_state_wrapper_arc.clone();
+            let tick_metrics_clone = tick_metrics.clone();
+            let reminder_metrics_arc_clone = reminder_metrics_arc.clone();
+            let send_limiter_clone = send_limiter.clone();
+
+            // The reminders are staggered and limited, so that many users with the same timer do not hit the flood limits
+            // of Telegram at once. Remaining `RetryAfter` errors are handled by `send_with_retry`.
+            reminder_tasks.push(tokio::spawn(
+                async move { 
+                    let reminder = send_daily_reminder(notifier_arc_clone, chat_id, user_state_wrapper_arc_clone, Some((tick_metrics_clone, reminder_metrics_arc_clone)));
+                    match send_limiter_clone.run(index, reminder).await {
+                        Ok(_) => log::info!(chat_id = chat_id.0, status = "completed"; "Sending completed"),
+                        Err(error) => log::error!(chat_id = chat_id.0, status = "failed", error = error.to_string(); "Could not send the reminder to {}: {}", chat_id.to_string(), error.to_string()),
+                    } 
+                } 
+            ));
+        }
+
+        // The weekly summaries follow the reminders of this minute. They are marked as sent beforehand,
+        // so that a summary is never sent twice in the same week.
+        let reminder_count = reminder_tasks.len();
+        for (index, chat_id) in summary_chat_ids.into_iter().enumerate() {
+            user_state_wrapper_arc.update_with(chat_id, |user_state| user_state.last_weekly_summary = Some(today)).await;
+            let user_state = user_state_wrapper_arc.find_userstate(chat_id).await;
+
+            let notifier_arc_clone = notifier_arc.clone();
+            let send_limiter_clone = send_limiter.clone();
+            reminder_tasks.push(tokio::spawn(
+                async move {
+                    let summary = send_weekly_summary(notifier_arc_clone.as_ref(), &user_state, today);
+                    if let Err(error) = send_limiter_clone.run(reminder_count + index, summary).await {
+                        log::error!(chat_id = chat_id.0, status = "summary_failed", error = error.to_string(); "Could not send the weekly summary to {}: {}", chat_id.to_string(), error.to_string());
+                    }
+                }
+            ));
+        }
+
+        // The summary is logged once all (staggered) reminders of this minute have been sent
+        if !reminder_tasks.is_empty() || users_skipped > 0 {
+            let tick_time = now.format("%H:%M").to_string();
+            tokio::spawn(async move {
+                for reminder_task in reminder_tasks {
+                    let _ = reminder_task.await;
+                }
+                log::info!(
+                    time = tick_time.as_str(),
+                    reminders_sent = tick_metrics.reminders_sent(),
+                    reminders_failed = tick_metrics.reminders_failed(),
+                    polls_sent = tick_metrics.polls_sent(),
+                    users_skipped = tick_metrics.users_skipped();
+                    "Reminder summary time={} {}", tick_time, tick_metrics
+                );
+            });
+        }
+
+        // The loop wakes up once at the start of every minute, so every timer is checked exactly once.
+        // If the next minute has already started, it is processed without waiting.
+        now_date_time += chrono::Duration::minutes(1);
+        let current_date_time = chrono::offset::Local::now().naive_local();
+        if current_date_time - now_date_time >= chrono::Duration::minutes(1) {
+            log::warn!("The timer is {} minute(s) behind, catching up", (current_date_time - now_date_time).num_minutes());
+        }
+        tokio::select! {
+            _ = tokio::time::sleep(duration_until(now_date_time, current_date_time)) => {},
+            _ = shutdown.cancelled() => {}
+        }
     }
+    log::info!("Shutdown the timer");
 }
 
-async fn run_save_userstate_loop(user_state_wrapper_arc: Arc<UserStateWrapper>) {
-    let control_c_pressed = tokio::spawn(
-        async {
-            let _ = signal::ctrl_c().await;
-            log::info!("Shutdown the user state saver timer");
+/// Checks every `SCHEDULE_CHECK_INTERVAL` whether a schedule file has been modified and reloads the schedules
+/// (see `biblereading::reload_plans`), so that an updated schedule is used without a restart.
+/// A changed schedule with problems is logged and not used, the previous schedule stays in use.
+async fn run_schedule_watch_loop(shutdown: CancellationToken) {
+    let mut last_modified_time = biblereading::schedule_modified_time();
+
+    while !shutdown.is_cancelled() {
+        tokio::select! {
+            _ = tokio::time::sleep(SCHEDULE_CHECK_INTERVAL) => {},
+            _ = shutdown.cancelled() => break
         }
-    );
+
+        let modified_time = biblereading::schedule_modified_time();
+        if modified_time != last_modified_time {
+            log::info!("The schedule {} has changed, reloading it.", biblereading::plan_file_paths().join(", "));
+            if biblereading::reload_plans() {
+                log::info!("The changed schedule has been loaded successfully.");
+            }
+            last_modified_time = modified_time;
+        }
+    }
+    log::info!("Shutdown the schedule watcher");
+}
+
+/// Saves the reminder metrics and, if `save_user_states` is set, the user states periodically until `shutdown` is cancelled.
+/// The final save is not performed here but by `handle_final_save` after all loops have stopped.
+async fn run_save_userstate_loop(user_state_wrapper_arc: Arc<UserStateWrapper>, reminder_metrics_arc: Arc<ReminderMetrics>, save_user_states: bool, shutdown: CancellationToken) {
+    let save_interval = env::var(SAVE_INTERVAL_ENV)
+        .ok()
+        .and_then(|interval_string| interval_string.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_SAVE_INTERVAL_SECONDS);
+    log::info!("Save the user states every {} seconds if they have changed", save_interval);
 
     loop {
         let cloned_user_state_wrapper_arc = user_state_wrapper_arc.clone();
+        let cloned_reminder_metrics_arc = reminder_metrics_arc.clone();
         tokio::spawn(
             async move {
-                handle_save_current_userstates(cloned_user_state_wrapper_arc).await;
+                if save_user_states {
+                    handle_save_current_userstates(cloned_user_state_wrapper_arc).await;
+                }
+                handle_save_reminder_metrics(cloned_reminder_metrics_arc).await;
             }
         );
 
-        tokio::time::sleep(time::Duration::from_secs(30)).await;
-        if control_c_pressed.is_finished() {
-            handle_save_current_userstates(user_state_wrapper_arc.clone()).await;               
-            break;
+        tokio::select! {
+            _ = tokio::time::sleep(time::Duration::from_secs(save_interval)) => {},
+            _ = shutdown.cancelled() => {
+                log::info!("Shutdown the user state saver timer");
+                break;
+            }
         }
     }
 }
 
-async fn handle_save_current_userstates(user_state_wrapper_arc: Arc<UserStateWrapper>) {
-    let user_state_file = env::var(USER_STATE_ENV).unwrap_or(DEFAULT_USER_STATE_FILE_PATH.to_string());
+/// Waits until the process receives either Ctrl-C (SIGINT) or, on unix systems, SIGTERM
+/// (which is sent by systemd or Docker to stop the bot).
+async fn wait_for_shutdown_signal() {
+    let ctrl_c = async {
+        let _ = signal::ctrl_c().await;
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match signal::unix::signal(signal::unix::SignalKind::terminate()) {
+            Ok(mut sigterm) => { sigterm.recv().await; },
+            Err(error) => {
+                log::warn!("Could not listen for SIGTERM: {}", error.to_string());
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
 
-    match user_state_wrapper_arc.write_states_to_file(&user_state_file).await {
-        Ok(_) => log::info!("Saved user states to {}", user_state_file),
+async fn handle_save_current_userstates(user_state_wrapper_arc: Arc<UserStateWrapper>) {
+    match user_state_wrapper_arc.save_states_if_dirty().await {
+        Ok(true) => log::info!("Saved user states"),
+        Ok(false) => {},
         Err(error) => log::warn!("Could not save user state file: {}", error.to_string())
     }
 }
+
+async fn handle_save_reminder_metrics(reminder_metrics_arc: Arc<ReminderMetrics>) {
+    match reminder_metrics_arc.save_if_dirty().await {
+        Ok(true) => log::info!("Saved reminder metrics"),
+        Ok(false) => {},
+        Err(error) => log::warn!("Could not save reminder metrics file: {}", error.to_string())
+    }
+}
+
+/// Saves the user states and the reminder metrics a last time before the bot exits.
+/// In contrast to the periodic save, the user states are saved even if they seem to be unchanged.
+async fn handle_final_save(user_state_wrapper_arc: Arc<UserStateWrapper>, reminder_metrics_arc: Arc<ReminderMetrics>) {
+    match user_state_wrapper_arc.save_states().await {
+        Ok(_) => log::info!("Final save of the user states completed successfully."),
+        Err(error) => log::error!("The final save of the user states failed: {}", error.to_string())
+    }
+    handle_save_reminder_metrics(reminder_metrics_arc).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a click on the button `mark_read` of user 111, optionally on a message in the given chat
+    fn button_callback(message_chat: Option<&str>) -> CallbackQuery {
+        let message = message_chat.map(|chat| format!(
+            r#", "message": {{"message_id": 5, "date": 1725177600, "chat": {}, "text": "Reading"}}"#, chat
+        ));
+        let json = format!(
+            r#"{{"id": "1", "from": {{"id": 111, "is_bot": false, "first_name": "Anna"}}, "chat_instance": "1", "data": "mark_read"{}}}"#,
+            message.unwrap_or_default()
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn callback_concerns_chat_of_message() {
+        // In a group the button changes the state of the group, not the one of the user who clicked
+        let callback = button_callback(Some(r#"{"id": -100222, "type": "supergroup", "title": "Bible group"}"#));
+        assert_eq!(callback_chat_id(&callback), ChatId(-100222));
+
+        let callback = button_callback(Some(r#"{"id": 111, "type": "private", "first_name": "Anna"}"#));
+        assert_eq!(callback_chat_id(&callback), ChatId(111));
+
+        assert_eq!(callback_chat_id(&button_callback(None)), ChatId(111));
+    }
+}
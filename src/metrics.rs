@@ -0,0 +1,302 @@
+//! In this unit, the counters about sent reminders are implemented which are exposed by the health check server,
+//! as well as the durable reminder counters per language which are reported to the administrators.
+
+use std::{collections::HashMap, fmt, sync::{atomic::{AtomicBool, AtomicI32, AtomicU64, Ordering}, Arc}};
+
+use chrono::{Datelike, NaiveDate};
+use tokio::sync::RwLock;
+
+use crate::error::DailyBibleError;
+use crate::localize::Language;
+
+
+/// Counters about the reminders which have been sent. All counters are atomic, so they can be updated
+/// by the spawned reminder tasks without locking.
+#[derive(Default)]
+pub struct Metrics {
+    /// The day (as number of days since the Common Era) for which `reminders_sent_today` is counted
+    day: AtomicI32,
+    reminders_sent_today: AtomicU64,
+    send_failures: AtomicU64,
+    polls_sent: AtomicU64,
+    users_skipped: AtomicU64,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Metrics::default()
+    }
+
+    /// Counts a reminder which has been sent successfully on the given date.
+    pub fn record_reminder_sent(&self, today: NaiveDate) {
+        self.roll_over(today);
+        self.reminders_sent_today.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Counts a message which could not be sent.
+    pub fn record_send_failure(&self) {
+        self.send_failures.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Counts a poll which has been sent after a reminder.
+    pub fn record_poll_sent(&self) {
+        self.polls_sent.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Counts users whose timer was due but who did not receive a reminder (paused, not enabled or skipped).
+    pub fn record_users_skipped(&self, count: u64) {
+        self.users_skipped.fetch_add(count, Ordering::SeqCst);
+    }
+
+    /// Returns the number of reminders which have been sent on the given date.
+    pub fn reminders_sent_today(&self, today: NaiveDate) -> u64 {
+        self.roll_over(today);
+        self.reminders_sent_today.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of messages which could not be sent since the start of the bot.
+    pub fn send_failures(&self) -> u64 {
+        self.send_failures.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of polls which have been sent since the start of the bot.
+    pub fn polls_sent(&self) -> u64 {
+        self.polls_sent.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of skipped users since the start of the bot.
+    pub fn users_skipped(&self) -> u64 {
+        self.users_skipped.load(Ordering::SeqCst)
+    }
+
+    /// Resets the daily counter if the given date is another day than the one which is counted.
+    fn roll_over(&self, today: NaiveDate) {
+        let day = today.num_days_from_ce();
+        if self.day.swap(day, Ordering::SeqCst) != day {
+            self.reminders_sent_today.store(0, Ordering::SeqCst);
+        }
+    }
+}
+
+
+/// The counters of the reminders of a single timer tick, which are logged as one summary line after all reminders
+/// of the tick have been sent. Every recorded event is also counted in the global `Metrics`.
+pub struct TickMetrics {
+    metrics: Arc<Metrics>,
+    reminders_sent: AtomicU64,
+    reminders_failed: AtomicU64,
+    polls_sent: AtomicU64,
+    users_skipped: AtomicU64,
+}
+
+impl TickMetrics {
+    pub fn new(metrics: Arc<Metrics>) -> Self {
+        TickMetrics {
+            metrics,
+            reminders_sent: AtomicU64::new(0),
+            reminders_failed: AtomicU64::new(0),
+            polls_sent: AtomicU64::new(0),
+            users_skipped: AtomicU64::new(0),
+        }
+    }
+
+    /// Counts a reminder which has been sent successfully on the given date.
+    pub fn record_reminder_sent(&self, today: NaiveDate) {
+        self.metrics.record_reminder_sent(today);
+        self.reminders_sent.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Counts a reminder or poll which could not be sent.
+    pub fn record_send_failure(&self) {
+        self.metrics.record_send_failure();
+        self.reminders_failed.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Counts a poll which has been sent after a reminder.
+    pub fn record_poll_sent(&self) {
+        self.metrics.record_poll_sent();
+        self.polls_sent.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Counts users whose timer was due but who did not receive a reminder.
+    pub fn record_users_skipped(&self, count: u64) {
+        self.metrics.record_users_skipped(count);
+        self.users_skipped.fetch_add(count, Ordering::SeqCst);
+    }
+
+    /// Returns the number of reminders which have been sent in this minute.
+    pub fn reminders_sent(&self) -> u64 {
+        self.reminders_sent.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of reminders and polls which could not be sent in this minute.
+    pub fn reminders_failed(&self) -> u64 {
+        self.reminders_failed.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of polls which have been sent in this minute.
+    pub fn polls_sent(&self) -> u64 {
+        self.polls_sent.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of users who did not receive a reminder in this minute.
+    pub fn users_skipped(&self) -> u64 {
+        self.users_skipped.load(Ordering::SeqCst)
+    }
+}
+
+impl fmt::Display for TickMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "reminders_sent={} reminders_failed={} polls_sent={} users_skipped={}",
+            self.reminders_sent(),
+            self.reminders_failed(),
+            self.polls_sent(),
+            self.users_skipped()
+        )
+    }
+}
+
+
+/// The number of reminders which have been sent per language since the counters were started.
+/// In contrast to `Metrics`, these counters are saved to a JSON file, so they survive restarts.
+pub struct ReminderMetrics {
+    file_path: String,
+    reminders_per_language: RwLock<HashMap<Language, u64>>,
+    dirty: AtomicBool,
+}
+
+impl ReminderMetrics {
+    /// Creates empty counters which are saved to the given file.
+    pub fn new(file_path: &str) -> Self {
+        ReminderMetrics {
+            file_path: file_path.to_string(),
+            reminders_per_language: RwLock::new(HashMap::new()),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    /// Loads the counters from the given file. If the file can not be read, the counters start at zero.
+    pub async fn load(file_path: &str) -> Self {
+        let reminder_metrics = ReminderMetrics::new(file_path);
+
+        let loaded: Result<HashMap<Language, u64>, DailyBibleError> = async {
+            let file_string = tokio::fs::read_to_string(file_path).await?;
+            Ok(serde_json::from_str(&file_string)?)
+        }.await;
+
+        match loaded {
+            Ok(reminders_per_language) => *reminder_metrics.reminders_per_language.write().await = reminders_per_language,
+            Err(error) => log::warn!("Could not load the reminder metrics from {}: {}", file_path, error.to_string()),
+        }
+        reminder_metrics
+    }
+
+    /// Counts a reminder which has been sent successfully in the given language.
+    pub async fn record_reminder_sent(&self, language: &Language) {
+        *self.reminders_per_language.write().await.entry(language.clone()).or_insert(0) += 1;
+        self.dirty.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns the number of sent reminders per language, sorted by the number of reminders (descending).
+    pub async fn reminders_per_language(&self) -> Vec<(Language, u64)> {
+        let mut reminders_per_language: Vec<(Language, u64)> = self.reminders_per_language.read().await
+            .iter()
+            .map(|(language, count)| (language.clone(), *count))
+            .collect();
+        reminders_per_language.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        reminders_per_language
+    }
+
+    /// Saves the counters to the file if they have changed since the last save.
+    ///
+    /// # Returns
+    /// `Ok(true)` if the counters have been saved, `Ok(false)` if nothing has changed.
+    pub async fn save_if_dirty(&self) -> Result<bool, DailyBibleError> {
+        if !self.dirty.swap(false, Ordering::SeqCst) {
+            return Ok(false);
+        }
+
+        let json_string = serde_json::to_string_pretty(&*self.reminders_per_language.read().await)?;
+        if let Err(error) = tokio::fs::write(&self.file_path, json_string).await {
+            self.dirty.store(true, Ordering::SeqCst);
+            return Err(error.into());
+        }
+        Ok(true)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Removes the file of a test when the test ends, even if it fails.
+    struct TestfileHandling(&'static str);
+
+    impl Drop for TestfileHandling {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(self.0);
+        }
+    }
+
+    #[test]
+    fn test_daily_counter_is_reset() {
+        let metrics = Metrics::new();
+        let today = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+
+        metrics.record_reminder_sent(today);
+        metrics.record_reminder_sent(today);
+        metrics.record_send_failure();
+        assert_eq!(metrics.reminders_sent_today(today), 2);
+
+        // On the next day only the daily counter starts again
+        let tomorrow = today.succ_opt().unwrap();
+        assert_eq!(metrics.reminders_sent_today(tomorrow), 0);
+        assert_eq!(metrics.send_failures(), 1);
+    }
+
+    #[test]
+    fn test_tick_metrics_update_global_metrics() {
+        let metrics = Arc::new(Metrics::new());
+        let today = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+
+        let tick_metrics = TickMetrics::new(metrics.clone());
+        tick_metrics.record_reminder_sent(today);
+        tick_metrics.record_reminder_sent(today);
+        tick_metrics.record_poll_sent();
+        tick_metrics.record_send_failure();
+        tick_metrics.record_users_skipped(3);
+        assert_eq!(tick_metrics.to_string(), "reminders_sent=2 reminders_failed=1 polls_sent=1 users_skipped=3");
+
+        // The next tick starts at zero, while the global counters keep counting
+        let tick_metrics = TickMetrics::new(metrics.clone());
+        tick_metrics.record_poll_sent();
+        assert_eq!(tick_metrics.to_string(), "reminders_sent=0 reminders_failed=0 polls_sent=1 users_skipped=0");
+        assert_eq!(metrics.reminders_sent_today(today), 2);
+        assert_eq!(metrics.polls_sent(), 2);
+        assert_eq!(metrics.users_skipped(), 3);
+        assert_eq!(metrics.send_failures(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_reminder_metrics_survive_restart() {
+        const TEST_FILE_PATH: &str = "testfile_reminder_metrics.json";
+        let _tfh = TestfileHandling(TEST_FILE_PATH);
+
+        let reminder_metrics = ReminderMetrics::new(TEST_FILE_PATH);
+        assert!(!reminder_metrics.save_if_dirty().await.unwrap());
+
+        reminder_metrics.record_reminder_sent(&Language::German).await;
+        reminder_metrics.record_reminder_sent(&Language::German).await;
+        reminder_metrics.record_reminder_sent(&Language::English).await;
+        assert!(reminder_metrics.save_if_dirty().await.unwrap());
+
+        let loaded = ReminderMetrics::load(TEST_FILE_PATH).await;
+        assert_eq!(
+            loaded.reminders_per_language().await,
+            vec![(Language::German, 2), (Language::English, 1)]
+        );
+    }
+}
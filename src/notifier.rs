@@ -0,0 +1,102 @@
+//! In this unit, the sending of the daily reminders is abstracted with the `Notifier` trait, so that the reminders
+//! can be logged instead of sent in a dry run (for local development and tests without access to Telegram).
+
+use async_trait::async_trait;
+use teloxide::{prelude::*, types::{InlineKeyboardMarkup, MessageId, ParseMode}, RequestError};
+
+use crate::retry::send_with_retry;
+
+
+/// A poll which has been sent, so that the answers of the user can be assigned to the reminder
+#[derive(Clone, Debug, PartialEq)]
+pub struct PollMessage {
+    pub message_id: MessageId,
+    pub poll_id: String,
+}
+
+
+/// Sends the messages of the daily reminder to a chat.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// Sends a text message with the given parse mode (plain text if it is `None`)
+    /// and an optional inline keyboard below the message.
+    async fn send_text(&self, chat_id: ChatId, text: String, parse_mode: Option<ParseMode>, keyboard: Option<InlineKeyboardMarkup>) -> Result<(), RequestError>;
+
+    /// Sends a poll with the given question and options.
+    ///
+    /// # Returns
+    /// The sent poll, or `None` if no poll has been sent to Telegram (so there will be no answers).
+    async fn send_poll(&self, chat_id: ChatId, question: String, options: Vec<String>, is_anonymous: bool) -> Result<Option<PollMessage>, RequestError>;
+}
+
+
+/// Sends the messages to Telegram. Transient errors are retried with `send_with_retry`.
+pub struct TelegramNotifier {
+    bot: Bot,
+}
+
+impl TelegramNotifier {
+    pub fn new(bot: Bot) -> Self {
+        TelegramNotifier { bot }
+    }
+}
+
+#[async_trait]
+impl Notifier for TelegramNotifier {
+    async fn send_text(&self, chat_id: ChatId, text: String, parse_mode: Option<ParseMode>, keyboard: Option<InlineKeyboardMarkup>) -> Result<(), RequestError> {
+        let mut request = self.bot.send_message(chat_id, text);
+        if let Some(parse_mode) = parse_mode {
+            request = request.parse_mode(parse_mode);
+        }
+        if let Some(keyboard) = keyboard {
+            request = request.reply_markup(keyboard);
+        }
+        send_with_retry(request).await?;
+        Ok(())
+    }
+
+    async fn send_poll(&self, chat_id: ChatId, question: String, options: Vec<String>, is_anonymous: bool) -> Result<Option<PollMessage>, RequestError> {
+        let message = send_with_retry(
+            self.bot.send_poll(chat_id, question, options).is_anonymous(is_anonymous)
+        ).await?;
+
+        Ok(message.poll().map(|poll| PollMessage {
+            message_id: message.id,
+            poll_id: poll.id.clone(),
+        }))
+    }
+}
+
+
+/// Only logs the messages which would be sent, without any request to Telegram (selected with `DRY_RUN=1`).
+pub struct LoggingNotifier;
+
+#[async_trait]
+impl Notifier for LoggingNotifier {
+    async fn send_text(&self, chat_id: ChatId, text: String, _parse_mode: Option<ParseMode>, keyboard: Option<InlineKeyboardMarkup>) -> Result<(), RequestError> {
+        log::info!("[Dry run] Message to {}{}:\n{}", chat_id.to_string(), if keyboard.is_some() { " (with buttons)" } else { "" }, text);
+        Ok(())
+    }
+
+    async fn send_poll(&self, chat_id: ChatId, question: String, options: Vec<String>, is_anonymous: bool) -> Result<Option<PollMessage>, RequestError> {
+        log::info!("[Dry run] Poll to {} (anonymous: {}): {} {:?}", chat_id.to_string(), is_anonymous, question, options);
+        Ok(None)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_logging_notifier_sends_nothing() {
+        let notifier: &dyn Notifier = &LoggingNotifier;
+
+        assert!(notifier.send_text(ChatId(1), String::from("Reminder"), Some(ParseMode::MarkdownV2), None).await.is_ok());
+
+        // Without a sent poll there is nothing to remember for the answers
+        let poll = notifier.send_poll(ChatId(1), String::from("Read?"), vec![String::from("Yes"), String::from("No")], false).await;
+        assert_eq!(poll.unwrap(), None);
+    }
+}
@@ -0,0 +1,119 @@
+//! In this unit, the retry logic for requests to Telegram is implemented.
+
+use std::{future::Future, time::Duration};
+
+use teloxide::{requests::{Output, Request}, RequestError};
+
+
+/// The number of attempts for sending a request to Telegram before giving up
+const SEND_ATTEMPTS: u32 = 3;
+
+/// The delay before the first retry, which is doubled after every further attempt
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+
+/// Sends a request to Telegram and retries it up to `SEND_ATTEMPTS` times on transient errors.
+/// Only network errors and `RetryAfter` errors are retried, all other errors (like a user who blocked the bot)
+/// are returned immediately.
+///
+/// # Params
+/// - `request`: The request which should be sent (for example `bot.send_message(...)`)
+///
+/// # Returns
+/// The output of the request or the last error which occurred.
+pub async fn send_with_retry<R>(request: R) -> Result<Output<R>, RequestError>
+where
+    R: Request<Err = RequestError>,
+{
+    retry_on_transient_errors(|| request.send_ref()).await
+}
+
+/// Calls `send` until it succeeds, returns a non-retryable error, or `SEND_ATTEMPTS` attempts have been made.
+/// Between two attempts the function waits with an exponential backoff, or for the duration which Telegram
+/// requested with a `RetryAfter` error.
+async fn retry_on_transient_errors<T, F, Fut>(mut send: F) -> Result<T, RequestError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, RequestError>>,
+{
+    let mut backoff = INITIAL_BACKOFF;
+    let mut attempt = 1;
+
+    loop {
+        match send().await {
+            Ok(output) => return Ok(output),
+            Err(error) => {
+                let delay = match &error {
+                    RequestError::RetryAfter(seconds) => seconds.duration(),
+                    RequestError::Network(_) => backoff,
+                    _ => return Err(error),
+                };
+
+                if attempt >= SEND_ATTEMPTS {
+                    return Err(error);
+                }
+
+                log::warn!("Sending failed (attempt {} of {}): {}. Retry in {:?}.", attempt, SEND_ATTEMPTS, error.to_string(), delay);
+                tokio::time::sleep(delay).await;
+
+                backoff *= 2;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use teloxide::{types::Seconds, ApiError};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_retry_until_success() {
+        let attempts = AtomicU32::new(0);
+
+        // The first attempt fails with a `RetryAfter` error of zero seconds, the second one succeeds
+        let result = retry_on_transient_errors(|| {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match attempt {
+                    0 => Err(RequestError::RetryAfter(Seconds::from_seconds(0))),
+                    _ => Ok("sent"),
+                }
+            }
+        }).await;
+
+        assert_eq!(result.unwrap(), "sent");
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_for_blocked_users() {
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), RequestError> = retry_on_transient_errors(|| {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(RequestError::Api(ApiError::BotBlocked)) }
+        }).await;
+
+        assert!(matches!(result, Err(RequestError::Api(ApiError::BotBlocked))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_give_up_after_all_attempts() {
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), RequestError> = retry_on_transient_errors(|| {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(RequestError::RetryAfter(Seconds::from_seconds(0))) }
+        }).await;
+
+        assert!(matches!(result, Err(RequestError::RetryAfter(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), SEND_ATTEMPTS);
+    }
+}
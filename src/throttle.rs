@@ -0,0 +1,160 @@
+//! In this unit, the sending of many reminders in the same minute is throttled, so that a burst of users
+//! with the same timer does not hit the flood limits of Telegram.
+
+use std::{env, future::Future, sync::atomic::{AtomicBool, Ordering}, time::Duration};
+
+use tokio::sync::Semaphore;
+
+/// The name of the environment variable with the maximal number of reminders which are sent at the same time
+pub const REMINDER_CONCURRENCY_ENV: &str = "REMINDER_CONCURRENCY";
+
+/// The name of the environment variable with the delay between the start of two reminders in milliseconds
+pub const REMINDER_DELAY_ENV: &str = "REMINDER_DELAY_MS";
+
+/// The number of reminders which are sent at the same time if `REMINDER_CONCURRENCY_ENV` is not set
+const DEFAULT_CONCURRENCY: usize = 5;
+
+/// The delay between the start of two reminders if `REMINDER_DELAY_ENV` is not set
+const DEFAULT_DELAY: Duration = Duration::from_millis(50);
+
+/// The time after which a send which is still waiting for a permit is logged, as all permits are in use for too long
+const SATURATION_WARNING: Duration = Duration::from_secs(1);
+
+
+/// Limits how many sends run at the same time and spaces out their start.
+pub struct SendLimiter {
+    semaphore: Semaphore,
+    concurrency: usize,
+    delay: Duration,
+    /// The time after which a waiting send is logged (see `SATURATION_WARNING`)
+    saturation_warning: Duration,
+    /// Whether the saturation has been logged, so that it is logged only once until a permit is free again
+    saturated: AtomicBool,
+}
+
+impl SendLimiter {
+    /// Creates a limiter which runs at most `concurrency` (at least one) sends at the same time
+    /// and starts them `delay` apart.
+    pub fn new(concurrency: usize, delay: Duration) -> Self {
+        let concurrency = concurrency.max(1);
+        SendLimiter {
+            semaphore: Semaphore::new(concurrency),
+            concurrency,
+            delay,
+            saturation_warning: SATURATION_WARNING,
+            saturated: AtomicBool::new(false),
+        }
+    }
+
+    /// Creates the limiter with the values of `REMINDER_CONCURRENCY_ENV` and `REMINDER_DELAY_ENV`.
+    /// Values which are missing or invalid are replaced by the defaults.
+    pub fn from_env() -> Self {
+        let concurrency = env::var(REMINDER_CONCURRENCY_ENV).ok()
+            .and_then(|concurrency| concurrency.trim().parse().ok())
+            .unwrap_or(DEFAULT_CONCURRENCY);
+        let delay = env::var(REMINDER_DELAY_ENV).ok()
+            .and_then(|delay| delay.trim().parse().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(DEFAULT_DELAY);
+        SendLimiter::new(concurrency, delay)
+    }
+
+    /// Returns the maximal number of sends which run at the same time.
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Returns the delay between the start of two sends.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Runs a send once its turn has come and a permit is free.
+    ///
+    /// # Params
+    /// - `index`: The position of the send within the current burst, which delays its start by `index` times the delay
+    /// - `send`: The send which should be run
+    ///
+    /// # Returns
+    /// The output of the send.
+    pub async fn run<F: Future>(&self, index: usize, send: F) -> F::Output {
+        tokio::time::sleep(self.delay.saturating_mul(u32::try_from(index).unwrap_or(u32::MAX))).await;
+        // The semaphore is never closed, so a permit is always granted eventually
+        let _permit = match self.semaphore.try_acquire() {
+            Ok(permit) => {
+                self.saturated.store(false, Ordering::SeqCst);
+                Ok(permit)
+            },
+            Err(_) => match tokio::time::timeout(self.saturation_warning, self.semaphore.acquire()).await {
+                Ok(permit) => permit,
+                Err(_) => {
+                    if !self.saturated.swap(true, Ordering::SeqCst) {
+                        log::warn!(
+                            "All {} reminder permits have been in use for more than {:?}, the reminders are delayed.",
+                            self.concurrency, self.saturation_warning
+                        );
+                    }
+                    self.semaphore.acquire().await
+                }
+            },
+        };
+        send.await
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{atomic::AtomicUsize, Arc};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_limiter_caps_concurrency() {
+        let limiter = Arc::new(SendLimiter::new(3, Duration::ZERO));
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+
+        let tasks: Vec<_> = (0..10).map(|index| {
+            let limiter = limiter.clone();
+            let running = running.clone();
+            let max_running = max_running.clone();
+            tokio::spawn(async move {
+                limiter.run(index, async {
+                    let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_running.fetch_max(now_running, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                }).await
+            })
+        }).collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(max_running.load(Ordering::SeqCst), 3);
+        // At least one send is always allowed
+        assert_eq!(SendLimiter::new(0, Duration::ZERO).concurrency(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_saturation_is_detected() {
+        let limiter = Arc::new(SendLimiter {
+            saturation_warning: Duration::from_millis(10),
+            ..SendLimiter::new(1, Duration::ZERO)
+        });
+
+        let first = {
+            let limiter = limiter.clone();
+            tokio::spawn(async move { limiter.run(0, tokio::time::sleep(Duration::from_millis(50))).await })
+        };
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        limiter.run(1, async {}).await;
+        first.await.unwrap();
+        assert!(limiter.saturated.load(Ordering::SeqCst));
+
+        // A free permit ends the saturation
+        limiter.run(2, async {}).await;
+        assert!(!limiter.saturated.load(Ordering::SeqCst));
+    }
+}
@@ -1,11 +1,32 @@
-use teloxide::types::ChatId;
-use std::{error::Error, ops::Deref, path::Path, sync::Arc};
+use chrono::{DateTime, Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc, Weekday};
+use teloxide::types::{ChatId, MessageId};
+use std::{collections::HashMap, sync::{atomic::{AtomicBool, Ordering}, Arc}, time::Duration};
 use tokio::sync::RwLock;
 
 use crate::localize::*;
+use crate::error::DailyBibleError;
+use crate::userstore::UserStore;
 use serde::{ Serialize, Deserialize };
 
 
+/// The time which is waited after an update before all user states are persisted, so that several
+/// updates in a short time only lead to a single write
+const PERSIST_DEBOUNCE: Duration = Duration::from_secs(1);
+
+/// The number of completed dates which are kept for `/history`, older dates are removed to bound the size of the state
+pub const COMPLETED_DATES_LIMIT: usize = 90;
+
+/// The weekday on which the weekly summary is sent (together with the reminder). It is the last day of the week,
+/// so the summary covers the whole week from Monday on.
+pub const WEEKLY_SUMMARY_WEEKDAY: Weekday = Weekday::Sun;
+
+/// The maximal number of characters of the custom prefix of the reminder (see `/setmessage`)
+pub const CUSTOM_PREFIX_MAX_LENGTH: usize = 100;
+
+/// The number of minutes in which a requested deletion of the data has to be confirmed, afterwards it is aborted
+pub const DELETION_CONFIRMATION_MINUTES: i64 = 5;
+
+
 /// Here the State of a User is specified which is the Single Point of Truth for all user data.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct UserState {
@@ -15,57 +36,351 @@ pub struct UserState {
     pub language: Language,
     /// The timer which is configured
     pub timer: Option<chrono::NaiveTime>,
+    /// The number of consecutive days on which the user has marked the reading as read
+    #[serde(default)]
+    pub streak: u32,
+    /// The last date on which the user has marked the reading as read
+    pub last_read: Option<NaiveDate>,
+    /// The Bible version (like `ESV`) which is used for links to the readings, no links are sent if it is not set
+    pub preferred_bible_version: Option<String>,
+    /// Whether the daily reminders are paused (the timer is kept)
+    #[serde(default)]
+    pub paused: bool,
+    /// Whether the daily reminder is confirmed only with the "Mark as read" button instead of the poll
+    #[serde(default)]
+    pub use_button_instead_of_poll: bool,
+    /// The poll which has been sent with the last daily reminder
+    pub last_poll: Option<SentPoll>,
+    /// Whether the chat is a group or supergroup instead of a private chat
+    #[serde(default)]
+    pub is_group: bool,
+    /// Whether the daily reminders have been enabled with `/enable`, which is only required in groups
+    #[serde(default)]
+    pub group_enabled: bool,
+    /// The date on which the user started a personal reading plan. If it is set, the rows of the schedule
+    /// are read one after another from this date on instead of following the dates of the schedule.
+    pub plan_start_date: Option<NaiveDate>,
+    /// The time when the user state has been saved for the first time. It is `None` for users
+    /// who registered before this field existed.
+    pub created_at: Option<DateTime<Utc>>,
+    /// A single date on which no reminder is sent (set with `/skiptoday`). It is cleared when the reminder is skipped.
+    pub skip_date: Option<NaiveDate>,
+    /// Whether the poll after the daily reminder is anonymous in a private chat (see `sends_anonymous_poll`)
+    #[serde(default)]
+    pub anonymous_poll: bool,
+    /// The dates on which the reading has been marked as read (at most the last `COMPLETED_DATES_LIMIT`, oldest first)
+    #[serde(default)]
+    pub completed_dates: Vec<NaiveDate>,
+    /// The text which replaces the header of the daily reminder (set with `/setmessage`)
+    #[serde(default)]
+    pub custom_prefix: Option<String>,
+    /// Whether the reminders are formatted with MarkdownV2, otherwise they are sent as plain text (set with `/markdown`)
+    #[serde(default = "default_use_markdown")]
+    pub use_markdown: bool,
+    /// Whether a summary of the week is sent on `WEEKLY_SUMMARY_WEEKDAY` (set with `/weeklysummary`)
+    #[serde(default)]
+    pub weekly_summary: bool,
+    /// The date on which the last weekly summary has been sent, so that it is sent only once per week
+    pub last_weekly_summary: Option<NaiveDate>,
+    /// The date on which the reminder of the timer has been sent last, so that a restart in the same minute
+    /// does not send it again
+    pub last_reminder_sent: Option<NaiveDate>,
+    /// The name of the reading plan whose schedule is used (set with `/setplan`), empty for the default plan
+    #[serde(default)]
+    pub plan: String,
+}
+
+/// The default of `UserState::use_markdown` for user states which have been saved before the field existed
+fn default_use_markdown() -> bool {
+    true
+}
+
+
+/// A poll of a daily reminder, which is remembered to answer the votes of the user
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SentPoll {
+    pub message_id: MessageId,
+    pub poll_id: String,
+    /// The date of the reminder, answers to polls of previous days are not counted
+    pub date: NaiveDate,
 }
 
+impl UserState {
+    /// Returns the default UserState for a given ChatId
+    pub fn new(chat_id: ChatId) -> Self {
+        UserState {
+            chat_id,
+            language: Language::English,
+            timer: None,
+            streak: 0,
+            last_read: None,
+            preferred_bible_version: None,
+            paused: false,
+            use_button_instead_of_poll: false,
+            last_poll: None,
+            is_group: false,
+            group_enabled: false,
+            plan_start_date: None,
+            created_at: None,
+            skip_date: None,
+            anonymous_poll: false,
+            completed_dates: Vec::new(),
+            custom_prefix: None,
+            use_markdown: true,
+            weekly_summary: false,
+            last_weekly_summary: None,
+            last_reminder_sent: None,
+            plan: String::new(),
+        }
+    }
+
+    /// Records that the user has read the Bible reading on the given date and updates the streak and the history.
+    /// The streak is continued if the last reading has been marked as read the day before, otherwise it starts again.
+    /// Marking the same date several times does not change the streak.
+    pub fn mark_as_read(&mut self, date: NaiveDate) {
+        match self.last_read {
+            Some(last_read) if last_read == date => return,
+            Some(last_read) if last_read.succ_opt() == Some(date) => self.streak += 1,
+            _ => self.streak = 1,
+        }
+        self.last_read = Some(date);
+
+        if !self.completed_dates.contains(&date) {
+            self.completed_dates.push(date);
+            if self.completed_dates.len() > COMPLETED_DATES_LIMIT {
+                let excess = self.completed_dates.len() - COMPLETED_DATES_LIMIT;
+                self.completed_dates.drain(..excess);
+            }
+        }
+    }
+
+    /// Checks whether the daily reminders are sent to the chat. Paused chats receive no reminders, and groups
+    /// only receive them after they have been enabled explicitly, so that the bot does not spam groups
+    /// it has merely been added to.
+    pub fn receives_reminders(&self) -> bool {
+        !self.paused && (!self.is_group || self.group_enabled)
+    }
+
+    /// Checks whether the poll after the daily reminder is anonymous. In group chats the answers of the members
+    /// are not revealed to each other, so the poll is always anonymous there.
+    pub fn sends_anonymous_poll(&self) -> bool {
+        self.anonymous_poll || !self.chat_id.is_user()
+    }
+
+    /// Checks whether the reminder of the given date should be skipped because of `/skiptoday`.
+    pub fn skips_reminder(&self, date: NaiveDate) -> bool {
+        self.skip_date == Some(date)
+    }
+
+    /// Checks whether the reminder of the timer has already been sent on the given date.
+    pub fn received_reminder_on(&self, date: NaiveDate) -> bool {
+        self.last_reminder_sent == Some(date)
+    }
 
-/// The type of the UserStateVector which assures accessibility over several threads and functions.
-/// As the UserStateVector is an `Arc<Rwlock<Vec<UserState>>>`, accessing the inner content is done with the RwLock functions read() and write()
+    /// Returns the date and time at which the timer sends the next reminder after `now`. Timers which have already
+    /// passed today (or whose reminder has been sent or skipped today) are sent on the next day.
+    /// # Returns
+    /// The next reminder, or `None` if no timer is set or the chat receives no reminders (see `receives_reminders`).
+    pub fn next_reminder(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        let timer = self.timer?;
+        if !self.receives_reminders() {
+            return None;
+        }
+
+        // The timer loop sends the reminder at the start of the minute of the timer
+        let timer = timer.with_second(0)?.with_nanosecond(0)?;
+        now.date().iter_days()
+            .map(|date| date.and_time(timer))
+            .find(|date_time| *date_time > now && !self.skips_reminder(date_time.date()) && !self.received_reminder_on(date_time.date()))
+    }
+
+    /// Checks whether the weekly summary has to be sent on the given date: it has to be enabled, the date has to be
+    /// a `WEEKLY_SUMMARY_WEEKDAY` and no summary may have been sent in the same week yet.
+    pub fn is_weekly_summary_due(&self, date: NaiveDate) -> bool {
+        self.weekly_summary
+            && date.weekday() == WEEKLY_SUMMARY_WEEKDAY
+            && self.last_weekly_summary.is_none_or(|last_weekly_summary| week_start(last_weekly_summary) != week_start(date))
+    }
+
+    /// Returns the number of days of the week of the given date (from Monday until the date) on which the reading
+    /// has been marked as read.
+    pub fn read_days_in_week(&self, date: NaiveDate) -> usize {
+        let week_start = week_start(date);
+        self.completed_dates.iter()
+            .filter(|completed_date| (week_start..=date).contains(*completed_date))
+            .count()
+    }
+
+    /// Returns the number of days since the start of the personal reading plan (`0` on the day it has been started),
+    /// or `None` if the user follows the dates of the schedule.
+    pub fn plan_day(&self, today: NaiveDate) -> Option<usize> {
+        let plan_start_date = self.plan_start_date?;
+        usize::try_from((today - plan_start_date).num_days()).ok()
+    }
+
+    /// Restores the settings of a user state from a JSON file which has been created with `/export`.
+    /// Only the settings which users can change with commands are taken from the file: the language, the timer,
+    /// the reading plan, the Bible version, the custom prefix and the poll settings. Everything else (like the ChatId,
+    /// the streak or whether the reminders are enabled in a group) starts from the default `UserState` of the given
+    /// ChatId, so that a forged file can neither overwrite another chat nor unlock anything.
+    ///
+    /// # Returns
+    /// The imported UserState or the error if the file is no valid export.
+    pub fn from_export(json: &[u8], chat_id: ChatId) -> Result<Self, serde_json::Error> {
+        let exported_user_state: UserState = serde_json::from_slice(json)?;
+        Ok(UserState {
+            language: exported_user_state.language,
+            timer: exported_user_state.timer,
+            plan: exported_user_state.plan,
+            preferred_bible_version: exported_user_state.preferred_bible_version,
+            custom_prefix: exported_user_state.custom_prefix,
+            use_button_instead_of_poll: exported_user_state.use_button_instead_of_poll,
+            anonymous_poll: exported_user_state.anonymous_poll,
+            ..UserState::new(chat_id)
+        })
+    }
+
+    /// Checks whether the poll with the given id belongs to the reminder of today.
+    pub fn is_current_poll(&self, poll_id: &str, today: NaiveDate) -> bool {
+        matches!(&self.last_poll, Some(last_poll) if last_poll.poll_id == poll_id && last_poll.date == today)
+    }
+}
+
+
+/// Parses a time for the timer which has been typed by the user. Besides `hh:mm`, the formats `h:mm`, `hh.mm`,
+/// a bare hour (`8` or `20`) and 12-hour times (`8am`, `8:30 pm`) are accepted.
+/// 
+/// # Returns
+/// The parsed time or `None` if the string matches none of the formats.
+pub fn parse_user_time(time_string: &str) -> Option<NaiveTime> {
+    let time_string = time_string.trim().to_lowercase().replace(' ', "");
+
+    for (suffix, offset) in [("am", 0), ("pm", 12)] {
+        if let Some(time_string) = time_string.strip_suffix(suffix) {
+            let time = parse_24_hour_time(time_string)?;
+            if time.hour() < 1 || time.hour() > 12 {
+                return None;
+            }
+            return NaiveTime::from_hms_opt(time.hour() % 12 + offset, time.minute(), 0);
+        }
+    }
+    parse_24_hour_time(&time_string)
+}
+
+/// Parses a time in the formats `hh:mm`, `hh.mm` (both also with a single digit hour) or a bare hour.
+fn parse_24_hour_time(time_string: &str) -> Option<NaiveTime> {
+    for format in ["%H:%M", "%H.%M"] {
+        if let Ok(time) = NaiveTime::parse_from_str(time_string, format) {
+            return Some(time);
+        }
+    }
+
+    if !time_string.is_empty() && time_string.len() <= 2 && time_string.chars().all(|c| c.is_ascii_digit()) {
+        return NaiveTime::from_hms_opt(time_string.parse().ok()?, 0, 0);
+    }
+    None
+}
+
+/// Removes control characters (like line breaks) and the surrounding whitespace from the custom prefix of the reminder.
+///
+/// # Params
+/// - `text`: The prefix as typed by the user
+///
+/// # Returns
+/// The cleaned prefix (which is empty if the prefix should be removed), or `None` if it is longer
+/// than `CUSTOM_PREFIX_MAX_LENGTH` characters.
+pub fn sanitize_custom_prefix(text: &str) -> Option<String> {
+    let prefix: String = text.chars().filter(|character| !character.is_control()).collect();
+    let prefix = prefix.trim();
+    if prefix.chars().count() > CUSTOM_PREFIX_MAX_LENGTH {
+        return None;
+    }
+    Some(prefix.to_string())
+}
+
+/// Returns the Monday of the week of the given date, which identifies the week.
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Days::new(u64::from(date.weekday().num_days_from_monday()))
+}
+
+
+/// The type of the UserStateMap which assures accessibility over several threads and functions.
+/// As the UserStateMap is an `Arc<Rwlock<HashMap<ChatId, UserState>>>`, accessing the inner content is done with the RwLock functions read() and write().
+/// The user states are keyed by their ChatId, so a single user state is found without scanning all of them.
 /// 
 /// # Example
 /// ```rust
-/// use std::sync::Arc;
+/// use std::{collections::HashMap, sync::Arc};
 /// use tokio::sync::RwLock;
 /// use teloxide::types::ChatId;
 /// use crate::localize::*;
 /// 
 /// let user_state = UserState {
-///     chat_id: ChatId(123456),
 ///     language: Language::German,
-///     timer: None,
+///     ..UserState::new(ChatId(123456))
 /// };
-/// let user_state_vector: UserStateVector = Arc::new(
+/// let user_state_map: UserStateMap = Arc::new(
 ///     RwLock::new(
-///         vec![user_state]
+///         HashMap::from([(user_state.chat_id, user_state)])
 ///     )
 /// );
-/// assert_eq!(user_state_vector.read().await.len(), 1);
+/// assert_eq!(user_state_map.read().await.len(), 1);
 /// ```
-pub type UserStateVector = Arc<RwLock<Vec<UserState>>>;
+pub type UserStateMap = Arc<RwLock<HashMap<ChatId, UserState>>>;
+
+
+/// Aggregated numbers about all users which are returned by `UserStateWrapper::stats`
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserStatistics {
+    /// The number of saved user states
+    pub total_users: usize,
+    /// The number of users who have set up a timer
+    pub users_with_timer: usize,
+    /// The number of users who currently receive reminders (with a timer, not paused and enabled in groups)
+    pub active_users: usize,
+    /// The number of users per language, sorted by the number of users (descending) and then by the language
+    pub users_per_language: Vec<(Language, usize)>,
+    /// The number of timers per hour of the day, sorted by the hour (hours without timers are left out)
+    pub timers_per_hour: Vec<(u32, usize)>,
+}
 
 
 /// The UserStateWrapper handles the managing of user state and can be savely used by the commands to read
 /// or write user states.
 /// Define any needed user state in the UserState struct.
+/// The user states are persisted with the given `UserStore`.
 #[derive(Clone)]
 pub struct UserStateWrapper {
-    pub user_states: UserStateVector,
+    pub user_states: UserStateMap,
+    /// The one-off reminders which have been requested with the "Remind me later" button or `/snoozeuntil`
+    pub pending_snoozes: Arc<RwLock<Vec<(ChatId, NaiveDateTime)>>>,
+    store: Arc<dyn UserStore>,
+    /// Whether a debounced save of all user states has already been scheduled
+    save_scheduled: Arc<AtomicBool>,
+    /// Whether the user states have changed since they have been saved the last time
+    dirty: Arc<AtomicBool>,
+    /// The minute in which the last reminder has been sent to each chat, so that a chat is reminded at most once per minute
+    last_reminders: Arc<RwLock<HashMap<ChatId, NaiveDateTime>>>,
+    /// The time at which each chat has requested the deletion of its data which has not been confirmed yet
+    pending_deletions: Arc<RwLock<HashMap<ChatId, NaiveDateTime>>>,
 }
 
 impl UserStateWrapper {
-    pub fn new() -> Self {
+    pub fn new(store: Arc<dyn UserStore>) -> Self {
         UserStateWrapper {
-            user_states: Arc::new(RwLock::new(Vec::new())),
+            user_states: Arc::new(RwLock::new(HashMap::new())),
+            pending_snoozes: Arc::new(RwLock::new(Vec::new())),
+            store,
+            save_scheduled: Arc::new(AtomicBool::new(false)),
+            dirty: Arc::new(AtomicBool::new(false)),
+            last_reminders: Arc::new(RwLock::new(HashMap::new())),
+            pending_deletions: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
     
     pub async fn user_state_exists(&self, chat_id: ChatId) -> bool {
-        for u in self.user_states.read().await.iter() {
-            if u.chat_id == chat_id {
-                return true;
-            }
-        }
-        false
+        self.user_states.read().await.contains_key(&chat_id)
     }
 
     
@@ -77,76 +392,290 @@ impl UserStateWrapper {
     /// # Returns
     /// The saved `UserState` if one is saved, or the default `UserState` if no one is found.
     pub async fn find_userstate(&self, chat_id: ChatId) -> UserState {
-        let default_user_state = UserState {
-                chat_id,
-                language: Language::English,
-                timer: None,
-        };
-        
-        for u in self.user_states.read().await.iter() {
-            if u.chat_id == chat_id {
-                return u.clone();
-            }
+        match self.user_states.read().await.get(&chat_id) {
+            Some(user_state) => user_state.clone(),
+            None => UserState::new(chat_id),
         }
-        default_user_state
     }
 
     
-    /// This updates a UserState internally and overrides an existing one if the ChatId does already exist
+    /// This updates a UserState internally and overrides an existing one if the ChatId does already exist.
+    /// The UserState is persisted with the `UserStore` afterwards.
     /// # Params
     /// - `user_state`: The UserState which should be updated.
     /// # Returns
     /// A bool, `true` if the given ChatId had already a UserStage which have been updated.
     /// `false` if a UserState with the given ChatId has been saved for the first time.
-    pub async fn update_userstate(&self, user_state: UserState) -> bool {
-        for u in self.user_states.write().await.iter_mut() {
-            if u.chat_id == user_state.chat_id {
-                *u = user_state.clone();
-                
-                // End the function if a UserState already exists which has been updated
-                return true;
+    /// In this case `created_at` is set to the current time (unless it is already set).
+    pub async fn update_userstate(&self, mut user_state: UserState) -> bool {
+        let existed = {
+            let mut user_states = self.user_states.write().await;
+            let existed = user_states.contains_key(&user_state.chat_id);
+
+            // If there has been no user_state saved, the creation time of the new UserState is recorded
+            if !existed {
+                user_state.created_at.get_or_insert_with(Utc::now);
             }
+            user_states.insert(user_state.chat_id, user_state.clone());
+            existed
         };
+        self.dirty.store(true, Ordering::SeqCst);
 
-        // If there has been no user_state saved, the function will get here and add a new UserState element
-        self.user_states.write().await.push(user_state);
+        if let Err(error) = self.store.save_one(&user_state).await {
+            log::warn!("Could not persist the user state of {}: {}", user_state.chat_id, error.to_string());
+        }
         
-        false
+        existed
     }
 
     
-    pub async fn write_states_to_file(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
-        match serde_json::to_string_pretty(self.user_states.read().await.deref()) {
-            Ok(json_string) => { 
-                match tokio::fs::write(
-                    &Path::new(file_path), 
-                    json_string)
-                    .await {
-                        Ok(_) => Ok(()),
-                        Err(error) => Err(Box::new(error)),
+    /// Updates a UserState like `update_userstate` and makes sure that the change is persisted promptly.
+    /// If the `UserStore` can not persist single updates, all user states are saved after `PERSIST_DEBOUNCE`.
+    /// Further updates within this time do not lead to additional writes.
+    /// # Params
+    /// - `user_state`: The UserState which should be updated.
+    /// # Returns
+    /// The same as `update_userstate`.
+    pub async fn update_and_persist(&self, user_state: UserState) -> bool {
+        let existed = self.update_userstate(user_state).await;
+        self.schedule_save();
+        existed
+    }
+
+    /// Changes the saved UserState of the given ChatId with `update` while holding the write lock, so that changes
+    /// which have been made since the UserState was read (for example by a command) are not overwritten.
+    /// The change is persisted like with `update_and_persist`.
+    /// # Params
+    /// - `chat_id`: The ChatId whose UserState should be changed.
+    /// - `update`: The function which changes the UserState.
+    /// # Returns
+    /// `true` if a UserState has been saved for the ChatId and has been changed, `false` otherwise.
+    pub async fn update_with(&self, chat_id: ChatId, update: impl FnOnce(&mut UserState)) -> bool {
+        let user_state = {
+            let mut user_states = self.user_states.write().await;
+            match user_states.get_mut(&chat_id) {
+                Some(user_state) => {
+                    update(user_state);
+                    user_state.clone()
+                },
+                None => return false,
+            }
+        };
+        self.dirty.store(true, Ordering::SeqCst);
+
+        if let Err(error) = self.store.save_one(&user_state).await {
+            log::warn!("Could not persist the user state of {}: {}", user_state.chat_id, error.to_string());
+        }
+        self.schedule_save();
+        true
+    }
+
+    /// Marks that today's reminder has been sent to the given ChatIds and persists this immediately, so that the
+    /// reminders are not sent again if the bot is restarted right afterwards. The change is saved with `save_one`,
+    /// or by saving all user states at once if the `UserStore` can not persist single updates.
+    /// # Params
+    /// - `chat_ids`: The ChatIds whose reminder has been sent.
+    /// - `date`: The date of the reminder.
+    pub async fn mark_reminders_sent(&self, chat_ids: &[ChatId], date: NaiveDate) {
+        let marked_user_states: Vec<UserState> = {
+            let mut user_states = self.user_states.write().await;
+            user_states.values_mut()
+                .filter(|user_state| chat_ids.contains(&user_state.chat_id))
+                .map(|user_state| {
+                    user_state.last_reminder_sent = Some(date);
+                    user_state.clone()
+                })
+                .collect()
+        };
+        if marked_user_states.is_empty() {
+            return;
+        }
+        self.dirty.store(true, Ordering::SeqCst);
+
+        if !self.store.persists_single_updates() {
+            if let Err(error) = self.save_states_if_dirty().await {
+                log::warn!("Could not persist the sent reminders: {}", error.to_string());
+            }
+            return;
+        }
+        for user_state in marked_user_states {
+            if let Err(error) = self.store.save_one(&user_state).await {
+                log::warn!("Could not persist the sent reminder of {}: {}", user_state.chat_id, error.to_string());
+            }
+        }
+    }
+
+    /// Saves all user states after `PERSIST_DEBOUNCE` if the `UserStore` can not persist single updates.
+    /// If a save has already been scheduled, no further one is scheduled.
+    fn schedule_save(&self) {
+        if !self.store.persists_single_updates() && !self.save_scheduled.swap(true, Ordering::SeqCst) {
+            let user_state_wrapper = self.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(PERSIST_DEBOUNCE).await;
+
+                // The flag is reset before saving, so that updates during the save schedule another one
+                user_state_wrapper.save_scheduled.store(false, Ordering::SeqCst);
+                match user_state_wrapper.save_states_if_dirty().await {
+                    Ok(true) => log::info!("Persisted updated user states"),
+                    Ok(false) => {},
+                    Err(error) => log::warn!("Could not persist updated user states: {}", error.to_string())
                 }
-            },
-            Err(error) => Err(Box::new(error)),
-        }
-    }
-
-    pub async fn load_states_from_file(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
-        match tokio::fs::read_to_string(file_path).await {
-            Ok(file_string) => {
-                match serde_json::from_str(&file_string) {
-                    Ok(object) => {
-                        let mut userstates: Vec<UserState> = object;
-                        let mut userstate_lock = self.user_states.write().await;
-                        userstate_lock.clear();
-                        userstate_lock.append(&mut userstates);
-                        Ok(())
-                    },
-                    Err(error) => Err(Box::new(error))
+            });
+        }
+    }
+
+    /// Removes the UserState of the given ChatId together with all pending reminders of it.
+    /// The removal is persisted immediately.
+    /// # Params
+    /// - `chat_id`: The ChatId whose data should be removed.
+    /// # Returns
+    /// `true` if a UserState has been removed, `false` if no UserState existed for the ChatId.
+    pub async fn remove_userstate(&self, chat_id: ChatId) -> bool {
+        let removed = {
+            self.user_states.write().await.remove(&chat_id).is_some()
+        };
+        self.pending_snoozes.write().await.retain(|(snooze_chat_id, _)| *snooze_chat_id != chat_id);
+        self.last_reminders.write().await.remove(&chat_id);
+        self.pending_deletions.write().await.remove(&chat_id);
+
+        if let Err(error) = self.store.remove(chat_id).await {
+            log::warn!("Could not remove the persisted user state of {}: {}", chat_id, error.to_string());
+        }
+        if removed {
+            self.dirty.store(true, Ordering::SeqCst);
+        }
+        if removed && !self.store.persists_single_updates() {
+            if let Err(error) = self.save_states_if_dirty().await {
+                log::warn!("Could not persist the removal of {}: {}", chat_id, error.to_string());
+            }
+        }
+
+        removed
+    }
+
+    /// Counts the saved user states.
+    /// # Returns
+    /// The `UserStatistics` with the total number of users, the users with a timer, the active users,
+    /// the users per language (sorted by the number of users, descending, and then by the language) and the timers per hour.
+    pub async fn stats(&self) -> UserStatistics {
+        let user_states = self.user_states.read().await;
+        let mut users_per_language: Vec<(Language, usize)> = Vec::new();
+        let mut timers_per_hour: Vec<(u32, usize)> = Vec::new();
+
+        for u in user_states.values() {
+            match users_per_language.iter_mut().find(|(language, _)| *language == u.language) {
+                Some((_, count)) => *count += 1,
+                None => users_per_language.push((u.language.clone(), 1)),
+            }
+
+            if let Some(timer) = u.timer {
+                match timers_per_hour.iter_mut().find(|(hour, _)| *hour == timer.hour()) {
+                    Some((_, count)) => *count += 1,
+                    None => timers_per_hour.push((timer.hour(), 1)),
                 }
-            },
-            Err(error) => Err(Box::new(error))
+            }
         }
-        
+        users_per_language.sort_by(|(language_a, count_a), (language_b, count_b)| {
+            count_b.cmp(count_a).then_with(|| language_a.locale_id().cmp(language_b.locale_id()))
+        });
+        timers_per_hour.sort_by_key(|(hour, _)| *hour);
+
+        UserStatistics {
+            total_users: user_states.len(),
+            users_with_timer: user_states.values().filter(|u| u.timer.is_some()).count(),
+            active_users: user_states.values().filter(|u| u.timer.is_some() && u.receives_reminders()).count(),
+            users_per_language,
+            timers_per_hour,
+        }
+    }
+
+    /// Schedules a one-off reminder for the given ChatId at the given date and time. The recurring timer is not changed.
+    pub async fn add_snooze(&self, chat_id: ChatId, date_time: NaiveDateTime) {
+        self.pending_snoozes.write().await.push((chat_id, date_time));
+    }
+
+    /// Removes all one-off reminders which are due in the minute of the given date and time. Reminders whose minute
+    /// has already passed (for example because the bot was not running) are removed without being sent.
+    /// # Returns
+    /// The ChatIds for which a reminder has to be sent now.
+    pub async fn take_due_snoozes(&self, now: NaiveDateTime) -> Vec<ChatId> {
+        let mut pending_snoozes = self.pending_snoozes.write().await;
+        let mut due_chat_ids = Vec::new();
+        pending_snoozes.retain(|(chat_id, date_time)| {
+            if date_time.date() == now.date() && date_time.hour() == now.hour() && date_time.minute() == now.minute() {
+                due_chat_ids.push(*chat_id);
+                false
+            } else {
+                *date_time > now
+            }
+        });
+        due_chat_ids
+    }
+
+    /// Records that the chat has requested the deletion of its data at `now`, which has to be confirmed
+    /// within `DELETION_CONFIRMATION_MINUTES` minutes. An older request of the chat is replaced.
+    pub async fn request_deletion(&self, chat_id: ChatId, now: NaiveDateTime) {
+        self.pending_deletions.write().await.insert(chat_id, now);
+    }
+
+    /// Removes the pending deletion request of the chat, because the user has confirmed or cancelled it.
+    /// # Returns
+    /// `true` if the chat has requested the deletion within the last `DELETION_CONFIRMATION_MINUTES` minutes
+    /// before `now`, `false` if there is no request or it has expired.
+    pub async fn take_deletion_request(&self, chat_id: ChatId, now: NaiveDateTime) -> bool {
+        match self.pending_deletions.write().await.remove(&chat_id) {
+            Some(requested_at) => now - requested_at <= chrono::Duration::minutes(DELETION_CONFIRMATION_MINUTES),
+            None => false,
+        }
+    }
+
+    /// Records that a reminder is sent to the chat in the minute of `now`, unless a reminder has already been sent
+    /// to it in the same minute (for example because a snooze and the timer are due at the same time).
+    /// # Returns
+    /// `true` if the reminder should be sent, `false` if it would be a duplicate.
+    pub async fn claim_reminder(&self, chat_id: ChatId, now: NaiveDateTime) -> bool {
+        let minute = now.with_second(0).and_then(|now| now.with_nanosecond(0)).unwrap_or(now);
+        let mut last_reminders = self.last_reminders.write().await;
+        if last_reminders.get(&chat_id) == Some(&minute) {
+            return false;
+        }
+        last_reminders.insert(chat_id, minute);
+        true
+    }
+
+    /// Persists all user states at once with the `UserStore`. The user states are sorted by their ChatId,
+    /// so that the saved file only changes where the user states have changed.
+    pub async fn save_states(&self) -> Result<(), DailyBibleError> {
+        let mut user_states: Vec<UserState> = self.user_states.read().await.values().cloned().collect();
+        user_states.sort_by_key(|user_state| user_state.chat_id.0);
+        self.store.save_all(&user_states).await
+    }
+
+    /// Persists all user states like `save_states`, but only if they have been changed since the last save.
+    /// # Returns
+    /// `true` if the user states have been saved, `false` if nothing has changed.
+    pub async fn save_states_if_dirty(&self) -> Result<bool, DailyBibleError> {
+        // The flag is reset before saving, so that updates during the save mark the states as dirty again
+        if !self.dirty.swap(false, Ordering::SeqCst) {
+            return Ok(false);
+        }
+
+        match self.save_states().await {
+            Ok(_) => Ok(true),
+            Err(error) => {
+                self.dirty.store(true, Ordering::SeqCst);
+                Err(error)
+            }
+        }
+    }
+
+    /// Replaces all user states with the ones which have been persisted in the `UserStore`.
+    pub async fn load_states(&self) -> Result<(), DailyBibleError> {
+        let userstates = self.store.load().await?;
+        let mut userstate_lock = self.user_states.write().await;
+        userstate_lock.clear();
+        userstate_lock.extend(userstates.into_iter().map(|user_state| (user_state.chat_id, user_state)));
+        Ok(())
     }
 
 }
@@ -155,15 +684,17 @@ impl UserStateWrapper {
 mod tests {
     const TEST_FILE_PATH: &str = "testfile.json";
 
-    use std::fs;
+    use std::{fs, path::Path};
+
+    use crate::userstore::JsonFileStore;
 
     use super::*;
 
-    struct TestfileHandling;
+    struct TestfileHandling(&'static str);
 
     impl Drop for TestfileHandling {
         fn drop(&mut self) {
-            if fs::remove_file(TEST_FILE_PATH).is_err() {
+            if fs::remove_file(self.0).is_err() {
                 println!("Warning: Test File couldn't be removed because it most likely did not exist.");
             }
         }
@@ -172,14 +703,13 @@ mod tests {
     
     #[tokio::test]
     async fn test_userstate_wrapper() {
-        let user_state_wrapper = UserStateWrapper::new();
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(TEST_FILE_PATH)));
         let userstate = user_state_wrapper.find_userstate(ChatId(123456));
         assert_eq!(userstate.await.language, Language::English);
 
         let user_state = UserState {
-            chat_id: ChatId(654321),
             language: Language::German,
-            timer: None
+            ..UserState::new(ChatId(654321))
         };
         user_state_wrapper.update_userstate(user_state).await;
         let userstate = user_state_wrapper.find_userstate(ChatId(654321));
@@ -187,47 +717,521 @@ mod tests {
     }
 
     
+    #[tokio::test]
+    async fn test_created_at_is_set_once() {
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(TEST_FILE_PATH)));
+
+        // Only viewing the default user state does not register the user
+        assert!(user_state_wrapper.find_userstate(ChatId(1)).await.created_at.is_none());
+
+        user_state_wrapper.update_userstate(UserState::new(ChatId(1))).await;
+        let created_at = user_state_wrapper.find_userstate(ChatId(1)).await.created_at;
+        assert!(created_at.is_some());
+
+        let user_state = UserState { language: Language::German, ..user_state_wrapper.find_userstate(ChatId(1)).await };
+        user_state_wrapper.update_userstate(user_state).await;
+        assert_eq!(user_state_wrapper.find_userstate(ChatId(1)).await.created_at, created_at);
+    }
+
     #[tokio::test]
     async fn test_save_userstate() {
         // This ensures that the test file will be deleted after this test.
-        let _tfh = TestfileHandling;
+        let _tfh = TestfileHandling(TEST_FILE_PATH);
         
-        let user_state_wrapper = UserStateWrapper::new();
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(TEST_FILE_PATH)));
         let userstate = user_state_wrapper.find_userstate(ChatId(123456));
         assert_eq!(userstate.await.language, Language::English);
 
         let user_state = UserState {
-            chat_id: ChatId(654321),
             language: Language::German,
-            timer: None
+            ..UserState::new(ChatId(654321))
         };
         user_state_wrapper.update_userstate(user_state).await;
 
-        assert!(user_state_wrapper.write_states_to_file(&TEST_FILE_PATH).await.is_ok());
+        assert!(user_state_wrapper.save_states().await.is_ok());
         assert!(Path::new(TEST_FILE_PATH).exists());
     }
 
     #[tokio::test]
     async fn test_load_userstate() {
-        let user_state_wrapper = UserStateWrapper::new();
-        assert!(user_state_wrapper.load_states_from_file("testdata/test_userstate_loading.json").await.is_ok());
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new("testdata/test_userstate_loading.json")));
+        assert!(user_state_wrapper.load_states().await.is_ok());
 
         assert_eq!(user_state_wrapper.user_states.read().await.len(), 2);
         assert_eq!(user_state_wrapper.find_userstate(ChatId(654321)).await.language, Language::German);
+
+        // Files which have been saved before the pause feature existed are loaded as not paused
+        assert!(!user_state_wrapper.find_userstate(ChatId(654321)).await.paused);
+        // ... and without the time of the registration
+        assert!(user_state_wrapper.find_userstate(ChatId(654321)).await.created_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_save_states_if_dirty() {
+        const DIRTY_TEST_FILE_PATH: &str = "testfile_dirty.json";
+        let _tfh = TestfileHandling(DIRTY_TEST_FILE_PATH);
+
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(DIRTY_TEST_FILE_PATH)));
+        assert!(!user_state_wrapper.save_states_if_dirty().await.unwrap());
+
+        user_state_wrapper.update_userstate(UserState::new(ChatId(123456))).await;
+        assert!(user_state_wrapper.save_states_if_dirty().await.unwrap());
+        assert!(Path::new(DIRTY_TEST_FILE_PATH).exists());
+
+        // Nothing has changed since the last save
+        assert!(!user_state_wrapper.save_states_if_dirty().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_saved_states_are_sorted() {
+        const SORTED_TEST_FILE_PATH: &str = "testfile_sorted.json";
+        let _tfh = TestfileHandling(SORTED_TEST_FILE_PATH);
+
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(SORTED_TEST_FILE_PATH)));
+        for chat_id in [300, -100, 200, 100] {
+            user_state_wrapper.update_userstate(UserState::new(ChatId(chat_id))).await;
+        }
+        assert!(user_state_wrapper.save_states().await.is_ok());
+
+        let saved: Vec<serde_json::Value> = serde_json::from_str(&fs::read_to_string(SORTED_TEST_FILE_PATH).unwrap()).unwrap();
+        let chat_ids: Vec<i64> = saved.iter().map(|entry| entry["chat_id"].as_i64().unwrap()).collect();
+        assert_eq!(chat_ids, vec![-100, 100, 200, 300]);
+    }
+
+    #[tokio::test]
+    async fn test_update_and_persist() {
+        const PERSIST_TEST_FILE_PATH: &str = "testfile_persist.json";
+        let _tfh = TestfileHandling(PERSIST_TEST_FILE_PATH);
+
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(PERSIST_TEST_FILE_PATH)));
+        user_state_wrapper.update_and_persist(UserState::new(ChatId(123456))).await;
+        user_state_wrapper.update_and_persist(UserState::new(ChatId(654321))).await;
+        assert!(!Path::new(PERSIST_TEST_FILE_PATH).exists());
+
+        // After the debounce time both updates have been written at once
+        tokio::time::sleep(PERSIST_DEBOUNCE * 2).await;
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(PERSIST_TEST_FILE_PATH)));
+        assert!(user_state_wrapper.load_states().await.is_ok());
+        assert_eq!(user_state_wrapper.user_states.read().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_update_with_keeps_other_changes() {
+        const UPDATE_TEST_FILE_PATH: &str = "testfile_update_with.json";
+        let _tfh = TestfileHandling(UPDATE_TEST_FILE_PATH);
+
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(UPDATE_TEST_FILE_PATH)));
+        user_state_wrapper.update_userstate(UserState::new(ChatId(123456))).await;
+        let stale_user_state = user_state_wrapper.find_userstate(ChatId(123456)).await;
+
+        // A command changes the UserState after it has been read
+        user_state_wrapper.update_userstate(UserState { paused: true, ..stale_user_state }).await;
+
+        let today = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        assert!(user_state_wrapper.update_with(ChatId(123456), |user_state| user_state.last_reminder_sent = Some(today)).await);
+        let user_state = user_state_wrapper.find_userstate(ChatId(123456)).await;
+        assert!(user_state.paused);
+        assert_eq!(user_state.last_reminder_sent, Some(today));
+
+        // Unknown ChatIds are not added
+        assert!(!user_state_wrapper.update_with(ChatId(654321), |user_state| user_state.paused = true).await);
+        assert!(!user_state_wrapper.user_state_exists(ChatId(654321)).await);
+    }
+
+    #[tokio::test]
+    async fn test_mark_reminders_sent_is_persisted_immediately() {
+        const REMINDER_TEST_FILE_PATH: &str = "testfile_mark_reminders_sent.json";
+        let _tfh = TestfileHandling(REMINDER_TEST_FILE_PATH);
+
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(REMINDER_TEST_FILE_PATH)));
+        user_state_wrapper.update_userstate(UserState::new(ChatId(123456))).await;
+        user_state_wrapper.update_userstate(UserState::new(ChatId(654321))).await;
+
+        let today = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        user_state_wrapper.mark_reminders_sent(&[ChatId(123456)], today).await;
+
+        // The file is written without waiting for the debounced save
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(REMINDER_TEST_FILE_PATH)));
+        assert!(user_state_wrapper.load_states().await.is_ok());
+        assert_eq!(user_state_wrapper.find_userstate(ChatId(123456)).await.last_reminder_sent, Some(today));
+        assert_eq!(user_state_wrapper.find_userstate(ChatId(654321)).await.last_reminder_sent, None);
+    }
+
+    #[tokio::test]
+    async fn test_remove_userstate() {
+        const REMOVE_TEST_FILE_PATH: &str = "testfile_remove.json";
+        let _tfh = TestfileHandling(REMOVE_TEST_FILE_PATH);
+
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(REMOVE_TEST_FILE_PATH)));
+        user_state_wrapper.update_userstate(UserState::new(ChatId(123456))).await;
+        user_state_wrapper.add_snooze(ChatId(123456), NaiveDate::from_ymd_opt(2024, 9, 1).unwrap().and_hms_opt(8, 30, 0).unwrap()).await;
+
+        assert!(user_state_wrapper.remove_userstate(ChatId(123456)).await);
+        assert!(!user_state_wrapper.user_state_exists(ChatId(123456)).await);
+        assert!(user_state_wrapper.pending_snoozes.read().await.is_empty());
+
+        // Removing again does not find anything
+        assert!(!user_state_wrapper.remove_userstate(ChatId(123456)).await);
+    }
+
+    #[tokio::test]
+    async fn test_stats() {
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(TEST_FILE_PATH)));
+        user_state_wrapper.update_userstate(UserState::new(ChatId(1))).await;
+        user_state_wrapper.update_userstate(UserState {
+            timer: NaiveTime::from_hms_opt(8, 0, 0),
+            ..UserState::new(ChatId(2))
+        }).await;
+        user_state_wrapper.update_userstate(UserState {
+            language: Language::German,
+            timer: NaiveTime::from_hms_opt(20, 0, 0),
+            ..UserState::new(ChatId(3))
+        }).await;
+        user_state_wrapper.update_userstate(UserState {
+            timer: NaiveTime::from_hms_opt(8, 30, 0),
+            paused: true,
+            ..UserState::new(ChatId(4))
+        }).await;
+        user_state_wrapper.update_userstate(UserState {
+            language: Language::Spanish,
+            ..UserState::new(ChatId(5))
+        }).await;
+
+        assert_eq!(user_state_wrapper.stats().await, UserStatistics {
+            total_users: 5,
+            users_with_timer: 3,
+            active_users: 2,
+            users_per_language: vec![(Language::English, 3), (Language::German, 1), (Language::Spanish, 1)],
+            timers_per_hour: vec![(8, 2), (20, 1)],
+        });
+    }
+
+    #[test]
+    fn test_parse_user_time() {
+        let accepted = [
+            ("08:00", (8, 0)),
+            ("8:00", (8, 0)),
+            (" 07:45 ", (7, 45)),
+            ("8", (8, 0)),
+            ("20", (20, 0)),
+            ("20.00", (20, 0)),
+            ("20.15", (20, 15)),
+            ("8am", (8, 0)),
+            ("8 AM", (8, 0)),
+            ("8:30pm", (20, 30)),
+            ("8:00 PM", (20, 0)),
+            ("08:00am", (8, 0)),
+            ("12am", (0, 0)),
+            ("12pm", (12, 0)),
+        ];
+        for (time_string, (hour, minute)) in accepted {
+            assert_eq!(parse_user_time(time_string), NaiveTime::from_hms_opt(hour, minute, 0), "{}", time_string);
+        }
+
+        let rejected = ["", "24:00", "25:00", "25", "8:60", "13pm", "0am", "+8", "8:00:00", "tomorrow"];
+        for time_string in rejected {
+            assert_eq!(parse_user_time(time_string), None, "{}", time_string);
+        }
+    }
+
+    #[test]
+    fn test_is_current_poll() {
+        let today = NaiveDate::from_ymd_opt(2024, 9, 2).unwrap();
+        let mut user_state = UserState::new(ChatId(123456));
+        assert!(!user_state.is_current_poll("poll", today));
+
+        user_state.last_poll = Some(SentPoll { message_id: MessageId(1), poll_id: String::from("poll"), date: today });
+        assert!(user_state.is_current_poll("poll", today));
+        assert!(!user_state.is_current_poll("older_poll", today));
+
+        // The same poll is stale on the next day
+        assert!(!user_state.is_current_poll("poll", today.succ_opt().unwrap()));
+    }
+
+    #[test]
+    fn test_receives_reminders() {
+        assert!(UserState::new(ChatId(1)).receives_reminders());
+        assert!(!UserState { paused: true, ..UserState::new(ChatId(1)) }.receives_reminders());
+
+        // Groups only receive reminders after they have been enabled
+        let group = UserState { is_group: true, ..UserState::new(ChatId(-1001)) };
+        assert!(!group.receives_reminders());
+        assert!(UserState { group_enabled: true, ..group.clone() }.receives_reminders());
+        assert!(!UserState { group_enabled: true, paused: true, ..group }.receives_reminders());
+    }
+
+    #[test]
+    fn test_plan_day() {
+        let today = NaiveDate::from_ymd_opt(2024, 9, 10).unwrap();
+        assert_eq!(UserState::new(ChatId(1)).plan_day(today), None);
+
+        let user_state = UserState { plan_start_date: NaiveDate::from_ymd_opt(2024, 9, 1), ..UserState::new(ChatId(1)) };
+        assert_eq!(user_state.plan_day(today), Some(9));
+        assert_eq!(user_state.plan_day(NaiveDate::from_ymd_opt(2024, 9, 1).unwrap()), Some(0));
+        // A start date in the future (for example after changing the clock) has no reading yet
+        assert_eq!(user_state.plan_day(NaiveDate::from_ymd_opt(2024, 8, 31).unwrap()), None);
+    }
+
+    #[test]
+    fn test_skips_reminder() {
+        let today = NaiveDate::from_ymd_opt(2024, 9, 10).unwrap();
+        assert!(!UserState::new(ChatId(1)).skips_reminder(today));
+
+        let user_state = UserState { skip_date: Some(today), ..UserState::new(ChatId(1)) };
+        assert!(user_state.skips_reminder(today));
+        // After midnight the reminders continue
+        assert!(!user_state.skips_reminder(today.succ_opt().unwrap()));
+    }
+
+    #[test]
+    fn test_sends_anonymous_poll() {
+        assert!(!UserState::new(ChatId(1)).sends_anonymous_poll());
+        assert!(UserState { anonymous_poll: true, ..UserState::new(ChatId(1)) }.sends_anonymous_poll());
+
+        // Polls in groups are always anonymous
+        assert!(UserState::new(ChatId(-1001)).sends_anonymous_poll());
+    }
+
+    #[test]
+    fn test_sanitize_custom_prefix() {
+        assert_eq!(sanitize_custom_prefix("  Good morning! Time to read: "), Some(String::from("Good morning! Time to read:")));
+        assert_eq!(sanitize_custom_prefix("Good\nmorning\u{7}!"), Some(String::from("Goodmorning!")));
+        assert_eq!(sanitize_custom_prefix(" "), Some(String::new()));
+        assert_eq!(sanitize_custom_prefix(&"ä".repeat(CUSTOM_PREFIX_MAX_LENGTH)), Some("ä".repeat(CUSTOM_PREFIX_MAX_LENGTH)));
+        assert_eq!(sanitize_custom_prefix(&"a".repeat(CUSTOM_PREFIX_MAX_LENGTH + 1)), None);
+    }
+
+    #[test]
+    fn test_received_reminder_on() {
+        let today = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        let user_state = UserState { last_reminder_sent: Some(today), ..UserState::new(ChatId(1)) };
+        assert!(user_state.received_reminder_on(today));
+        // The next reminder is sent after midnight
+        assert!(!user_state.received_reminder_on(today.succ_opt().unwrap()));
+        assert!(!UserState::new(ChatId(1)).received_reminder_on(today));
+    }
+
+    #[test]
+    fn test_week_start() {
+        // 2024-09-01 is a Sunday, which is the last day of the week starting on Monday 2024-08-26
+        assert_eq!(week_start(NaiveDate::from_ymd_opt(2024, 9, 1).unwrap()), NaiveDate::from_ymd_opt(2024, 8, 26).unwrap());
+        assert_eq!(week_start(NaiveDate::from_ymd_opt(2024, 9, 2).unwrap()), NaiveDate::from_ymd_opt(2024, 9, 2).unwrap());
+        // Weeks can span the end of the year
+        assert_eq!(week_start(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()), NaiveDate::from_ymd_opt(2024, 12, 30).unwrap());
+    }
+
+    #[test]
+    fn test_weekly_summary_is_due_once_per_week() {
+        let sunday = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        let mut user_state = UserState::new(ChatId(1));
+        assert!(!user_state.is_weekly_summary_due(sunday));
+
+        user_state.weekly_summary = true;
+        assert!(user_state.is_weekly_summary_due(sunday));
+        assert!(!user_state.is_weekly_summary_due(sunday.pred_opt().unwrap()));
+
+        // After it has been sent, it is due again only in the next week
+        user_state.last_weekly_summary = Some(sunday);
+        assert!(!user_state.is_weekly_summary_due(sunday));
+        assert!(user_state.is_weekly_summary_due(sunday + Days::new(7)));
+    }
+
+    #[test]
+    fn test_read_days_in_week() {
+        let sunday = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        let mut user_state = UserState::new(ChatId(1));
+        // The Sunday of the previous week and the Monday of the next week are not counted
+        for day in [0, 1, 3, 7, 8] {
+            user_state.mark_as_read(sunday - Days::new(day));
+        }
+        user_state.mark_as_read(sunday + Days::new(1));
+
+        assert_eq!(user_state.read_days_in_week(sunday), 3);
+    }
+
+    #[test]
+    fn test_mark_as_read() {
+        let mut user_state = UserState::new(ChatId(123456));
+        let date = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+
+        user_state.mark_as_read(date);
+        assert_eq!(user_state.streak, 1);
+
+        // Marking the same day again must not increase the streak
+        user_state.mark_as_read(date);
+        assert_eq!(user_state.streak, 1);
+
+        user_state.mark_as_read(date.succ_opt().unwrap());
+        assert_eq!(user_state.streak, 2);
+
+        // Skipping a day starts the streak again
+        user_state.mark_as_read(NaiveDate::from_ymd_opt(2024, 9, 5).unwrap());
+        assert_eq!(user_state.streak, 1);
+
+        assert_eq!(user_state.completed_dates, vec![date, date.succ_opt().unwrap(), NaiveDate::from_ymd_opt(2024, 9, 5).unwrap()]);
+    }
+
+    #[test]
+    fn test_completed_dates_are_limited() {
+        let mut user_state = UserState::new(ChatId(123456));
+        let first_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        for day in 0..200 {
+            user_state.mark_as_read(first_date + chrono::Duration::days(day));
+        }
+
+        // Only the newest dates are kept
+        assert_eq!(user_state.completed_dates.len(), COMPLETED_DATES_LIMIT);
+        assert_eq!(user_state.completed_dates.last(), Some(&(first_date + chrono::Duration::days(199))));
+        assert_eq!(user_state.streak, 200);
+    }
+
+    #[tokio::test]
+    async fn test_take_due_snoozes() {
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(TEST_FILE_PATH)));
+        let today = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        user_state_wrapper.add_snooze(ChatId(123456), today.and_hms_opt(8, 30, 0).unwrap()).await;
+        user_state_wrapper.add_snooze(ChatId(654321), today.and_hms_opt(9, 0, 0).unwrap()).await;
+        // The same time on the next day is not due yet
+        user_state_wrapper.add_snooze(ChatId(111111), today.succ_opt().unwrap().and_hms_opt(8, 30, 0).unwrap()).await;
+
+        let due_chat_ids = user_state_wrapper.take_due_snoozes(today.and_hms_opt(8, 30, 15).unwrap()).await;
+        assert_eq!(due_chat_ids, vec![ChatId(123456)]);
+        assert_eq!(user_state_wrapper.pending_snoozes.read().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_passed_snoozes_are_removed() {
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(TEST_FILE_PATH)));
+        let today = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        user_state_wrapper.add_snooze(ChatId(123456), today.and_hms_opt(8, 30, 0).unwrap()).await;
+
+        // The timer loop did not run at 08:30 (for example because the bot was stopped)
+        let due_chat_ids = user_state_wrapper.take_due_snoozes(today.and_hms_opt(9, 0, 0).unwrap()).await;
+        assert!(due_chat_ids.is_empty());
+        assert!(user_state_wrapper.pending_snoozes.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_claim_reminder_once_per_minute() {
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(TEST_FILE_PATH)));
+        let minute = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap().and_hms_opt(8, 0, 10).unwrap();
+
+        // Two reminders which are due in the same minute lead to a single send
+        assert!(user_state_wrapper.claim_reminder(ChatId(1), minute).await);
+        assert!(!user_state_wrapper.claim_reminder(ChatId(1), minute + chrono::Duration::seconds(5)).await);
+        assert!(user_state_wrapper.claim_reminder(ChatId(2), minute).await);
+
+        assert!(user_state_wrapper.claim_reminder(ChatId(1), minute + chrono::Duration::minutes(1)).await);
+        assert!(user_state_wrapper.claim_reminder(ChatId(1), minute + chrono::Duration::days(1)).await);
+    }
+
+    #[test]
+    fn test_next_reminder_rolls_over_midnight() {
+        let today = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        let tomorrow = today.succ_opt().unwrap();
+        let user_state = UserState { timer: NaiveTime::from_hms_opt(20, 0, 0), ..UserState::new(ChatId(1)) };
+
+        assert_eq!(user_state.next_reminder(today.and_hms_opt(8, 0, 0).unwrap()), Some(today.and_hms_opt(20, 0, 0).unwrap()));
+        // A timer which has passed today is sent tomorrow
+        assert_eq!(user_state.next_reminder(today.and_hms_opt(20, 0, 30).unwrap()), Some(tomorrow.and_hms_opt(20, 0, 0).unwrap()));
+        assert_eq!(user_state.next_reminder(today.and_hms_opt(23, 59, 59).unwrap()), Some(tomorrow.and_hms_opt(20, 0, 0).unwrap()));
+
+        // A timer shortly after midnight is still sent on the same day after midnight
+        let user_state = UserState { timer: NaiveTime::from_hms_opt(0, 5, 0), ..user_state };
+        assert_eq!(user_state.next_reminder(today.and_hms_opt(23, 58, 0).unwrap()), Some(tomorrow.and_hms_opt(0, 5, 0).unwrap()));
+        assert_eq!(user_state.next_reminder(tomorrow.and_hms_opt(0, 1, 0).unwrap()), Some(tomorrow.and_hms_opt(0, 5, 0).unwrap()));
+
+        // Skipped and already sent reminders are not counted
+        let user_state = UserState { timer: NaiveTime::from_hms_opt(20, 0, 0), skip_date: Some(today), ..user_state };
+        assert_eq!(user_state.next_reminder(today.and_hms_opt(8, 0, 0).unwrap()), Some(tomorrow.and_hms_opt(20, 0, 0).unwrap()));
+        let user_state = UserState { skip_date: None, last_reminder_sent: Some(today), ..user_state };
+        assert_eq!(user_state.next_reminder(today.and_hms_opt(8, 0, 0).unwrap()), Some(tomorrow.and_hms_opt(20, 0, 0).unwrap()));
+
+        assert_eq!(UserState { paused: true, ..user_state.clone() }.next_reminder(today.and_hms_opt(8, 0, 0).unwrap()), None);
+        assert_eq!(UserState { timer: None, ..user_state }.next_reminder(today.and_hms_opt(8, 0, 0).unwrap()), None);
     }
 
     #[tokio::test]
-    async fn test_userstatevector() {
+    async fn test_deletion_request_expires() {
+        let user_state_wrapper = UserStateWrapper::new(Arc::new(JsonFileStore::new(TEST_FILE_PATH)));
+        let now = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap().and_hms_opt(8, 0, 0).unwrap();
+
+        // Without a request nothing can be confirmed
+        assert!(!user_state_wrapper.take_deletion_request(ChatId(1), now).await);
+
+        user_state_wrapper.request_deletion(ChatId(1), now).await;
+        assert!(user_state_wrapper.take_deletion_request(ChatId(1), now + chrono::Duration::minutes(2)).await);
+        // The request can only be used once
+        assert!(!user_state_wrapper.take_deletion_request(ChatId(1), now + chrono::Duration::minutes(2)).await);
+
+        user_state_wrapper.request_deletion(ChatId(2), now).await;
+        assert!(!user_state_wrapper.take_deletion_request(ChatId(2), now + chrono::Duration::minutes(DELETION_CONFIRMATION_MINUTES + 1)).await);
+    }
+
+    #[test]
+    fn test_import_replaces_chat_id() {
+        let exported_user_state = UserState {
+            language: Language::Spanish,
+            timer: NaiveTime::from_hms_opt(7, 30, 0),
+            last_poll: Some(SentPoll {
+                message_id: MessageId(1),
+                poll_id: String::from("poll"),
+                date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            }),
+            ..UserState::new(ChatId(1))
+        };
+        let json = serde_json::to_vec_pretty(&exported_user_state).unwrap();
+
+        let user_state = UserState::from_export(&json, ChatId(2)).unwrap();
+        assert_eq!(user_state.chat_id, ChatId(2));
+        assert_eq!(user_state.language, Language::Spanish);
+        assert_eq!(user_state.timer, NaiveTime::from_hms_opt(7, 30, 0));
+        assert!(user_state.last_poll.is_none());
+
+        assert!(UserState::from_export(b"not json", ChatId(2)).is_err());
+        assert!(UserState::from_export(br#"{"chat_id": 1}"#, ChatId(2)).is_err());
+    }
+
+    #[test]
+    fn test_import_drops_forged_state() {
+        let exported_user_state = UserState {
+            plan: String::from("chronological"),
+            preferred_bible_version: Some(String::from("ESV")),
+            custom_prefix: Some(String::from("Good morning!")),
+            anonymous_poll: true,
+            group_enabled: true,
+            streak: 1000,
+            last_read: NaiveDate::from_ymd_opt(2024, 9, 1),
+            completed_dates: vec![NaiveDate::from_ymd_opt(2024, 9, 1).unwrap()],
+            ..UserState::new(ChatId(1))
+        };
+        let json = serde_json::to_vec_pretty(&exported_user_state).unwrap();
+
+        let user_state = UserState::from_export(&json, ChatId(2)).unwrap();
+        assert_eq!(user_state.plan, "chronological");
+        assert_eq!(user_state.preferred_bible_version.as_deref(), Some("ESV"));
+        assert_eq!(user_state.custom_prefix.as_deref(), Some("Good morning!"));
+        assert!(user_state.anonymous_poll);
+
+        // The state which can not be set with commands is not taken from the file
+        assert!(!user_state.group_enabled);
+        assert_eq!(user_state.streak, 0);
+        assert!(user_state.last_read.is_none());
+        assert!(user_state.completed_dates.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_userstatemap() {
         let user_state = UserState {
-            chat_id: ChatId(123456),
             language: Language::German,
-            timer: None,
+            ..UserState::new(ChatId(123456))
         };
-        let user_state_vector: UserStateVector = Arc::new(
+        let user_state_map: UserStateMap = Arc::new(
             RwLock::new(
-                vec![user_state]
+                HashMap::from([(user_state.chat_id, user_state)])
             )
         );
-        assert_eq!(user_state_vector.read().await.len(), 1);
+        assert_eq!(user_state_map.read().await.len(), 1);
+        assert_eq!(user_state_map.read().await[&ChatId(123456)].language, Language::German);
     }
 }
\ No newline at end of file
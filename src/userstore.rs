@@ -0,0 +1,282 @@
+//! In this unit, the persistence of the user states is implemented. The `UserStore` trait abstracts
+//! over the storage backend, so that the `UserStateWrapper` does not need to know where the user states are saved.
+
+use std::{path::{Path, PathBuf}, str::FromStr};
+
+use async_trait::async_trait;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+use teloxide::types::ChatId;
+
+use crate::error::DailyBibleError;
+use crate::userstate::UserState;
+
+
+/// A storage backend for the user states.
+#[async_trait]
+pub trait UserStore: Send + Sync {
+    /// Loads all user states which have been persisted so far.
+    async fn load(&self) -> Result<Vec<UserState>, DailyBibleError>;
+
+    /// Persists a single user state which has been created or updated.
+    async fn save_one(&self, user_state: &UserState) -> Result<(), DailyBibleError>;
+
+    /// Persists all given user states at once.
+    async fn save_all(&self, user_states: &[UserState]) -> Result<(), DailyBibleError>;
+
+    /// Removes the persisted user state of the given ChatId.
+    async fn remove(&self, chat_id: ChatId) -> Result<(), DailyBibleError>;
+
+    /// Returns `true` if `save_one` persists single updates directly. Otherwise the user states have to be
+    /// saved with `save_all` to persist changes.
+    fn persists_single_updates(&self) -> bool {
+        false
+    }
+}
+
+
+/// Parses a single persisted user state. Malformed entries are skipped with a warning.
+fn parse_user_state(entry: serde_json::Value) -> Option<UserState> {
+    let chat_id = entry.get("chat_id").cloned();
+    match serde_json::from_value(entry) {
+        Ok(user_state) => Some(user_state),
+        Err(error) => {
+            log::warn!("Skipping the malformed user state of the chat {:?}: {}", chat_id, error.to_string());
+            None
+        }
+    }
+}
+
+
+/// Saves all user states in a single JSON file. As the whole file has to be rewritten for every change,
+/// single updates are not persisted directly but all user states are saved periodically with `save_all`.
+pub struct JsonFileStore {
+    file_path: String,
+}
+
+impl JsonFileStore {
+    pub fn new(file_path: &str) -> Self {
+        JsonFileStore {
+            file_path: file_path.to_string(),
+        }
+    }
+
+    /// Returns the path of the temporary file next to the user state file which is renamed to the user state file
+    /// after it has been written completely. It has to be in the same directory, as renaming is only atomic
+    /// within the same file system.
+    fn temporary_file_path(&self) -> PathBuf {
+        let mut temporary_file_path = Path::new(&self.file_path).as_os_str().to_owned();
+        temporary_file_path.push(".tmp");
+        PathBuf::from(temporary_file_path)
+    }
+}
+
+#[async_trait]
+impl UserStore for JsonFileStore {
+    async fn load(&self) -> Result<Vec<UserState>, DailyBibleError> {
+        let file_string = tokio::fs::read_to_string(&self.file_path).await?;
+
+        // The entries are parsed one by one, so that a single broken entry (for example an edited timer)
+        // does not prevent all other users from being loaded
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&file_string)?;
+        Ok(entries.into_iter().filter_map(parse_user_state).collect())
+    }
+
+    async fn save_one(&self, _user_state: &UserState) -> Result<(), DailyBibleError> {
+        // The file is rewritten completely by `save_all`, so nothing has to be done here.
+        Ok(())
+    }
+
+    async fn save_all(&self, user_states: &[UserState]) -> Result<(), DailyBibleError> {
+        let json_string = serde_json::to_string_pretty(user_states)?;
+
+        // The states are written to a temporary file in the same directory which replaces the file afterwards,
+        // so a crash while writing never leaves a truncated file behind
+        let temporary_file_path = self.temporary_file_path();
+        tokio::fs::write(&temporary_file_path, json_string).await?;
+        tokio::fs::rename(&temporary_file_path, Path::new(&self.file_path)).await?;
+        Ok(())
+    }
+
+    async fn remove(&self, _chat_id: ChatId) -> Result<(), DailyBibleError> {
+        // The file is rewritten completely by `save_all`, so nothing has to be done here.
+        Ok(())
+    }
+}
+
+
+/// Saves the user states in a SQLite database. Every user state is saved as JSON in its own row,
+/// so single updates can be persisted directly without rewriting all other user states.
+pub struct SqliteStore {
+    pool: SqlitePool,
+}
+
+impl SqliteStore {
+    /// Connects to the SQLite database with the given url (for example `sqlite://userstates.db`).
+    /// The database file and the table are created if they do not exist yet.
+    pub async fn connect(database_url: &str) -> Result<Self, DailyBibleError> {
+        let options = SqliteConnectOptions::from_str(database_url)?.create_if_missing(true);
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS user_states (chat_id INTEGER PRIMARY KEY NOT NULL, state TEXT NOT NULL)"
+        )
+        .execute(&pool)
+        .await?;
+
+        Ok(SqliteStore { pool })
+    }
+}
+
+#[async_trait]
+impl UserStore for SqliteStore {
+    async fn load(&self) -> Result<Vec<UserState>, DailyBibleError> {
+        let rows: Vec<(String,)> = sqlx::query_as("SELECT state FROM user_states")
+            .fetch_all(&self.pool)
+            .await?;
+
+        // A malformed row is skipped like a malformed entry of the JSON file
+        let user_states = rows.into_iter()
+            .filter_map(|(state,)| match serde_json::from_str(&state) {
+                Ok(entry) => parse_user_state(entry),
+                Err(error) => {
+                    log::warn!("Skipping a malformed user state: {}", error.to_string());
+                    None
+                }
+            })
+            .collect();
+        Ok(user_states)
+    }
+
+    async fn save_one(&self, user_state: &UserState) -> Result<(), DailyBibleError> {
+        sqlx::query(
+            "INSERT INTO user_states (chat_id, state) VALUES (?, ?) ON CONFLICT(chat_id) DO UPDATE SET state = excluded.state"
+        )
+        .bind(user_state.chat_id.0)
+        .bind(serde_json::to_string(user_state)?)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn save_all(&self, user_states: &[UserState]) -> Result<(), DailyBibleError> {
+        let mut transaction = self.pool.begin().await?;
+        for user_state in user_states {
+            sqlx::query(
+                "INSERT INTO user_states (chat_id, state) VALUES (?, ?) ON CONFLICT(chat_id) DO UPDATE SET state = excluded.state"
+            )
+            .bind(user_state.chat_id.0)
+            .bind(serde_json::to_string(user_state)?)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn remove(&self, chat_id: ChatId) -> Result<(), DailyBibleError> {
+        sqlx::query("DELETE FROM user_states WHERE chat_id = ?")
+            .bind(chat_id.0)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    fn persists_single_updates(&self) -> bool {
+        true
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::localize::Language;
+
+    use super::*;
+
+    /// Removes the user state file of a test and its temporary file when the test ends, even if it fails.
+    struct TestfileHandling(&'static str);
+
+    impl Drop for TestfileHandling {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(self.0);
+            let _ = std::fs::remove_file(JsonFileStore::new(self.0).temporary_file_path());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_json_file_store_load() {
+        let store = JsonFileStore::new("testdata/test_userstate_loading.json");
+        let user_states = store.load().await.unwrap();
+        assert_eq!(user_states.len(), 2);
+        assert_eq!(user_states[0].language, Language::German);
+        // Fields which did not exist in older files get their defaults
+        assert!(user_states[0].use_markdown);
+        assert!(user_states[0].plan.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_json_file_store_skips_malformed_entries() {
+        let store = JsonFileStore::new("testdata/test_userstate_corrupt.json");
+        let user_states = store.load().await.unwrap();
+        assert_eq!(user_states.len(), 1);
+        assert_eq!(user_states[0].chat_id, ChatId(654321));
+        assert_eq!(user_states[0].timer, chrono::NaiveTime::from_hms_opt(8, 0, 0));
+    }
+
+    #[tokio::test]
+    async fn test_json_file_store_replaces_file() {
+        let file_path = "testfile_json_file_store_replace.json";
+        let _tfh = TestfileHandling(file_path);
+        let store = JsonFileStore::new(file_path);
+
+        // A previously written file is replaced with the new user states
+        let user_states = vec![UserState::new(ChatId(1))];
+        assert!(store.save_all(&user_states).await.is_ok());
+        let user_states = vec![UserState { language: Language::German, ..UserState::new(ChatId(2)) }];
+        assert!(store.save_all(&user_states).await.is_ok());
+
+        let file_string = tokio::fs::read_to_string(file_path).await.unwrap();
+        assert_eq!(file_string, serde_json::to_string_pretty(&user_states).unwrap());
+        assert!(!store.temporary_file_path().exists());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_store() {
+        let store = SqliteStore::connect("sqlite::memory:").await.unwrap();
+
+        let mut user_state = UserState::new(ChatId(123456));
+        assert!(store.save_one(&user_state).await.is_ok());
+
+        // Saving the same ChatId again must update the existing row instead of adding a new one
+        user_state.language = Language::German;
+        assert!(store.save_one(&user_state).await.is_ok());
+
+        let user_states = store.load().await.unwrap();
+        assert_eq!(user_states.len(), 1);
+        assert_eq!(user_states[0].language, Language::German);
+
+        assert!(store.remove(ChatId(123456)).await.is_ok());
+        assert!(store.load().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_store_save_all() {
+        let store = SqliteStore::connect("sqlite::memory:").await.unwrap();
+        assert!(store.persists_single_updates());
+
+        let user_states = vec![UserState::new(ChatId(1)), UserState::new(ChatId(2))];
+        assert!(store.save_all(&user_states).await.is_ok());
+
+        // Saving all user states again updates the existing rows instead of adding new ones
+        let user_states = vec![UserState { language: Language::Spanish, ..UserState::new(ChatId(1)) }, UserState::new(ChatId(2))];
+        assert!(store.save_all(&user_states).await.is_ok());
+
+        let mut loaded_user_states = store.load().await.unwrap();
+        loaded_user_states.sort_by_key(|user_state| user_state.chat_id.0);
+        assert_eq!(loaded_user_states.len(), 2);
+        assert_eq!(loaded_user_states[0].language, Language::Spanish);
+    }
+}
@@ -0,0 +1,104 @@
+//! In this unit, the optional verses of the day are implemented which are appended to today's Bible reading.
+
+use std::{env, fs::File, io};
+
+use chrono::{Local, NaiveDate};
+
+use crate::biblereading::{schedule_date_format, ScheduleDate};
+
+/// The default file path of the file which contains the verses of the day
+const DEFAULT_VERSES_FILE_PATH: &str = "verses.csv";
+
+/// The name of the environment variable where the path of the verses file can be specified.
+/// If it is not set, `DEFAULT_VERSES_FILE_PATH` is used.
+pub const VERSES_FILE_ENV: &str = "DAILYBIBLE_VERSES_FILE";
+
+/// A short verse which is sent together with the Bible reading of a day
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerseOfTheDay {
+    pub reference: String,
+    pub text: String,
+}
+
+/// The file which contains the verses of the day. It has the columns date, reference and text
+/// and uses the same date format as the schedule.
+pub struct Verses {
+    file_path: String,
+    date_format: String,
+}
+
+impl Verses {
+    pub fn new(file_path: &str, date_format: &str) -> Self {
+        Verses {
+            file_path: file_path.to_string(),
+            date_format: date_format.to_string(),
+        }
+    }
+
+    /// Returns the verse for the given date.
+    /// 
+    /// # Returns
+    /// The verse or `None` if the file does not exist or contains no verse for the date.
+    pub fn get_verse_for_date(&self, date: NaiveDate) -> Option<VerseOfTheDay> {
+        let file = File::open(&self.file_path).ok()?;
+        find_verse(file, &self.date_format, date)
+    }
+}
+
+/// Returns the verse for today from the file which is configured with `VERSES_FILE_ENV`
+pub fn get_todays_verse() -> Option<VerseOfTheDay> {
+    let file_path = env::var(VERSES_FILE_ENV).unwrap_or(DEFAULT_VERSES_FILE_PATH.to_string());
+    Verses::new(&file_path, &schedule_date_format()).get_verse_for_date(Local::now().date_naive())
+}
+
+/// Searches the verse for the given date in CSV data (with a header row). As the verses are optional,
+/// rows which can not be parsed are skipped with a warning instead of returning an error.
+fn find_verse<R: io::Read>(reader: R, date_format: &str, date: NaiveDate) -> Option<VerseOfTheDay> {
+    let csv_reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+
+    for string_record in csv_reader.into_records().flatten() {
+        if string_record.len() != 3 {
+            log::warn!("The row {:?} of the verses file does not have 3 columns", string_record);
+            continue;
+        }
+
+        match ScheduleDate::parse(string_record.get(0).unwrap(), date_format) {
+            Ok(schedule_date) => {
+                if schedule_date.matches(date) {
+                    return Some(VerseOfTheDay {
+                        reference: string_record.get(1).unwrap().trim().to_string(),
+                        text: string_record.get(2).unwrap().trim().to_string(),
+                    });
+                }
+            },
+            Err(_) => log::warn!("Can not parse the date {} of the verses file", string_record.get(0).unwrap()),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const VERSES: &str = "Date,Reference,Text\n\
+        09-01-24,Ps 118:24,\"This is the day that the LORD has made; let us rejoice and be glad in it.\"\n\
+        invalid,Joh 3:16,\"For God so loved the world\"\n\
+        09-02-24,Joh 1:5,\"The light shines in the darkness\"\n";
+
+    #[test]
+    fn verse_can_be_found() {
+        let verse = find_verse(VERSES.as_bytes(), "%m-%d-%y", NaiveDate::from_ymd_opt(2024, 9, 2).unwrap());
+        assert_eq!(verse, Some(VerseOfTheDay {
+            reference: String::from("Joh 1:5"),
+            text: String::from("The light shines in the darkness"),
+        }));
+    }
+
+    #[test]
+    fn missing_verse_is_none() {
+        assert!(find_verse(VERSES.as_bytes(), "%m-%d-%y", NaiveDate::from_ymd_opt(2024, 9, 3).unwrap()).is_none());
+        assert!(Verses::new("testdata/does_not_exist.csv", "%m-%d-%y").get_verse_for_date(NaiveDate::from_ymd_opt(2024, 9, 1).unwrap()).is_none());
+    }
+}